@@ -0,0 +1,537 @@
+//! Sourcing and validation of the bridge events against the L2 logs.
+
+use std::{collections::HashMap, fmt, ops::RangeInclusive};
+
+use aggchain_proof_contracts::{
+    bridge_events::{InsertedGerEvent, L2BridgeEvents},
+    contracts::{L2BlockHashFetcher, L2BridgeEventsFetcher},
+};
+use aggchain_proof_types::{
+    imported_bridge_exit::{BridgeExitHash, ImportedBridgeExitWithBlockNumber},
+    inserted_ger::{InsertedGer, InsertedGerWithBlockNumber},
+    removed_ger::RemovedGerWithBlockNumber,
+    unclaim::UnclaimWithBlockNumber,
+    AggchainProofInputs,
+};
+use agglayer_primitives::{Digest, U256};
+use tracing::{info, warn};
+
+use crate::{
+    chain_data::{check_pinned_block, retry_on_l2_reorg},
+    Error,
+};
+
+/// Replaces the bridge events of `inputs` with the ones emitted on the L2 in
+/// `(last_proven_block, end_block]`.
+///
+/// Only the L1 info tree data and the GER inclusion proofs are kept from the
+/// request: the inclusion proof of every GER inserted in the range is looked
+/// up in the `ger_leaves` supplied by the client. The bridge exits claimed
+/// through another contract on a node not serving the call traces are taken
+/// from the `imported_bridge_exits` supplied by the client, the proof still
+/// checking them against the claimed global index hash chain.
+///
+/// Returns the hash of the end block the events were read at.
+pub(crate) async fn source_from_l2_logs<ContractsClient>(
+    contracts_client: &ContractsClient,
+    inputs: &mut AggchainProofInputs,
+    end_block: u64,
+) -> Result<Digest, Error>
+where
+    ContractsClient: L2BlockHashFetcher + L2BridgeEventsFetcher,
+{
+    let (events, end_block_hash) =
+        fetch_pinned_l2_bridge_events(contracts_client, inputs.last_proven_block, end_block)
+            .await?;
+
+    info!(
+        last_proven_block = inputs.last_proven_block,
+        %end_block,
+        inserted_gers = events.inserted_gers.len(),
+        removed_gers = events.removed_gers.len(),
+        imported_bridge_exits = events.imported_bridge_exits.len(),
+        unclaims = events.unclaims.len(),
+        unresolved_claims = events.unresolved_claims.len(),
+        "Bridge events sourced from the l2 logs"
+    );
+
+    let mut imported_bridge_exits = events.imported_bridge_exits;
+    for claim in events.unresolved_claims {
+        let bridge_exit_hash = supplied_bridge_exit_hash(inputs, claim.global_index).ok_or(
+            Error::UnresolvedClaim {
+                global_index: claim.global_index,
+                tx_hash: claim.tx_hash,
+                block_number: claim.block_number,
+                log_index: claim.log_index,
+            },
+        )?;
+
+        imported_bridge_exits.push(ImportedBridgeExitWithBlockNumber {
+            block_number: claim.block_number,
+            bridge_exit_hash,
+            global_index: claim.global_index.into(),
+            log_index: claim.log_index,
+        });
+    }
+    imported_bridge_exits.sort();
+
+    // The same GER may be inserted more than once, so the leaves are keyed by
+    // the position of their insertion rather than by the client keys.
+    let inclusion_proofs: HashMap<Digest, InsertedGer> = inputs
+        .ger_leaves
+        .drain()
+        .map(|(_, leaf)| {
+            (
+                leaf.inserted_ger.l1_leaf.inner.global_exit_root,
+                leaf.inserted_ger,
+            )
+        })
+        .collect();
+
+    inputs.ger_leaves = events
+        .inserted_gers
+        .into_iter()
+        .map(|event| {
+            let inserted_ger = inclusion_proofs.get(&event.global_exit_root).ok_or(
+                Error::MissingGerInclusionProof {
+                    global_exit_root: event.global_exit_root,
+                    block_number: event.block_number,
+                    log_index: event.log_index,
+                },
+            )?;
+
+            Ok((
+                format!("{}:{}", event.block_number, event.log_index),
+                InsertedGerWithBlockNumber {
+                    block_number: event.block_number,
+                    inserted_ger: inserted_ger.clone(),
+                    log_index: event.log_index,
+                },
+            ))
+        })
+        .collect::<Result<_, Error>>()?;
+    inputs.imported_bridge_exits = imported_bridge_exits;
+    inputs.removed_gers = events.removed_gers;
+    inputs.unclaims = events.unclaims;
+
+    Ok(end_block_hash)
+}
+
+/// Fetches the bridge events emitted in `(last_proven_block, end_block]`,
+/// checking that they were all read on the fork of the end block, whose hash
+/// is returned.
+async fn fetch_pinned_l2_bridge_events<ContractsClient>(
+    contracts_client: &ContractsClient,
+    last_proven_block: u64,
+    end_block: u64,
+) -> Result<(L2BridgeEvents, Digest), Error>
+where
+    ContractsClient: L2BlockHashFetcher + L2BridgeEventsFetcher,
+{
+    retry_on_l2_reorg(
+        "bridge events",
+        last_proven_block,
+        end_block,
+        || async move {
+            let end_block_hash = contracts_client
+                .get_l2_block_hash(end_block)
+                .await
+                .map_err(Error::L2ChainDataRetrievalError)?;
+
+            let events = contracts_client
+                .get_l2_bridge_events((last_proven_block + 1)..=end_block)
+                .await
+                .map_err(Error::L2ChainDataRetrievalError)?;
+
+            // The logs can only be requested by number, so the block hash they were
+            // read at is checked against the canonical one instead.
+            for (&block_number, &block_hash) in &events.block_hashes {
+                let canonical_block_hash = contracts_client
+                    .get_l2_block_hash(block_number)
+                    .await
+                    .map_err(Error::L2ChainDataRetrievalError)?;
+                check_pinned_block(block_number, canonical_block_hash, block_hash)?;
+            }
+
+            // The canonical blocks are the ancestors of the pinned end block as long
+            // as it has not been reorged out in the meantime.
+            let canonical_end_block_hash = contracts_client
+                .get_l2_block_hash(end_block)
+                .await
+                .map_err(Error::L2ChainDataRetrievalError)?;
+            check_pinned_block(end_block, end_block_hash, canonical_end_block_hash)?;
+
+            Ok((events, end_block_hash))
+        },
+    )
+    .await
+}
+
+/// Hash of the bridge exit of `global_index` supplied in the request.
+fn supplied_bridge_exit_hash(
+    inputs: &AggchainProofInputs,
+    global_index: U256,
+) -> Option<BridgeExitHash> {
+    inputs
+        .imported_bridge_exits
+        .iter()
+        .find(|exit| U256::from(exit.global_index) == global_index)
+        .map(|exit| exit.bridge_exit_hash)
+}
+
+/// Position of a bridge event in the L2 chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventPosition {
+    pub block_number: u64,
+    pub log_index: u64,
+}
+
+impl fmt::Display for EventPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "block {}, log index {}",
+            self.block_number, self.log_index
+        )
+    }
+}
+
+/// Bridge exit claimed on the L2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClaimedBridgeExit {
+    pub global_index: U256,
+
+    /// Hash of the bridge exit, unknown for a claim made through another
+    /// contract on a node not serving the call traces, and not supplied in
+    /// the request either.
+    pub bridge_exit_hash: Option<Digest>,
+}
+
+impl fmt::Display for ClaimedBridgeExit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.bridge_exit_hash {
+            Some(bridge_exit_hash) => write!(
+                f,
+                "global index {}, bridge exit hash {bridge_exit_hash}",
+                self.global_index
+            ),
+            None => write!(
+                f,
+                "global index {}, claimed through another contract",
+                self.global_index
+            ),
+        }
+    }
+}
+
+/// Difference between a bridge event supplied in the request and the ones
+/// emitted on the L2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeEventMismatch<V> {
+    /// Event emitted on the L2 but absent from the request.
+    Missing { value: V, expected: EventPosition },
+
+    /// Event supplied in the request but not emitted on the L2.
+    Extra { value: V, got: EventPosition },
+
+    /// Event supplied in the request at another position than the one it was
+    /// emitted at on the L2.
+    Misordered {
+        value: V,
+        expected: EventPosition,
+        got: EventPosition,
+    },
+}
+
+impl<V> BridgeEventMismatch<V> {
+    fn position(&self) -> EventPosition {
+        match self {
+            Self::Missing { expected, .. } => *expected,
+            Self::Extra { got, .. } => *got,
+            Self::Misordered { expected, .. } => *expected,
+        }
+    }
+}
+
+impl<V: fmt::Display> fmt::Display for BridgeEventMismatch<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { value, expected } => {
+                write!(f, "missing {value}, emitted at {expected}")
+            }
+            Self::Extra { value, got } => write!(f, "extra {value} at {got}, not emitted"),
+            Self::Misordered {
+                value,
+                expected,
+                got,
+            } => write!(f, "misordered {value} at {got}, emitted at {expected}"),
+        }
+    }
+}
+
+/// Differences between the bridge events supplied in the request and the ones
+/// emitted on the L2, each list sorted by position.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BridgeEventsDiff {
+    pub imported_bridge_exits: Vec<BridgeEventMismatch<ClaimedBridgeExit>>,
+    pub inserted_gers: Vec<BridgeEventMismatch<Digest>>,
+    pub removed_gers: Vec<BridgeEventMismatch<Digest>>,
+    pub unclaims: Vec<BridgeEventMismatch<U256>>,
+}
+
+impl BridgeEventsDiff {
+    pub fn is_empty(&self) -> bool {
+        self.imported_bridge_exits.is_empty()
+            && self.inserted_gers.is_empty()
+            && self.removed_gers.is_empty()
+            && self.unclaims.is_empty()
+    }
+
+    /// Every mismatch along with the name of the request field it relates to.
+    pub fn violations(&self) -> Vec<(&'static str, String)> {
+        fn describe<V: fmt::Display>(
+            field: &'static str,
+            mismatches: &[BridgeEventMismatch<V>],
+        ) -> impl Iterator<Item = (&'static str, String)> + '_ {
+            mismatches
+                .iter()
+                .map(move |mismatch| (field, mismatch.to_string()))
+        }
+
+        describe("imported_bridge_exits", &self.imported_bridge_exits)
+            .chain(describe("ger_leaves", &self.inserted_gers))
+            .chain(describe("removed_gers", &self.removed_gers))
+            .chain(describe("unclaims", &self.unclaims))
+            .collect()
+    }
+}
+
+impl fmt::Display for BridgeEventsDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let violations = self.violations();
+        for (i, (field, description)) in violations.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{field}: {description}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks the bridge events supplied in `inputs` against the ones emitted on
+/// the L2 in `(last_proven_block, end_block]`.
+///
+/// Returns [`Error::BridgeEventsMismatch`] listing every difference, instead
+/// of letting the proof fail on an opaque hash chain mismatch, or else the
+/// hash of the end block the events were read at.
+pub(crate) async fn validate_against_l2_logs<ContractsClient>(
+    contracts_client: &ContractsClient,
+    inputs: &AggchainProofInputs,
+    end_block: u64,
+) -> Result<Digest, Error>
+where
+    ContractsClient: L2BlockHashFetcher + L2BridgeEventsFetcher,
+{
+    let range = (inputs.last_proven_block + 1)..=end_block;
+    let (events, end_block_hash) =
+        fetch_pinned_l2_bridge_events(contracts_client, inputs.last_proven_block, end_block)
+            .await?;
+
+    // The bridge exits claimed through another contract are matched on their
+    // global index only, taking the hash supplied for it if any.
+    let unresolved_claims = events.unresolved_claims.iter().map(|claim| {
+        (
+            EventPosition {
+                block_number: claim.block_number,
+                log_index: claim.log_index,
+            },
+            ClaimedBridgeExit {
+                global_index: claim.global_index,
+                bridge_exit_hash: supplied_bridge_exit_hash(inputs, claim.global_index)
+                    .map(|hash| hash.0),
+            },
+        )
+    });
+
+    let diff = BridgeEventsDiff {
+        imported_bridge_exits: diff_in_range(
+            &range,
+            inputs.imported_bridge_exits.iter().map(L2Event::positioned),
+            events
+                .imported_bridge_exits
+                .iter()
+                .map(L2Event::positioned)
+                .chain(unresolved_claims),
+        ),
+        inserted_gers: diff_in_range(
+            &range,
+            inputs.ger_leaves.values().map(L2Event::positioned),
+            events.inserted_gers.iter().map(L2Event::positioned),
+        ),
+        removed_gers: diff_in_range(
+            &range,
+            inputs.removed_gers.iter().map(L2Event::positioned),
+            events.removed_gers.iter().map(L2Event::positioned),
+        ),
+        unclaims: diff_in_range(
+            &range,
+            inputs.unclaims.iter().map(L2Event::positioned),
+            events.unclaims.iter().map(L2Event::positioned),
+        ),
+    };
+
+    if diff.is_empty() {
+        return Ok(end_block_hash);
+    }
+
+    warn!(
+        last_proven_block = inputs.last_proven_block,
+        %end_block,
+        %diff,
+        "Bridge events supplied in the request do not match the l2 logs"
+    );
+
+    Err(Error::BridgeEventsMismatch(Box::new(diff)))
+}
+
+/// Bridge event emitted on the L2, as supplied in the request or read from
+/// the logs.
+trait L2Event {
+    type Value;
+
+    /// Position of the event along with the value it is matched on.
+    fn positioned(&self) -> (EventPosition, Self::Value);
+}
+
+impl L2Event for ImportedBridgeExitWithBlockNumber {
+    type Value = ClaimedBridgeExit;
+
+    fn positioned(&self) -> (EventPosition, ClaimedBridgeExit) {
+        (
+            EventPosition {
+                block_number: self.block_number,
+                log_index: self.log_index,
+            },
+            ClaimedBridgeExit {
+                global_index: self.global_index.into(),
+                bridge_exit_hash: Some(self.bridge_exit_hash.0),
+            },
+        )
+    }
+}
+
+impl L2Event for InsertedGerWithBlockNumber {
+    type Value = Digest;
+
+    fn positioned(&self) -> (EventPosition, Digest) {
+        (
+            EventPosition {
+                block_number: self.block_number,
+                log_index: self.log_index,
+            },
+            self.inserted_ger.l1_leaf.inner.global_exit_root,
+        )
+    }
+}
+
+impl L2Event for InsertedGerEvent {
+    type Value = Digest;
+
+    fn positioned(&self) -> (EventPosition, Digest) {
+        (
+            EventPosition {
+                block_number: self.block_number,
+                log_index: self.log_index,
+            },
+            self.global_exit_root,
+        )
+    }
+}
+
+impl L2Event for RemovedGerWithBlockNumber {
+    type Value = Digest;
+
+    fn positioned(&self) -> (EventPosition, Digest) {
+        (
+            EventPosition {
+                block_number: self.block_number,
+                log_index: self.log_index,
+            },
+            self.global_exit_root,
+        )
+    }
+}
+
+impl L2Event for UnclaimWithBlockNumber {
+    type Value = U256;
+
+    fn positioned(&self) -> (EventPosition, U256) {
+        (
+            EventPosition {
+                block_number: self.block_number,
+                log_index: self.log_index,
+            },
+            self.global_index,
+        )
+    }
+}
+
+/// Matches the `supplied` events in `range` against the `emitted` ones, the
+/// supplied events out of `range` being left to the proof.
+fn diff_in_range<V: PartialEq>(
+    range: &RangeInclusive<u64>,
+    supplied: impl Iterator<Item = (EventPosition, V)>,
+    emitted: impl Iterator<Item = (EventPosition, V)>,
+) -> Vec<BridgeEventMismatch<V>> {
+    diff_events(
+        supplied
+            .filter(|(position, _)| range.contains(&position.block_number))
+            .collect(),
+        emitted.collect(),
+    )
+}
+
+/// Matches the `supplied` events against the `emitted` ones.
+///
+/// The events found at the same position on both sides match. The remaining
+/// ones with the same value on both sides are misordered, the others are
+/// missing from or extra in the `supplied` events.
+pub(crate) fn diff_events<V: PartialEq>(
+    mut supplied: Vec<(EventPosition, V)>,
+    emitted: Vec<(EventPosition, V)>,
+) -> Vec<BridgeEventMismatch<V>> {
+    let mut unmatched = Vec::new();
+    for event in emitted {
+        match supplied.iter().position(|supplied| *supplied == event) {
+            Some(index) => {
+                supplied.remove(index);
+            }
+            None => unmatched.push(event),
+        }
+    }
+
+    let mut mismatches = Vec::new();
+    for (expected, value) in unmatched {
+        match supplied.iter().position(|(_, supplied)| *supplied == value) {
+            Some(index) => {
+                let (got, _) = supplied.remove(index);
+                mismatches.push(BridgeEventMismatch::Misordered {
+                    value,
+                    expected,
+                    got,
+                });
+            }
+            None => mismatches.push(BridgeEventMismatch::Missing { value, expected }),
+        }
+    }
+    mismatches.extend(
+        supplied
+            .into_iter()
+            .map(|(got, value)| BridgeEventMismatch::Extra { value, got }),
+    );
+
+    mismatches.sort_by_key(BridgeEventMismatch::position);
+    mismatches
+}
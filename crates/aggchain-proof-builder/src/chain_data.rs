@@ -0,0 +1,216 @@
+//! L2 chain data of an aggchain proof, read consistently from a single fork.
+
+use std::future::Future;
+
+use aggchain_proof_contracts::contracts::{
+    L2BlockHashFetcher, L2EvmStateSketchFetcher, L2LocalExitRootFetcher, L2OutputAtBlock,
+    L2OutputAtBlockFetcher,
+};
+use agglayer_primitives::Digest;
+use alloy::eips::BlockNumberOrTag;
+use sp1_cc_client_executor::io::EvmSketchInput;
+use tracing::warn;
+
+use crate::Error;
+
+/// Number of times the L2 chain data is fetched before giving up when the L2
+/// keeps reorging in between the reads.
+const MAX_L2_CHAIN_DATA_FETCH_ATTEMPTS: usize = 3;
+
+/// L2 chain data at the last proven block and at the end block.
+pub(crate) struct L2ChainData {
+    pub(crate) prev_local_exit_root: Digest,
+    pub(crate) new_local_exit_root: Digest,
+    pub(crate) l2_pre_root_output_at_block: L2OutputAtBlock,
+    pub(crate) claim_root_output_at_block: L2OutputAtBlock,
+    pub(crate) prev_l2_block_sketch: EvmSketchInput,
+    pub(crate) new_l2_block_sketch: EvmSketchInput,
+}
+
+/// Fetches the [`L2ChainData`], retrying the whole fetch when the L2 reorgs
+/// between the reads.
+///
+/// The end block is pinned to `pinned_end_block_hash` when given, the hash of
+/// the fork the bridge events were read on.
+pub(crate) async fn fetch_l2_chain_data<ContractsClient>(
+    contracts_client: &ContractsClient,
+    last_proven_block: u64,
+    end_block: u64,
+    pinned_end_block_hash: Option<Digest>,
+) -> Result<L2ChainData, Error>
+where
+    ContractsClient: L2BlockHashFetcher
+        + L2LocalExitRootFetcher
+        + L2OutputAtBlockFetcher
+        + L2EvmStateSketchFetcher,
+{
+    retry_on_l2_reorg("chain data", last_proven_block, end_block, || {
+        fetch_pinned_l2_chain_data(
+            contracts_client,
+            last_proven_block,
+            end_block,
+            pinned_end_block_hash,
+        )
+    })
+    .await
+}
+
+/// Runs `fetch` again when it detects an L2 reorg, up to
+/// [`MAX_L2_CHAIN_DATA_FETCH_ATTEMPTS`] times.
+pub(crate) async fn retry_on_l2_reorg<T, Fetch, Fut>(
+    what: &str,
+    last_proven_block: u64,
+    end_block: u64,
+    mut fetch: Fetch,
+) -> Result<T, Error>
+where
+    Fetch: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let mut attempt = 1;
+    loop {
+        match fetch().await {
+            Err(error @ Error::L2ReorgDetected { .. })
+                if attempt < MAX_L2_CHAIN_DATA_FETCH_ATTEMPTS =>
+            {
+                warn!(%attempt, %last_proven_block, %end_block, ?error,
+                    "L2 reorg detected while retrieving the {what}, retrying");
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Fetches the [`L2ChainData`] with every read pinned to the hashes of the
+/// `last_proven_block` and `end_block` blocks, resolved once.
+///
+/// The local exit roots are read by block hash. The output roots and the
+/// sketches can only be requested by number, so the block hash they were
+/// computed at is checked against the pinned one instead.
+async fn fetch_pinned_l2_chain_data<ContractsClient>(
+    contracts_client: &ContractsClient,
+    last_proven_block: u64,
+    end_block: u64,
+    pinned_end_block_hash: Option<Digest>,
+) -> Result<L2ChainData, Error>
+where
+    ContractsClient: L2BlockHashFetcher
+        + L2LocalExitRootFetcher
+        + L2OutputAtBlockFetcher
+        + L2EvmStateSketchFetcher,
+{
+    let prev_block_hash = contracts_client
+        .get_l2_block_hash(last_proven_block)
+        .await
+        .map_err(Error::L2ChainDataRetrievalError)?;
+
+    let new_block_hash = match pinned_end_block_hash {
+        Some(block_hash) => block_hash,
+        None => contracts_client
+            .get_l2_block_hash(end_block)
+            .await
+            .map_err(Error::L2ChainDataRetrievalError)?,
+    };
+
+    let prev_local_exit_root =
+        pinned_local_exit_root(contracts_client, last_proven_block, prev_block_hash).await?;
+
+    let new_local_exit_root =
+        pinned_local_exit_root(contracts_client, end_block, new_block_hash).await?;
+
+    let l2_pre_root_output_at_block = contracts_client
+        .get_l2_output_at_block(last_proven_block)
+        .await
+        .map_err(Error::L2ChainDataRetrievalError)?;
+    check_pinned_block(
+        last_proven_block,
+        prev_block_hash,
+        l2_pre_root_output_at_block.latest_block_hash,
+    )?;
+
+    let claim_root_output_at_block = contracts_client
+        .get_l2_output_at_block(end_block)
+        .await
+        .map_err(Error::L2ChainDataRetrievalError)?;
+    check_pinned_block(
+        end_block,
+        new_block_hash,
+        claim_root_output_at_block.latest_block_hash,
+    )?;
+
+    let prev_l2_block_sketch = contracts_client
+        .get_prev_l2_block_sketch(BlockNumberOrTag::Number(last_proven_block))
+        .await
+        .map_err(Error::L2ChainDataRetrievalError)?;
+    check_pinned_block(
+        last_proven_block,
+        prev_block_hash,
+        sketch_block_hash(&prev_l2_block_sketch),
+    )?;
+
+    let new_l2_block_sketch = contracts_client
+        .get_new_l2_block_sketch(BlockNumberOrTag::Number(end_block))
+        .await
+        .map_err(Error::L2ChainDataRetrievalError)?;
+    check_pinned_block(
+        end_block,
+        new_block_hash,
+        sketch_block_hash(&new_l2_block_sketch),
+    )?;
+
+    Ok(L2ChainData {
+        prev_local_exit_root,
+        new_local_exit_root,
+        l2_pre_root_output_at_block,
+        claim_root_output_at_block,
+        prev_l2_block_sketch,
+        new_l2_block_sketch,
+    })
+}
+
+/// Reads the local exit root at the pinned `block_hash` of `block_number`.
+///
+/// The read fails once the block is reorged out, which is reported as a reorg
+/// when the canonical block has changed since it was pinned.
+async fn pinned_local_exit_root<ContractsClient>(
+    contracts_client: &ContractsClient,
+    block_number: u64,
+    block_hash: Digest,
+) -> Result<Digest, Error>
+where
+    ContractsClient: L2BlockHashFetcher + L2LocalExitRootFetcher,
+{
+    match contracts_client.get_l2_local_exit_root(block_hash).await {
+        Ok(local_exit_root) => Ok(local_exit_root),
+        Err(error) => {
+            let canonical_block_hash = contracts_client
+                .get_l2_block_hash(block_number)
+                .await
+                .map_err(Error::L2ChainDataRetrievalError)?;
+            check_pinned_block(block_number, block_hash, canonical_block_hash)?;
+
+            Err(Error::L2ChainDataRetrievalError(error))
+        }
+    }
+}
+
+fn sketch_block_hash(sketch: &EvmSketchInput) -> Digest {
+    Digest(sketch.anchor.header().hash_slow().0)
+}
+
+pub(crate) fn check_pinned_block(
+    block_number: u64,
+    pinned: Digest,
+    got: Digest,
+) -> Result<(), Error> {
+    if pinned != got {
+        return Err(Error::L2ReorgDetected {
+            block_number,
+            pinned,
+            got,
+        });
+    }
+
+    Ok(())
+}
@@ -22,11 +22,35 @@ pub struct AggchainProofBuilderConfig {
     #[serde(with = "prover_utils::with::HumanDuration")]
     pub proving_timeout: Duration,
 
+    /// Where the bridge events proven by the aggchain proof come from.
+    #[serde(default)]
+    pub bridge_events_source: BridgeEventsSource,
+
+    /// Check the bridge events supplied in the request against the L2 logs
+    /// before building the witness, reporting the missing, extra and
+    /// misordered ones. Ignored when the events are sourced from the L2 logs.
+    #[serde(default)]
+    pub validate_bridge_events: bool,
+
     /// Contract configuration
     #[serde(default)]
     pub contracts: AggchainProofContractsConfig,
 }
 
+/// Source of the inserted and removed GERs, imported bridge exits and unclaims.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum BridgeEventsSource {
+    /// Trust the events supplied by the aggsender in the request.
+    #[default]
+    Request,
+
+    /// Scan the events of the L2 bridge and GER manager contracts, the
+    /// aggsender only supplies the L1 info tree data and the GER inclusion
+    /// proofs.
+    L2Logs,
+}
+
 impl Default for AggchainProofBuilderConfig {
     fn default() -> Self {
         AggchainProofBuilderConfig {
@@ -34,6 +58,8 @@ impl Default for AggchainProofBuilderConfig {
             proving_timeout: default_aggchain_prover_timeout(),
             primary_prover: ProverType::NetworkProver(prover_config::NetworkProverConfig::default()),
             fallback_prover: None,
+            bridge_events_source: BridgeEventsSource::default(),
+            validate_bridge_events: false,
             contracts: AggchainProofContractsConfig::default(),
         }
     }
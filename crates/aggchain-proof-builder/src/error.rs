@@ -1,14 +1,25 @@
 use aggchain_proof_core::full_execution_proof::AggregationProofPublicValues;
 use agglayer_interop::types::bincode;
-use agglayer_primitives::{vkey_hash::VKeyHash, Digest};
+use agglayer_primitives::{vkey_hash::VKeyHash, Digest, U256};
+use alloy_primitives::B256;
 
-use crate::WitnessGeneration;
+use crate::{BridgeEventsDiff, WitnessGeneration};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("Failed to retrieve l2 chain data")]
     L2ChainDataRetrievalError(#[source] aggchain_proof_contracts::Error),
 
+    #[error(
+        "L2 reorg detected at block {block_number}: data read at block hash {got}, pinned to \
+         {pinned}"
+    )]
+    L2ReorgDetected {
+        block_number: u64,
+        pinned: Digest,
+        got: Digest,
+    },
+
     #[error("Failed to retrieve l1 chain data")]
     L1ChainDataRetrievalError(#[source] aggchain_proof_contracts::Error),
 
@@ -36,6 +47,9 @@ pub enum Error {
     #[error("Prover service is not ready")]
     ProverServiceReadyError(#[source] tower::BoxError),
 
+    #[error("Prover backend is unreachable")]
+    ProverBackendUnreachable(#[source] eyre::Report),
+
     #[error(
         "Mismatch on the aggregation vkey hash derived from the elf aggregation vkey. got: \
          {got:?}, expected: {expected:?}"
@@ -67,6 +81,31 @@ pub enum Error {
     #[error("Unable to fetch trusted sequencer address")]
     UnableToFetchTrustedSequencerAddress(#[source] aggchain_proof_contracts::Error),
 
+    #[error(
+        "Missing inclusion proof of the GER {global_exit_root} inserted at block {block_number}, \
+         log index {log_index}"
+    )]
+    MissingGerInclusionProof {
+        global_exit_root: Digest,
+        block_number: u64,
+        log_index: u64,
+    },
+
+    #[error(
+        "Unable to resolve the bridge exit of global index {global_index} claimed through another \
+         contract by transaction {tx_hash} (block {block_number}, log index {log_index}), and \
+         none is supplied in the request"
+    )]
+    UnresolvedClaim {
+        global_index: U256,
+        tx_hash: B256,
+        block_number: u64,
+        log_index: u64,
+    },
+
+    #[error("Bridge events supplied in the request do not match the l2 logs: {0}")]
+    BridgeEventsMismatch(Box<BridgeEventsDiff>),
+
     #[error("Filtering values overflow {0}")]
     FilteringValuesOverflow(usize),
 
@@ -1,3 +1,5 @@
+mod bridge_events;
+mod chain_data;
 pub mod config;
 mod error;
 
@@ -13,8 +15,9 @@ use std::{
 
 use aggchain_proof_contracts::{
     contracts::{
-        GetTrustedSequencerAddress, L1OpSuccinctConfigFetcher, L2EvmStateSketchFetcher,
-        L2LocalExitRootFetcher, L2OutputAtBlockFetcher, OpSuccinctConfig,
+        GetTrustedSequencerAddress, L1OpSuccinctConfigFetcher, L2BlockHashFetcher,
+        L2EvmStateSketchFetcher, L2LocalExitRootFetcher, L2OutputAtBlockFetcher, OpSuccinctConfig,
+        RpcHealthChecker as _,
     },
     AggchainContractsClient,
 };
@@ -25,24 +28,28 @@ use aggchain_proof_core::{
     },
     proof::{AggchainProofWitness, IMPORTED_BRIDGE_EXIT_COMMITMENT_VERSION},
 };
-use aggchain_proof_types::AggchainProofInputs;
+use aggchain_proof_types::{AggchainProofInputs, ProofProgress, ProofStage};
 use aggkit_prover_types::vkey_hash::{Sp1VKeyHash, VKeyHash};
 use agglayer_interop::types::{
     bincode, GlobalIndexWithLeafHash, ImportedBridgeExitCommitmentValues,
 };
 use agglayer_primitives::{Address, Digest, U256};
-use alloy::eips::BlockNumberOrTag;
+use alloy_primitives::B256;
+pub use bridge_events::{BridgeEventMismatch, BridgeEventsDiff, ClaimedBridgeExit, EventPosition};
 pub use error::Error;
 use eyre::Context as _;
 use futures::{future::BoxFuture, FutureExt, TryFutureExt as _};
-use prover_executor::{sp1_async, sp1_fast, Executor, ProofType};
+use prover_executor::{sp1_async, sp1_fast, BackendProbe, Executor, ProofType};
 use serde::{Deserialize, Serialize};
 use sp1_sdk::{HashableKey, SP1Stdin, SP1VerifyingKey};
 use tower::{buffer::Buffer, util::BoxService, ServiceExt as _};
 use tracing::{debug, error, info};
 use unified_bridge::AggchainProofPublicValues;
 
-use crate::config::AggchainProofBuilderConfig;
+use crate::{
+    chain_data::L2ChainData,
+    config::{AggchainProofBuilderConfig, BridgeEventsSource},
+};
 
 const MAX_CONCURRENT_REQUESTS: usize = 100;
 
@@ -65,6 +72,7 @@ pub(crate) type ProverService = Buffer<
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AggchainProverInputs {
     pub output_root: ClaimRoot,
+    pub aggchain_params_values: AggchainParamsValues,
     pub stdin: SP1Stdin,
 }
 
@@ -104,6 +112,9 @@ pub struct AggchainProofBuilderRequest {
 
     /// Aggchain proof partial prover inputs coming from the aggsender request.
     pub aggchain_proof_inputs: AggchainProofInputs,
+
+    /// Observer of the stages of the request.
+    pub progress: ProofProgress,
 }
 
 pub struct AggchainProofBuilderResponse {
@@ -125,11 +136,18 @@ pub struct AggchainProofBuilderResponse {
     /// Output root.
     pub output_root: ClaimRoot,
 
+    /// Values hashed into the aggchain params.
+    pub aggchain_params_values: AggchainParamsValues,
+
     /// New Local exit root.
     pub new_local_exit_root: Digest,
 
     /// The public inputs that were provided to the proof
     pub public_values: AggchainProofPublicValues,
+
+    /// Id of the request of the proof on the sp1 proving network, if proven
+    /// there.
+    pub network_request_id: Option<B256>,
 }
 
 /// Filters out values from a list based on a set of keys to remove, using a key
@@ -302,6 +320,9 @@ pub struct AggchainProofBuilder<ContractsClient> {
     /// Prover client service.
     prover: ProverService,
 
+    /// Probe of the backends of the prover.
+    prover_backend: BackendProbe,
+
     /// Verification key for the aggregated fep proof.
     aggregation_vkey: Arc<SP1VerifyingKey>,
 
@@ -313,6 +334,13 @@ pub struct AggchainProofBuilder<ContractsClient> {
 
     /// Static call caller address.
     static_call_caller_address: Address,
+
+    /// Where the bridge events proven by the aggchain proof come from.
+    bridge_events_source: BridgeEventsSource,
+
+    /// Whether to check the bridge events supplied in the request against
+    /// the L2 logs.
+    validate_bridge_events: bool,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -338,6 +366,7 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
         .context("Failed creating executor for AggchainProofBuilder")?;
 
         let aggchain_vkey = executor.get_vkey().clone();
+        let prover_backend = executor.backend_probe();
         let executor = tower::ServiceBuilder::new().service(executor).boxed();
 
         let prover = Buffer::new(executor, MAX_CONCURRENT_REQUESTS);
@@ -383,13 +412,21 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
             aggchain_vkey,
             contracts_client,
             prover,
+            prover_backend,
             network_id: config.network_id,
             aggregation_vkey,
             range_vkey_commitment,
             static_call_caller_address: config.contracts.static_call_caller_address,
+            bridge_events_source: config.bridge_events_source,
+            validate_bridge_events: config.validate_bridge_events,
         })
     }
 
+    /// Verification key of the aggchain proof program.
+    pub fn aggchain_vkey(&self) -> Arc<SP1VerifyingKey> {
+        self.aggchain_vkey.clone()
+    }
+
     /// Retrieve l1 and l2 public data needed for aggchain proof generation.
     /// Combine with the rest of the inputs to form an `AggchainProverInputs`.
     pub(crate) async fn retrieve_chain_data(
@@ -399,9 +436,11 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
         aggregation_vkey: Arc<SP1VerifyingKey>,
         static_call_caller_address: Address,
         range_vkey_commitment: Digest,
+        pinned_end_block_hash: Option<Digest>,
     ) -> Result<AggchainProverInputs, Error>
     where
-        ContractsClient: L2LocalExitRootFetcher
+        ContractsClient: L2BlockHashFetcher
+            + L2LocalExitRootFetcher
             + L2OutputAtBlockFetcher
             + L2EvmStateSketchFetcher
             + GetTrustedSequencerAddress
@@ -413,26 +452,21 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
         let new_blocks_range =
             (request.aggchain_proof_inputs.last_proven_block + 1)..=request.end_block;
 
-        // Fetch from RPCs
-        let prev_local_exit_root = contracts_client
-            .get_l2_local_exit_root(request.aggchain_proof_inputs.last_proven_block)
-            .await
-            .map_err(Error::L2ChainDataRetrievalError)?;
-
-        let new_local_exit_root = contracts_client
-            .get_l2_local_exit_root(request.end_block)
-            .await
-            .map_err(Error::L2ChainDataRetrievalError)?;
-
-        let l2_pre_root_output_at_block = contracts_client
-            .get_l2_output_at_block(request.aggchain_proof_inputs.last_proven_block)
-            .await
-            .map_err(Error::L2ChainDataRetrievalError)?;
-
-        let claim_root_output_at_block = contracts_client
-            .get_l2_output_at_block(request.end_block)
-            .await
-            .map_err(Error::L2ChainDataRetrievalError)?;
+        // Fetch from RPCs, pinned to a single L2 fork.
+        let L2ChainData {
+            prev_local_exit_root,
+            new_local_exit_root,
+            l2_pre_root_output_at_block,
+            claim_root_output_at_block,
+            prev_l2_block_sketch,
+            new_l2_block_sketch,
+        } = chain_data::fetch_l2_chain_data(
+            contracts_client.as_ref(),
+            request.aggchain_proof_inputs.last_proven_block,
+            request.end_block,
+            pinned_end_block_hash,
+        )
+        .await?;
 
         let op_succinct_config = contracts_client
             .get_op_succinct_config()
@@ -446,18 +480,6 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
             &range_vkey_commitment,
         )?;
 
-        let prev_l2_block_sketch = contracts_client
-            .get_prev_l2_block_sketch(BlockNumberOrTag::Number(
-                request.aggchain_proof_inputs.last_proven_block,
-            ))
-            .await
-            .map_err(Error::L2ChainDataRetrievalError)?;
-
-        let new_l2_block_sketch = contracts_client
-            .get_new_l2_block_sketch(BlockNumberOrTag::Number(request.end_block))
-            .await
-            .map_err(Error::L2ChainDataRetrievalError)?;
-
         let trusted_sequencer = contracts_client
             .get_trusted_sequencer_address()
             .await
@@ -558,9 +580,10 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
                 }
             }
 
+            let aggchain_params_values = AggchainParamsValues::from(&fep_inputs);
             info!(
-                "Aggchain-params unrolled values: {:?}; Aggchain-params keccak-hashed: {}",
-                AggchainParamsValues::from(&fep_inputs),
+                "Aggchain-params unrolled values: {aggchain_params_values:?}; Aggchain-params \
+                 keccak-hashed: {}",
                 fep_inputs.aggchain_params()
             );
 
@@ -614,12 +637,58 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
 
             Ok(AggchainProverInputs {
                 output_root,
+                aggchain_params_values,
                 stdin: sp1_stdin,
             })
         }
     }
 }
 
+impl<ContractsClient> AggchainProofBuilder<ContractsClient>
+where
+    ContractsClient: AggchainContractsClient + Send + Sync,
+{
+    /// Checks the dependencies of the aggchain proof builder: the L1 and L2
+    /// nodes, the op-succinct configuration in the L1 contract, the prover
+    /// service and the backend it sends the proof requests to.
+    pub async fn check_health(&self) -> Result<(), Error> {
+        self.contracts_client
+            .check_l1_rpc()
+            .await
+            .map_err(Error::L1ChainDataRetrievalError)?;
+
+        self.contracts_client
+            .check_l2_rpc()
+            .await
+            .map_err(Error::L2ChainDataRetrievalError)?;
+
+        let op_succinct_config = self
+            .contracts_client
+            .get_op_succinct_config()
+            .await
+            .map_err(Error::L1ChainDataRetrievalError)?;
+
+        validate_op_succinct_config_keys(
+            &op_succinct_config,
+            self.aggregation_vkey.as_ref(),
+            &self.range_vkey_commitment,
+        )?;
+
+        self.prover
+            .clone()
+            .ready()
+            .await
+            .map_err(Error::ProverServiceReadyError)?;
+
+        self.prover_backend
+            .check()
+            .await
+            .map_err(Error::ProverBackendUnreachable)?;
+
+        Ok(())
+    }
+}
+
 /// Validates that the OpSuccinct config keys match the expected values.
 /// This ensures that the same proposer aggregation program is being used.
 fn validate_op_succinct_config_keys(
@@ -677,7 +746,7 @@ where
         })
     }
 
-    fn call(&mut self, req: AggchainProofBuilderRequest) -> Self::Future {
+    fn call(&mut self, mut req: AggchainProofBuilderRequest) -> Self::Future {
         let contracts_client = self.contracts_client.clone();
         let mut prover = self.prover.clone();
         let network_id = self.network_id;
@@ -685,6 +754,8 @@ where
         let aggchain_vkey = self.aggchain_vkey.clone();
         let static_call_caller_address = self.static_call_caller_address;
         let range_vkey_commitment = self.range_vkey_commitment;
+        let bridge_events_source = self.bridge_events_source;
+        let validate_bridge_events = self.validate_bridge_events;
 
         // TODO: figure out a way to stop only this service upon an sp1 panic, and not
         // the entire system. For now, just ignore the panic, even though some
@@ -693,6 +764,29 @@ where
             let last_proven_block = req.aggchain_proof_inputs.last_proven_block;
             let end_block = req.end_block;
             info!(%last_proven_block, %end_block, "Starting generation of the aggchain proof");
+            let progress = req.progress.clone();
+            progress.report(ProofStage::FetchingChainData);
+            // The chain data is read on the fork the bridge events were read on.
+            let pinned_end_block_hash = match bridge_events_source {
+                BridgeEventsSource::L2Logs => Some(
+                    bridge_events::source_from_l2_logs(
+                        contracts_client.as_ref(),
+                        &mut req.aggchain_proof_inputs,
+                        end_block,
+                    )
+                    .await?,
+                ),
+                BridgeEventsSource::Request if validate_bridge_events => Some(
+                    bridge_events::validate_against_l2_logs(
+                        contracts_client.as_ref(),
+                        &req.aggchain_proof_inputs,
+                        end_block,
+                    )
+                    .await?,
+                ),
+                BridgeEventsSource::Request => None,
+            };
+
             // Retrieve all the necessary public inputs. Combine with
             // the data provided by the agg-sender in the request.
             let aggchain_prover_inputs = Self::retrieve_chain_data(
@@ -702,11 +796,17 @@ where
                 aggregation_vkey,
                 static_call_caller_address,
                 range_vkey_commitment,
+                pinned_end_block_hash,
             )
             .await?;
 
             let output_root = aggchain_prover_inputs.output_root;
-            let prover_executor::Response { proof } = prover
+            let aggchain_params_values = aggchain_prover_inputs.aggchain_params_values;
+            progress.report(ProofStage::Proving);
+            let prover_executor::Response {
+                proof,
+                network_request_id,
+            } = prover
                 .ready()
                 .await
                 .map_err(Error::ProverServiceReadyError)?
@@ -738,7 +838,7 @@ where
                 public_input.commit_imported_bridge_exits
             );
 
-            info!(%last_proven_block, %end_block, "Aggchain proof generated");
+            info!(%last_proven_block, %end_block, ?network_request_id, "Aggchain proof generated");
 
             Ok(AggchainProofBuilderResponse {
                 vkey: bincode::default()
@@ -751,8 +851,10 @@ where
                 last_proven_block,
                 end_block,
                 output_root,
+                aggchain_params_values,
                 new_local_exit_root: public_input.new_local_exit_root,
                 public_values: public_input,
+                network_request_id,
             })
         }))
         .map_err(Error::Other)
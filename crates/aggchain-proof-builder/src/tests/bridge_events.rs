@@ -0,0 +1,348 @@
+use std::collections::HashMap;
+
+use aggchain_proof_contracts::{
+    bridge_events::{InsertedGerEvent, L2BridgeEvents, UnresolvedClaim},
+    testutils::{FakeAggchainContractsClient, FakeMethod},
+};
+use aggchain_proof_types::{
+    imported_bridge_exit::{BridgeExitHash, ImportedBridgeExitWithBlockNumber},
+    inserted_ger::{InsertedGer, InsertedGerWithBlockNumber},
+    AggchainProofInputs,
+};
+use agglayer_interop::types::{L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof};
+use agglayer_primitives::{Digest, U256};
+use alloy_primitives::B256;
+
+use crate::{
+    bridge_events::{diff_events, source_from_l2_logs, validate_against_l2_logs},
+    BridgeEventMismatch, BridgeEventsDiff, ClaimedBridgeExit, Error, EventPosition,
+};
+
+const LAST_PROVEN_BLOCK: u64 = 10;
+const END_BLOCK: u64 = 20;
+
+fn at(block_number: u64, log_index: u64) -> EventPosition {
+    EventPosition {
+        block_number,
+        log_index,
+    }
+}
+
+fn ger(byte: u8) -> Digest {
+    Digest([byte; 32])
+}
+
+#[test]
+fn matching_events_have_no_diff() {
+    let events = vec![(at(1, 0), ger(1)), (at(2, 3), ger(2))];
+
+    assert!(diff_events(events.clone(), events).is_empty());
+}
+
+#[test]
+fn missing_extra_and_misordered_events_are_reported() {
+    let supplied = vec![
+        (at(1, 0), ger(1)),
+        (at(2, 1), ger(3)),
+        (at(3, 0), ger(2)),
+        (at(4, 0), ger(4)),
+    ];
+    let emitted = vec![
+        (at(1, 0), ger(1)),
+        (at(2, 0), ger(2)),
+        (at(2, 1), ger(3)),
+        (at(5, 2), ger(5)),
+    ];
+
+    assert_eq!(
+        diff_events(supplied, emitted),
+        vec![
+            BridgeEventMismatch::Misordered {
+                value: ger(2),
+                expected: at(2, 0),
+                got: at(3, 0),
+            },
+            BridgeEventMismatch::Extra {
+                value: ger(4),
+                got: at(4, 0),
+            },
+            BridgeEventMismatch::Missing {
+                value: ger(5),
+                expected: at(5, 2),
+            },
+        ]
+    );
+}
+
+#[test]
+fn violations_are_reported_per_request_field() {
+    let diff = BridgeEventsDiff {
+        removed_gers: vec![BridgeEventMismatch::Extra {
+            value: ger(4),
+            got: at(4, 0),
+        }],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        diff.violations(),
+        vec![(
+            "removed_gers",
+            format!("extra {} at block 4, log index 0, not emitted", ger(4))
+        )]
+    );
+}
+
+fn inserted_ger(global_exit_root: Digest) -> InsertedGer {
+    InsertedGer {
+        proof_ger_l1root: MerkleProof::new(Digest([2; 32]), [Digest::default(); 32]),
+        l1_leaf: L1InfoTreeLeaf {
+            l1_info_tree_index: 42,
+            rer: Digest([4; 32]),
+            mer: Digest([5; 32]),
+            inner: L1InfoTreeLeafInner {
+                global_exit_root,
+                block_hash: Digest([9; 32]),
+                timestamp: 1_700_000_000,
+            },
+        },
+    }
+}
+
+/// Request supplying the inclusion proof of `ger(1)` and the bridge exit of
+/// global index 5, at positions unrelated to the L2 logs.
+fn inputs() -> AggchainProofInputs {
+    let leaf = inserted_ger(ger(1));
+
+    AggchainProofInputs {
+        last_proven_block: LAST_PROVEN_BLOCK,
+        requested_end_block: END_BLOCK,
+        l1_info_tree_root_hash: Digest([2; 32]),
+        l1_info_tree_leaf: leaf.l1_leaf,
+        l1_info_tree_merkle_proof: leaf.proof_ger_l1root.clone(),
+        ger_leaves: HashMap::from([(
+            "AQEBAQ==".to_owned(),
+            InsertedGerWithBlockNumber {
+                block_number: 99,
+                inserted_ger: leaf,
+                log_index: 0,
+            },
+        )]),
+        imported_bridge_exits: vec![ImportedBridgeExitWithBlockNumber {
+            block_number: 99,
+            bridge_exit_hash: BridgeExitHash(Digest([10; 32])),
+            global_index: U256::from(5u64).into(),
+            log_index: 1,
+        }],
+        removed_gers: vec![],
+        unclaims: vec![],
+    }
+}
+
+/// Chain emitting `bridge_events` in the blocks after the last proven one.
+fn fake_chain(bridge_events: L2BridgeEvents) -> FakeAggchainContractsClient {
+    let client = FakeAggchainContractsClient::new().set_bridge_events(bridge_events);
+    client.update(|chain| {
+        for block_number in LAST_PROVEN_BLOCK..=END_BLOCK {
+            chain.block_mut(block_number);
+        }
+    });
+    client
+}
+
+fn unresolved_claim(global_index: u64) -> UnresolvedClaim {
+    UnresolvedClaim {
+        global_index: U256::from(global_index),
+        block_number: 13,
+        log_index: 2,
+        tx_hash: B256::repeat_byte(0xcc),
+    }
+}
+
+#[tokio::test]
+async fn ger_inserted_twice_is_sourced_twice() {
+    let client = fake_chain(L2BridgeEvents {
+        inserted_gers: vec![
+            InsertedGerEvent {
+                global_exit_root: ger(1),
+                block_number: 11,
+                log_index: 0,
+            },
+            InsertedGerEvent {
+                global_exit_root: ger(1),
+                block_number: 12,
+                log_index: 1,
+            },
+        ],
+        ..Default::default()
+    });
+    let mut inputs = inputs();
+
+    source_from_l2_logs(&client, &mut inputs, END_BLOCK)
+        .await
+        .unwrap();
+
+    let inserted_gers = inputs.sorted_inserted_gers(&(LAST_PROVEN_BLOCK + 1..=END_BLOCK));
+    assert_eq!(
+        inserted_gers
+            .iter()
+            .map(|ger| (
+                at(ger.block_number, ger.log_index),
+                ger.l1_info_tree_leaf.inner.global_exit_root
+            ))
+            .collect::<Vec<_>>(),
+        vec![(at(11, 0), ger(1)), (at(12, 1), ger(1))]
+    );
+    assert!(inputs.imported_bridge_exits.is_empty());
+}
+
+#[tokio::test]
+async fn ger_without_inclusion_proof_is_reported() {
+    let client = fake_chain(L2BridgeEvents {
+        inserted_gers: vec![InsertedGerEvent {
+            global_exit_root: ger(2),
+            block_number: 11,
+            log_index: 0,
+        }],
+        ..Default::default()
+    });
+
+    let error = source_from_l2_logs(&client, &mut inputs(), END_BLOCK)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::MissingGerInclusionProof {
+            global_exit_root,
+            block_number: 11,
+            log_index: 0,
+        } if global_exit_root == ger(2)
+    ));
+}
+
+#[tokio::test]
+async fn unresolved_claim_takes_the_supplied_bridge_exit() {
+    let client = fake_chain(L2BridgeEvents {
+        unresolved_claims: vec![unresolved_claim(5)],
+        ..Default::default()
+    });
+    let mut inputs = inputs();
+
+    source_from_l2_logs(&client, &mut inputs, END_BLOCK)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        inputs.imported_bridge_exits,
+        vec![ImportedBridgeExitWithBlockNumber {
+            block_number: 13,
+            bridge_exit_hash: BridgeExitHash(Digest([10; 32])),
+            global_index: U256::from(5u64).into(),
+            log_index: 2,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn unresolved_claim_without_supplied_bridge_exit_is_reported() {
+    let client = fake_chain(L2BridgeEvents {
+        unresolved_claims: vec![unresolved_claim(6)],
+        ..Default::default()
+    });
+
+    let error = source_from_l2_logs(&client, &mut inputs(), END_BLOCK)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::UnresolvedClaim {
+            block_number: 13,
+            log_index: 2,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn unresolved_claim_is_validated_on_its_global_index() {
+    let client = fake_chain(L2BridgeEvents {
+        unresolved_claims: vec![unresolved_claim(5)],
+        ..Default::default()
+    });
+    let mut inputs = inputs();
+    inputs.imported_bridge_exits[0].block_number = 13;
+    inputs.imported_bridge_exits[0].log_index = 2;
+
+    validate_against_l2_logs(&client, &inputs, END_BLOCK)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn unresolved_claim_missing_from_the_request_is_reported() {
+    let client = fake_chain(L2BridgeEvents {
+        unresolved_claims: vec![unresolved_claim(6)],
+        ..Default::default()
+    });
+
+    let error = validate_against_l2_logs(&client, &inputs(), END_BLOCK)
+        .await
+        .unwrap_err();
+
+    let Error::BridgeEventsMismatch(diff) = error else {
+        panic!("Expected a bridge events mismatch, got {error:?}");
+    };
+    assert_eq!(
+        diff.imported_bridge_exits,
+        vec![BridgeEventMismatch::Missing {
+            value: ClaimedBridgeExit {
+                global_index: U256::from(6u64),
+                bridge_exit_hash: None,
+            },
+            expected: at(13, 2),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn bridge_events_are_read_on_the_fork_of_the_end_block() {
+    let client = fake_chain(L2BridgeEvents {
+        inserted_gers: vec![InsertedGerEvent {
+            global_exit_root: ger(1),
+            block_number: 11,
+            log_index: 0,
+        }],
+        ..Default::default()
+    });
+    let end_block_hash = client.update(|chain| chain.block_mut(END_BLOCK).hash);
+
+    let pinned = source_from_l2_logs(&client, &mut inputs(), END_BLOCK)
+        .await
+        .unwrap();
+
+    assert_eq!(pinned, end_block_hash);
+}
+
+#[tokio::test]
+async fn reorg_during_the_bridge_events_scan_is_retried() {
+    // The block of the GER is reorged right after its logs are read.
+    let client = fake_chain(L2BridgeEvents {
+        inserted_gers: vec![InsertedGerEvent {
+            global_exit_root: ger(1),
+            block_number: 11,
+            log_index: 0,
+        }],
+        ..Default::default()
+    })
+    .set_change_after(FakeMethod::L2BridgeEvents, 1, |chain| {
+        chain.block_mut(11).hash = Digest([0xaa; 32]);
+    });
+
+    source_from_l2_logs(&client, &mut inputs(), END_BLOCK)
+        .await
+        .unwrap();
+
+    assert_eq!(client.calls(FakeMethod::L2BridgeEvents), 2);
+}
@@ -0,0 +1,218 @@
+use std::{collections::HashMap, fs::File, io::BufReader, path::Path, sync::Arc};
+
+use aggchain_proof_contracts::{
+    contracts::OpSuccinctConfig,
+    testutils::{FakeAggchainContractsClient, FakeL2Block, FakeMethod},
+};
+use aggchain_proof_core::bridge::BridgeConstraintsInput;
+use aggchain_proof_types::AggchainProofInputs;
+use agglayer_primitives::{address, Address, Digest, Signature, U256};
+
+use crate::{
+    chain_data::fetch_l2_chain_data, AggchainProofBuilder, AggchainProofBuilderRequest, Error,
+    FepVerification,
+};
+
+const LAST_PROVEN_BLOCK: u64 = 10;
+const END_BLOCK: u64 = 20;
+
+/// Bridge constraints test input, with sketches recorded on sepolia.
+fn bridge_constraints_input() -> BridgeConstraintsInput {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../aggchain-proof-core/src/test_input/bridge_constraints_input.json");
+    serde_json::from_reader(BufReader::new(File::open(path).unwrap())).unwrap()
+}
+
+/// Chain with the sketches of the bridge constraints test input at the last
+/// proven block and at the end block.
+fn fake_chain() -> FakeAggchainContractsClient {
+    let input = bridge_constraints_input();
+
+    FakeAggchainContractsClient::new()
+        .set_block(
+            LAST_PROVEN_BLOCK,
+            FakeL2Block::new(LAST_PROVEN_BLOCK)
+                .set_local_exit_root(Digest([1; 32]))
+                .set_sketch(input.bridge_witness.prev_l2_block_sketch),
+        )
+        .set_block(
+            END_BLOCK,
+            FakeL2Block::new(END_BLOCK)
+                .set_local_exit_root(Digest([2; 32]))
+                .set_sketch(input.bridge_witness.new_l2_block_sketch),
+        )
+}
+
+#[tokio::test]
+async fn chain_data_is_read_at_the_pinned_blocks() {
+    let client = fake_chain();
+
+    let chain_data = fetch_l2_chain_data(&client, LAST_PROVEN_BLOCK, END_BLOCK, None)
+        .await
+        .unwrap();
+
+    assert_eq!(chain_data.prev_local_exit_root, Digest([1; 32]));
+    assert_eq!(chain_data.new_local_exit_root, Digest([2; 32]));
+    assert_eq!(
+        chain_data.claim_root_output_at_block.latest_block_hash,
+        client.update(|chain| chain.block_mut(END_BLOCK).hash)
+    );
+    assert_eq!(client.calls(FakeMethod::L2BlockHash), 2);
+}
+
+#[tokio::test]
+async fn transient_l2_reorg_is_retried() {
+    let client = fake_chain();
+    let canonical_end_block = client.update(|chain| chain.block_mut(END_BLOCK).clone());
+
+    // The end block is reorged out once its hash is pinned, and back in before
+    // the retry.
+    let client = client
+        .set_change_after(FakeMethod::L2LocalExitRoot, 2, |chain| {
+            chain.block_mut(END_BLOCK).hash = Digest([0xaa; 32]);
+        })
+        .set_change_after(FakeMethod::L2OutputAtBlock, 2, move |chain| {
+            chain.blocks.insert(END_BLOCK, canonical_end_block);
+        });
+
+    let chain_data = fetch_l2_chain_data(&client, LAST_PROVEN_BLOCK, END_BLOCK, None)
+        .await
+        .unwrap();
+
+    assert_eq!(chain_data.new_local_exit_root, Digest([2; 32]));
+    assert_eq!(client.calls(FakeMethod::L2BlockHash), 4);
+}
+
+#[tokio::test]
+async fn persistent_l2_reorg_is_reported() {
+    // The end block is reorged for good, while the node keeps serving the
+    // sketch of the reorged out block.
+    let client = fake_chain().set_change_after(FakeMethod::L2LocalExitRoot, 2, |chain| {
+        chain.block_mut(END_BLOCK).hash = Digest([0xaa; 32]);
+    });
+
+    let result = fetch_l2_chain_data(&client, LAST_PROVEN_BLOCK, END_BLOCK, None).await;
+
+    assert!(matches!(
+        result,
+        Err(Error::L2ReorgDetected {
+            block_number: END_BLOCK,
+            ..
+        })
+    ));
+    assert_eq!(client.calls(FakeMethod::L2BlockHash), 6);
+}
+
+#[tokio::test]
+async fn l2_node_failures_are_not_retried() {
+    let client = fake_chain().set_failures(FakeMethod::L2OutputAtBlock, 1);
+
+    let result = fetch_l2_chain_data(&client, LAST_PROVEN_BLOCK, END_BLOCK, None).await;
+
+    assert!(matches!(result, Err(Error::L2ChainDataRetrievalError(_))));
+    assert_eq!(client.calls(FakeMethod::L2BlockHash), 2);
+}
+
+#[tokio::test]
+async fn reorg_failing_a_local_exit_root_read_is_retried() {
+    let client = fake_chain();
+    let canonical_end_block = client.update(|chain| chain.block_mut(END_BLOCK).clone());
+
+    // The end block is reorged out once its hash is pinned, failing the read of
+    // its local exit root, and back in once the reorg is detected.
+    let client = client
+        .set_change_after(FakeMethod::L2BlockHash, 2, |chain| {
+            chain.block_mut(END_BLOCK).hash = Digest([0xaa; 32]);
+        })
+        .set_change_after(FakeMethod::L2BlockHash, 3, move |chain| {
+            chain.blocks.insert(END_BLOCK, canonical_end_block);
+        });
+
+    let chain_data = fetch_l2_chain_data(&client, LAST_PROVEN_BLOCK, END_BLOCK, None)
+        .await
+        .unwrap();
+
+    assert_eq!(chain_data.new_local_exit_root, Digest([2; 32]));
+    assert_eq!(client.calls(FakeMethod::L2BlockHash), 5);
+}
+
+#[tokio::test]
+async fn chain_data_is_read_on_the_fork_of_the_bridge_events() {
+    let client = fake_chain();
+
+    let result = fetch_l2_chain_data(
+        &client,
+        LAST_PROVEN_BLOCK,
+        END_BLOCK,
+        Some(Digest([0xbb; 32])),
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::L2ReorgDetected {
+            block_number: END_BLOCK,
+            pinned: Digest([0xbb; 32]),
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn chain_data_of_an_optimistic_request_is_retrieved_offline() {
+    const TRUSTED_SEQUENCER: Address = address!("0x0000000000000000000000000000000000000005");
+
+    let input = bridge_constraints_input();
+    let client = fake_chain()
+        .set_op_succinct_config(OpSuccinctConfig {
+            aggregation_vkey_hash: Digest(proposer_elfs::aggregation::vkey().bytes32_raw()),
+            range_vkey_commitment: Digest(proposer_elfs::range::commitment()),
+            rollup_config_hash: Digest([0x11; 32]),
+        })
+        .set_trusted_sequencer(TRUSTED_SEQUENCER);
+
+    // Anchored at the latest L1 info tree leaf of the input.
+    let anchor = input.bridge_witness.inserted_gers.last().unwrap();
+    let request = AggchainProofBuilderRequest {
+        fep_verification: FepVerification::Optimistic {
+            signature: Signature::try_from(&[1u8; 65][..]).unwrap(),
+        },
+        end_block: END_BLOCK,
+        aggchain_proof_inputs: AggchainProofInputs {
+            last_proven_block: LAST_PROVEN_BLOCK,
+            requested_end_block: END_BLOCK,
+            l1_info_tree_root_hash: input.l1_info_root,
+            l1_info_tree_leaf: anchor.l1_info_tree_leaf,
+            l1_info_tree_merkle_proof: anchor.proof.clone(),
+            ger_leaves: HashMap::new(),
+            imported_bridge_exits: Vec::new(),
+            removed_gers: Vec::new(),
+            unclaims: Vec::new(),
+        },
+        progress: Default::default(),
+    };
+    let end_block_hash = client.update(|chain| chain.block_mut(END_BLOCK).hash);
+
+    let inputs = AggchainProofBuilder::<FakeAggchainContractsClient>::retrieve_chain_data(
+        Arc::new(client),
+        request,
+        1,
+        Arc::new(proposer_elfs::aggregation::vkey().clone()),
+        input.bridge_witness.caller_address,
+        Digest(proposer_elfs::range::commitment()),
+        Some(end_block_hash),
+    )
+    .await
+    .unwrap();
+
+    let params = inputs.aggchain_params_values;
+    assert_eq!(params.claim_root, inputs.output_root.into());
+    assert_eq!(params.claim_block_num, U256::from(END_BLOCK));
+    assert_eq!(params.rollup_config_hash, [0x11; 32].into());
+    assert!(params.optimistic_mode);
+    assert_eq!(params.trusted_sequencer, TRUSTED_SEQUENCER);
+    assert_eq!(
+        params.range_vkey_commitment,
+        proposer_elfs::range::commitment().into()
+    );
+}
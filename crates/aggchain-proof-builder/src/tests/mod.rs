@@ -1,5 +1,8 @@
 use crate::AggchainProverInputs;
 
+mod bridge_events;
+mod chain_data;
+
 #[allow(unused)]
 pub fn dump_aggchain_prover_inputs_json(
     aggchain_prover_inputs: &AggchainProverInputs,
@@ -39,6 +42,7 @@ mod aggchain_proof_builder {
                 proving_timeout: Duration::from_secs(3600),
                 proving_request_timeout: Some(Duration::from_secs(600)),
                 sp1_cluster_endpoint: "https://rpc.production.succinct.xyz/".parse()?,
+                ..Default::default()
             }),
             None,
             crate::AGGCHAIN_PROOF_ELF,
@@ -59,7 +63,7 @@ mod aggchain_proof_builder {
             "src/tests/data/aggchain_prover_inputs_001_lpb_1_eb_4.json",
         )?;
 
-        let prover_executor::Response { proof } = prover
+        let prover_executor::Response { proof, .. } = prover
             .ready()
             .await
             .map_err(Error::ProverServiceReadyError)?
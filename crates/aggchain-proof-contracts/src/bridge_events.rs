@@ -0,0 +1,295 @@
+//! Bridge events emitted on the L2 by the bridge and the
+//! `GlobalExitRootManagerL2SovereignChain` contracts.
+
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+use aggchain_proof_types::{
+    imported_bridge_exit::{BridgeExitHash, ImportedBridgeExitWithBlockNumber},
+    removed_ger::RemovedGerWithBlockNumber,
+    unclaim::UnclaimWithBlockNumber,
+};
+use agglayer_interop::types::Digest;
+use alloy::{
+    primitives::{keccak256, Address, B256, U256},
+    rpc::types::{trace::geth::CallFrame, Log},
+    sol,
+    sol_types::{SolCall, SolEvent, SolValue},
+};
+
+use crate::{
+    contracts::{GlobalExitRootManagerL2SovereignChain, PolygonZkevmBridgeV2},
+    Error,
+};
+
+sol! {
+    /// Events of the sovereign chain bridge missing from the
+    /// `PolygonZkEVMBridgeV2` ABI.
+    interface BridgeL2SovereignChainEvents {
+        event UpdatedUnsetGlobalIndexHashChain(
+            bytes32 unsetGlobalIndex,
+            bytes32 newUnsetGlobalIndexHashChain
+        );
+    }
+}
+
+/// Leaf type of the asset bridge exits.
+const LEAF_TYPE_ASSET: u8 = 0;
+
+/// Leaf type of the message bridge exits.
+const LEAF_TYPE_MESSAGE: u8 = 1;
+
+/// GER inserted in the `GlobalExitRootManagerL2SovereignChain` contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsertedGerEvent {
+    pub global_exit_root: Digest,
+    pub block_number: u64,
+    pub log_index: u64,
+}
+
+/// Claim emitted on the L2 whose bridge exit could be resolved neither from
+/// the calldata nor from the call trace of its transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnresolvedClaim {
+    pub global_index: U256,
+    pub block_number: u64,
+    pub log_index: u64,
+    pub tx_hash: B256,
+}
+
+/// Bridge events emitted on the L2 in a block range, each list sorted by
+/// `(block_number, log_index)`.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct L2BridgeEvents {
+    pub inserted_gers: Vec<InsertedGerEvent>,
+    pub removed_gers: Vec<RemovedGerWithBlockNumber>,
+    pub imported_bridge_exits: Vec<ImportedBridgeExitWithBlockNumber>,
+    pub unclaims: Vec<UnclaimWithBlockNumber>,
+
+    /// Claims made through another contract on a node not serving the call
+    /// traces, left to the caller to resolve.
+    pub unresolved_claims: Vec<UnresolvedClaim>,
+
+    /// Hashes of the blocks the events were read at, by block number, for the
+    /// caller to check that they are all on the same fork.
+    pub block_hashes: BTreeMap<u64, Digest>,
+}
+
+/// Topics of the events making up the [`L2BridgeEvents`].
+pub(crate) fn event_signatures() -> Vec<B256> {
+    vec![
+        GlobalExitRootManagerL2SovereignChain::UpdateHashChainValue::SIGNATURE_HASH,
+        GlobalExitRootManagerL2SovereignChain::UpdateRemovalHashChainValue::SIGNATURE_HASH,
+        PolygonZkevmBridgeV2::ClaimEvent::SIGNATURE_HASH,
+        BridgeL2SovereignChainEvents::UpdatedUnsetGlobalIndexHashChain::SIGNATURE_HASH,
+    ]
+}
+
+/// Splits `range` in consecutive ranges of at most `max_len` blocks.
+pub(crate) fn block_ranges(
+    range: RangeInclusive<u64>,
+    max_len: u64,
+) -> impl Iterator<Item = RangeInclusive<u64>> {
+    let max_len = max_len.max(1);
+    let end = *range.end();
+
+    range
+        .step_by(max_len as usize)
+        .map(move |start| start..=start.saturating_add(max_len - 1).min(end))
+}
+
+/// Position of a log in the chain.
+pub(crate) struct LogPosition {
+    pub(crate) block_number: u64,
+    pub(crate) block_hash: B256,
+    pub(crate) log_index: u64,
+    pub(crate) transaction_hash: B256,
+}
+
+impl LogPosition {
+    pub(crate) fn of(log: &Log) -> Result<Self, Error> {
+        match (
+            log.block_number,
+            log.block_hash,
+            log.log_index,
+            log.transaction_hash,
+        ) {
+            (Some(block_number), Some(block_hash), Some(log_index), Some(transaction_hash)) => {
+                Ok(Self {
+                    block_number,
+                    block_hash,
+                    log_index,
+                    transaction_hash,
+                })
+            }
+            _ => Err(Error::PendingL2BridgeEvent),
+        }
+    }
+}
+
+/// L2 event relevant to the aggchain proof, before the claims are resolved to
+/// their bridge exit.
+pub(crate) enum L2BridgeEvent {
+    InsertedGer(Digest),
+    RemovedGer(Digest),
+    Claim(U256),
+    Unclaim(U256),
+}
+
+impl L2BridgeEvent {
+    /// Decodes `log`, returning `None` for the events not relevant to the
+    /// aggchain proof.
+    pub(crate) fn decode(log: &Log) -> Result<Option<Self>, Error> {
+        let Some(topic) = log.topic0() else {
+            return Ok(None);
+        };
+
+        let event = match *topic {
+            GlobalExitRootManagerL2SovereignChain::UpdateHashChainValue::SIGNATURE_HASH => {
+                let event = log
+                    .log_decode::<GlobalExitRootManagerL2SovereignChain::UpdateHashChainValue>()
+                    .map_err(Error::MalformedL2BridgeEvent)?;
+                Self::InsertedGer(event.inner.data.newGlobalExitRoot.0.into())
+            }
+            GlobalExitRootManagerL2SovereignChain::UpdateRemovalHashChainValue::SIGNATURE_HASH => {
+                let event = log
+                    .log_decode::<GlobalExitRootManagerL2SovereignChain::UpdateRemovalHashChainValue>()
+                    .map_err(Error::MalformedL2BridgeEvent)?;
+                Self::RemovedGer(event.inner.data.removedGlobalExitRoot.0.into())
+            }
+            PolygonZkevmBridgeV2::ClaimEvent::SIGNATURE_HASH => {
+                let event = log
+                    .log_decode::<PolygonZkevmBridgeV2::ClaimEvent>()
+                    .map_err(Error::MalformedL2BridgeEvent)?;
+                Self::Claim(event.inner.data.globalIndex)
+            }
+            BridgeL2SovereignChainEvents::UpdatedUnsetGlobalIndexHashChain::SIGNATURE_HASH => {
+                let event = log
+                    .log_decode::<BridgeL2SovereignChainEvents::UpdatedUnsetGlobalIndexHashChain>()
+                    .map_err(Error::MalformedL2BridgeEvent)?;
+                Self::Unclaim(U256::from_be_bytes(event.inner.data.unsetGlobalIndex.0))
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(event))
+    }
+}
+
+/// Computes the hash of the bridge exit claimed by `input`, the calldata of
+/// a `claimAsset` or `claimMessage` call on the bridge.
+///
+/// The claim events do not carry the leaf type nor the metadata of the bridge
+/// exit, so `None` is returned for the claims made through another contract,
+/// to be resolved with [`claimed_bridge_exit_hash_in_trace`], as well as for
+/// the calls claiming another global index than `global_index`.
+pub(crate) fn claimed_bridge_exit_hash(input: &[u8], global_index: U256) -> Option<BridgeExitHash> {
+    let (leaf_type, claim) =
+        if let Ok(call) = PolygonZkevmBridgeV2::claimAssetCall::abi_decode(input) {
+            (
+                LEAF_TYPE_ASSET,
+                (
+                    call.globalIndex,
+                    call.originNetwork,
+                    call.originTokenAddress,
+                    call.destinationNetwork,
+                    call.destinationAddress,
+                    call.amount,
+                    call.metadata,
+                ),
+            )
+        } else if let Ok(call) = PolygonZkevmBridgeV2::claimMessageCall::abi_decode(input) {
+            (
+                LEAF_TYPE_MESSAGE,
+                (
+                    call.globalIndex,
+                    call.originNetwork,
+                    call.originAddress,
+                    call.destinationNetwork,
+                    call.destinationAddress,
+                    call.amount,
+                    call.metadata,
+                ),
+            )
+        } else {
+            return None;
+        };
+
+    let (
+        claimed_global_index,
+        origin_network,
+        origin_address,
+        destination_network,
+        destination_address,
+        amount,
+        metadata,
+    ) = claim;
+    if claimed_global_index != global_index {
+        return None;
+    }
+
+    Some(BridgeExitHash(
+        bridge_exit_leaf_hash(
+            leaf_type,
+            origin_network,
+            origin_address,
+            destination_network,
+            destination_address,
+            amount,
+            &metadata,
+        )
+        .0
+        .into(),
+    ))
+}
+
+/// Computes the hash of the bridge exit claimed in the call `trace` of a
+/// transaction, from the `claimAsset` or `claimMessage` call on the `bridge`
+/// claiming `global_index`.
+///
+/// Resolves the claims made through a multicall, a forwarder or a smart
+/// account. The reverted calls are skipped along with their subcalls.
+pub(crate) fn claimed_bridge_exit_hash_in_trace(
+    trace: &CallFrame,
+    bridge: Address,
+    global_index: U256,
+) -> Option<BridgeExitHash> {
+    if trace.error.is_some() {
+        return None;
+    }
+
+    if trace.to == Some(bridge) {
+        if let Some(bridge_exit_hash) = claimed_bridge_exit_hash(&trace.input, global_index) {
+            return Some(bridge_exit_hash);
+        }
+    }
+
+    trace
+        .calls
+        .iter()
+        .find_map(|call| claimed_bridge_exit_hash_in_trace(call, bridge, global_index))
+}
+
+/// Leaf value of a bridge exit in the local exit tree, as computed by
+/// `getLeafValue` in the bridge contract.
+pub(crate) fn bridge_exit_leaf_hash(
+    leaf_type: u8,
+    origin_network: u32,
+    origin_address: Address,
+    destination_network: u32,
+    destination_address: Address,
+    amount: U256,
+    metadata: &[u8],
+) -> B256 {
+    keccak256(
+        (
+            leaf_type,
+            origin_network,
+            origin_address,
+            destination_network,
+            destination_address,
+            amount,
+            keccak256(metadata),
+        )
+            .abi_encode_packed(),
+    )
+}
@@ -1,10 +1,9 @@
 use agglayer_primitives::{address, Address};
-use prover_alloy::L1RpcEndpoint;
+use prover_alloy::{L1RpcEndpoint, RpcEndpoints, RpcFixtureConfig};
 use prover_utils::from_env_or_default;
 use serde::{Deserialize, Serialize};
 use sp1_cc_client_executor::Genesis;
 use tracing::info;
-use url::Url;
 
 /// Address of the `GlobalExitRootManagerL2SovereignChain.sol` contract
 /// on the L2 chain is always fixed.
@@ -21,17 +20,17 @@ const STATIC_CALL_CALLER_ADDRESS: Address = address!("0x39027D57969aD59161365e0b
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct AggchainProofContractsConfig {
-    /// JSON-RPC endpoint of the l1 node.
+    /// JSON-RPC endpoints of the l1 node.
     #[serde(default)]
     pub l1_rpc_endpoint: L1RpcEndpoint,
 
-    /// JSON-RPC endpoint of the l2 execution node.
-    #[serde(default = "prover_alloy::default_l2_execution_layer_url")]
-    pub l2_execution_layer_rpc_endpoint: Url,
+    /// JSON-RPC endpoints of the l2 execution node.
+    #[serde(default = "prover_alloy::default_l2_execution_layer_endpoints")]
+    pub l2_execution_layer_rpc_endpoint: RpcEndpoints,
 
-    /// JSON-RPC endpoint of the l2 rollup node.
-    #[serde(default = "prover_alloy::default_l2_consensus_layer_url")]
-    pub l2_consensus_layer_rpc_endpoint: Url,
+    /// JSON-RPC endpoints of the l2 rollup node.
+    #[serde(default = "prover_alloy::default_l2_consensus_layer_endpoints")]
+    pub l2_consensus_layer_rpc_endpoint: RpcEndpoints,
 
     /// Address of the L1 PolygonRollupManager.sol contract
     #[serde(default = "default_polygon_rollup_manager")]
@@ -49,19 +48,31 @@ pub struct AggchainProofContractsConfig {
     /// or path to a custom genesis file.
     #[serde(default = "default_evm_sketch_genesis")]
     pub evm_sketch_genesis: String,
+
+    /// Maximum number of blocks covered by a single `eth_getLogs` request
+    /// when scanning the l2 bridge events.
+    #[serde(default = "default_l2_logs_max_block_range")]
+    pub l2_logs_max_block_range: u64,
+
+    /// Records the rpc traffic of the nodes to a fixture file, or replays it
+    /// from one without any network access, for the tests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_fixture: Option<RpcFixtureConfig>,
 }
 
 impl Default for AggchainProofContractsConfig {
     fn default() -> Self {
         Self {
             l1_rpc_endpoint: L1RpcEndpoint::default(),
-            l2_execution_layer_rpc_endpoint: prover_alloy::default_l2_execution_layer_url(),
-            l2_consensus_layer_rpc_endpoint: prover_alloy::default_l2_consensus_layer_url(),
+            l2_execution_layer_rpc_endpoint: prover_alloy::default_l2_execution_layer_endpoints(),
+            l2_consensus_layer_rpc_endpoint: prover_alloy::default_l2_consensus_layer_endpoints(),
             polygon_rollup_manager: default_polygon_rollup_manager(),
             global_exit_root_manager_v2_sovereign_chain:
                 default_global_exit_root_manager_v2_sovereign_chain(),
             static_call_caller_address: default_static_call_caller_address(),
             evm_sketch_genesis: default_evm_sketch_genesis(),
+            l2_logs_max_block_range: default_l2_logs_max_block_range(),
+            rpc_fixture: None,
         }
     }
 }
@@ -89,6 +100,10 @@ fn default_evm_sketch_genesis() -> String {
     String::from("mainnet")
 }
 
+fn default_l2_logs_max_block_range() -> u64 {
+    10_000
+}
+
 pub(crate) fn parse_evm_sketch_genesis(evm_sketch_genesis: &str) -> Result<Genesis, crate::Error> {
     let evm_sketch_genesis = evm_sketch_genesis.trim();
     if evm_sketch_genesis.is_empty() {
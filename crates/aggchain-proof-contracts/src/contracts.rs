@@ -1,9 +1,11 @@
+use std::ops::RangeInclusive;
+
 use agglayer_interop::types::Digest;
 use agglayer_primitives::Address;
 use alloy::{eips::BlockNumberOrTag, network::Ethereum, sol};
 use sp1_cc_client_executor::io::EvmSketchInput;
 
-use crate::Error;
+use crate::{bridge_events::L2BridgeEvents, Error};
 
 sol!(
     #[allow(missing_docs)]
@@ -52,9 +54,17 @@ pub(crate) type GlobalExitRootManagerL2SovereignChainRpcClient<RpcProvider> =
         Ethereum,
     >;
 
+#[async_trait::async_trait]
+pub trait L2BlockHashFetcher {
+    /// Resolves the hash of the canonical L2 block at `block_number`.
+    async fn get_l2_block_hash(&self, block_number: u64) -> Result<Digest, Error>;
+}
+
 #[async_trait::async_trait]
 pub trait L2LocalExitRootFetcher {
-    async fn get_l2_local_exit_root(&self, block_number: u64) -> Result<Digest, Error>;
+    /// Reads the local exit root at the L2 block `block_hash`, failing if the
+    /// block is no longer canonical.
+    async fn get_l2_local_exit_root(&self, block_hash: Digest) -> Result<Digest, Error>;
 }
 
 #[async_trait::async_trait]
@@ -85,6 +95,31 @@ pub trait L2EvmStateSketchFetcher {
     ) -> Result<EvmSketchInput, Error>;
 }
 
+#[async_trait::async_trait]
+pub trait L2BridgeEventsFetcher {
+    /// Scans the L2 bridge and `GlobalExitRootManagerL2SovereignChain` events
+    /// emitted in `block_range`.
+    async fn get_l2_bridge_events(
+        &self,
+        block_range: RangeInclusive<u64>,
+    ) -> Result<L2BridgeEvents, Error>;
+}
+
+#[async_trait::async_trait]
+pub trait L2SyncStatusFetcher {
+    /// Retrieves the unsafe, safe and finalized heads from the rollup node.
+    async fn get_l2_sync_status(&self) -> Result<L2SyncStatus, Error>;
+}
+
+#[async_trait::async_trait]
+pub trait RpcHealthChecker {
+    /// Checks that the L1 node answers requests.
+    async fn check_l1_rpc(&self) -> Result<(), Error>;
+
+    /// Checks that the L2 execution and rollup nodes answer requests.
+    async fn check_l2_rpc(&self) -> Result<(), Error>;
+}
+
 /// L2 output at block data structure.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct L2OutputAtBlock {
@@ -95,6 +130,17 @@ pub struct L2OutputAtBlock {
     pub output_root: Digest,
 }
 
+/// Heads of the L2 chain as seen by the rollup node.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct L2SyncStatus {
+    /// Latest block, not yet derived from the L1.
+    pub unsafe_l2: u64,
+    /// Latest block derived from the L1.
+    pub safe_l2: u64,
+    /// Latest block derived from finalized L1 blocks.
+    pub finalized_l2: u64,
+}
+
 /// Configuration parameters for the OP Succinct verification.
 /// This config is retrieved from the Aggchain FEP contract, map of
 /// opSuccinctConfigs.
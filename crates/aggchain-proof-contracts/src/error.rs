@@ -1,4 +1,5 @@
 use aggchain_proof_core::bridge::static_call::StaticCallStage;
+use alloy::primitives::B256;
 use sp1_cc_host_executor::HostError;
 
 #[derive(thiserror::Error, Debug)]
@@ -21,6 +22,12 @@ pub enum Error {
     #[error("Unable to retrieve aggchain fep address from the polygon rollup manager contract")]
     AggchainFepAddressError(#[source] alloy::contract::Error),
 
+    #[error("Error retrieving l2 block hash")]
+    L2BlockHashRetrievalError(#[source] alloy::transports::TransportError),
+
+    #[error("L2 block {0} not found")]
+    L2BlockNotFound(u64),
+
     #[error("Error retrieving local exit root")]
     LocalExitRootError(#[source] alloy::contract::Error),
 
@@ -28,10 +35,10 @@ pub enum Error {
     AsyncEngineSetupError(#[source] std::io::Error),
 
     #[error("Unable to create HTTP RPC rollup node client")]
-    RollupNodeInitError(#[source] jsonrpsee::core::ClientError),
+    RollupNodeInitError(#[source] eyre::Error),
 
     #[error("Error retrieving l2 output at block from the node")]
-    L2OutputAtBlockRetrievalError(#[source] jsonrpsee::core::ClientError),
+    L2OutputAtBlockRetrievalError(#[source] alloy::transports::TransportError),
 
     #[error("L2 output at block value is missing, field {0}")]
     L2OutputAtBlockValueMissing(String),
@@ -39,6 +46,12 @@ pub enum Error {
     #[error("Invalid L2 output at block, field {0}")]
     L2OutputAtBlockInvalidValue(String, #[source] alloy::hex::FromHexError),
 
+    #[error("Error retrieving l2 sync status from the node")]
+    L2SyncStatusRetrievalError(#[source] alloy::transports::TransportError),
+
+    #[error("L2 sync status value is missing, field {0}")]
+    L2SyncStatusValueMissing(String),
+
     #[error("Error performing rollup manager rollup id to rollup data call")]
     InvalidRollupIdToRollupData(#[source] alloy::contract::Error),
 
@@ -72,6 +85,27 @@ pub enum Error {
     #[error("Unable to retrieve trusted sequencer address")]
     UnableToRetrieveTrustedSequencerAddress(#[source] alloy::contract::Error),
 
+    #[error("L1 node is unreachable")]
+    L1RpcUnreachable(#[source] alloy::transports::TransportError),
+
+    #[error("L2 execution layer node is unreachable")]
+    L2ExecutionLayerRpcUnreachable(#[source] alloy::transports::TransportError),
+
+    #[error("L2 rollup node is unreachable")]
+    L2ConsensusLayerRpcUnreachable(#[source] alloy::transports::TransportError),
+
+    #[error("Error retrieving the l2 bridge events")]
+    L2BridgeEventsRetrievalError(#[source] alloy::transports::TransportError),
+
+    #[error("Malformed l2 bridge event")]
+    MalformedL2BridgeEvent(#[source] alloy::sol_types::Error),
+
+    #[error("L2 bridge event is not included in a block yet")]
+    PendingL2BridgeEvent,
+
+    #[error("Claim transaction {tx_hash} not found")]
+    ClaimTransactionNotFound { tx_hash: B256 },
+
     #[error("Invalid evm sketch genesis input: {0}")]
     InvalidEvmSketchGenesisInput(String),
 
@@ -1,37 +1,54 @@
+pub mod bridge_events;
 pub mod config;
 pub mod contracts;
 mod error;
+#[cfg(feature = "testutils")]
+pub mod testutils;
 
 #[cfg(test)]
 mod tests;
 
-use std::{panic::AssertUnwindSafe, str::FromStr, sync::Arc};
+use std::{ops::RangeInclusive, panic::AssertUnwindSafe, str::FromStr};
 
 use aggchain_proof_core::bridge::{
     static_call::{HashChainType, StaticCallStage},
     BridgeL2SovereignChain,
 };
+use aggchain_proof_types::{
+    imported_bridge_exit::{BridgeExitHash, ImportedBridgeExitWithBlockNumber},
+    removed_ger::RemovedGerWithBlockNumber,
+    unclaim::UnclaimWithBlockNumber,
+};
 use agglayer_interop::types::Digest;
 use agglayer_primitives::Address;
 use alloy::{
-    eips::BlockNumberOrTag, network::AnyNetwork, primitives::B256, providers::Provider,
+    consensus::Transaction as _,
+    eips::{BlockId, BlockNumberOrTag, RpcBlockHash},
+    network::AnyNetwork,
+    primitives::{B256, U256},
+    providers::{Provider, ProviderBuilder, RootProvider},
+    rpc::{
+        client::RpcClient,
+        types::{trace::geth::CallFrame, Filter},
+    },
     sol_types::SolCall,
 };
+use bridge_events::{
+    InsertedGerEvent, L2BridgeEvent, L2BridgeEvents, LogPosition, UnresolvedClaim,
+};
 use contracts::{
-    GetTrustedSequencerAddress, GlobalExitRootManagerL2SovereignChainRpcClient,
-    L2EvmStateSketchFetcher,
+    GetTrustedSequencerAddress, GlobalExitRootManagerL2SovereignChainRpcClient, L2BlockHashFetcher,
+    L2BridgeEventsFetcher, L2EvmStateSketchFetcher,
 };
 use eyre::Context as _;
-use jsonrpsee::{core::client::ClientT, http_client::HttpClient, rpc_params};
-use prover_alloy::{build_alloy_fill_provider, AlloyFillProvider};
+use prover_alloy::{build_alloy_fill_provider, build_rpc_client, AlloyFillProvider, RpcFixture};
 use prover_executor::sp1_async;
 use sp1_cc_client_executor::{
     io::{EvmSketchInput, Primitives},
     ContractInput, Genesis,
 };
 use sp1_cc_host_executor::EvmSketch;
-use tracing::{debug, info};
-use url::Url;
+use tracing::{debug, info, warn};
 
 pub use crate::error::Error;
 use crate::{
@@ -39,7 +56,7 @@ use crate::{
     contracts::{
         AggchainFep, AggchainFepRpcClient, GlobalExitRootManagerL2SovereignChain,
         L1OpSuccinctConfigFetcher, L2LocalExitRootFetcher, L2OutputAtBlock, L2OutputAtBlockFetcher,
-        OpSuccinctConfig, PolygonRollupManagerRpcClient, PolygonZkevmBridgeV2,
+        OpSuccinctConfig, PolygonRollupManagerRpcClient, PolygonZkevmBridgeV2, RpcHealthChecker,
         ZkevmBridgeRpcClient,
     },
 };
@@ -47,10 +64,14 @@ use crate::{
 /// `AggchainContractsClient` is a trait for interacting with the smart
 /// contracts relevant for the aggchain prover.
 pub trait AggchainContractsClient:
-    L2LocalExitRootFetcher
+    L2BlockHashFetcher
+    + L2LocalExitRootFetcher
     + L2OutputAtBlockFetcher
     + L1OpSuccinctConfigFetcher
     + L2EvmStateSketchFetcher
+    + L2BridgeEventsFetcher
+    + L2SyncStatusFetcher
+    + RpcHealthChecker
 {
 }
 
@@ -58,11 +79,11 @@ pub trait AggchainContractsClient:
 /// smart contracts relevant for the aggchain prover.
 #[derive(Clone)]
 pub struct AggchainContractsRpcClient<RpcProvider> {
-    /// Url for the evm state sketch builder.
-    l2_root_provider_endpoint: Url,
+    /// L2 rpc execution layer client, for the evm state sketch builder.
+    l2_el_rpc_client: RpcClient,
 
     /// L2 rpc consensus layer client (rollup node).
-    l2_cl_client: Arc<HttpClient>,
+    l2_cl_client: RpcClient,
 
     /// Polygon zkevm bridge contract on the l2 network.
     polygon_zkevm_bridge_v2: ZkevmBridgeRpcClient<RpcProvider>,
@@ -84,21 +105,45 @@ pub struct AggchainContractsRpcClient<RpcProvider> {
 
     /// Aggchain FEP opSuccinctConfig name.
     op_succinct_config_name: agglayer_primitives::alloy_primitives::FixedBytes<32>,
+
+    /// Maximum number of blocks covered by a single `eth_getLogs` request.
+    l2_logs_max_block_range: u64,
 }
 
 impl<T: Provider> AggchainContractsClient for AggchainContractsRpcClient<T> {}
 
+#[async_trait::async_trait]
+impl<RpcProvider> L2BlockHashFetcher for AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: Provider + Send + Sync,
+{
+    async fn get_l2_block_hash(&self, block_number: u64) -> Result<Digest, Error> {
+        let block = self
+            .polygon_zkevm_bridge_v2
+            .provider()
+            .get_block_by_number(block_number.into())
+            .await
+            .map_err(Error::L2BlockHashRetrievalError)?
+            .ok_or(Error::L2BlockNotFound(block_number))?;
+
+        Ok(block.header.hash.0.into())
+    }
+}
+
 #[async_trait::async_trait]
 impl<RpcProvider> L2LocalExitRootFetcher for AggchainContractsRpcClient<RpcProvider>
 where
     RpcProvider: Provider + Send + Sync,
 {
-    async fn get_l2_local_exit_root(&self, block_number: u64) -> Result<Digest, Error> {
+    async fn get_l2_local_exit_root(&self, block_hash: Digest) -> Result<Digest, Error> {
         let response = self
             .polygon_zkevm_bridge_v2
             .getRoot()
             .call()
-            .block(block_number.into())
+            .block(BlockId::Hash(RpcBlockHash::from_hash(
+                B256::from(block_hash.0),
+                Some(true),
+            )))
             .await
             .map_err(Error::LocalExitRootError)?;
 
@@ -112,10 +157,12 @@ where
     RpcProvider: alloy::providers::Provider + Send + Sync,
 {
     async fn get_l2_output_at_block(&self, block_number: u64) -> Result<L2OutputAtBlock, Error> {
-        let params = rpc_params![format!("0x{block_number:x}")];
         let json: serde_json::Value = self
             .l2_cl_client
-            .request(&crate::config::default_output_at_block_endpoint(), params)
+            .request(
+                crate::config::default_output_at_block_endpoint(),
+                (format!("0x{block_number:x}"),),
+            )
             .await
             .map_err(Error::L2OutputAtBlockRetrievalError)?;
 
@@ -123,6 +170,22 @@ where
     }
 }
 
+#[async_trait::async_trait]
+impl<RpcProvider> L2SyncStatusFetcher for AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: alloy::providers::Provider + Send + Sync,
+{
+    async fn get_l2_sync_status(&self) -> Result<L2SyncStatus, Error> {
+        let json: serde_json::Value = self
+            .l2_cl_client
+            .request_noparams("optimism_syncStatus")
+            .await
+            .map_err(Error::L2SyncStatusRetrievalError)?;
+
+        Self::parse_l2_sync_status(json)
+    }
+}
+
 #[async_trait::async_trait]
 impl<RpcProvider> L1OpSuccinctConfigFetcher for AggchainContractsRpcClient<RpcProvider>
 where
@@ -154,6 +217,188 @@ where
     }
 }
 
+#[async_trait::async_trait]
+impl<RpcProvider> L2BridgeEventsFetcher for AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: Provider + Send + Sync,
+{
+    async fn get_l2_bridge_events(
+        &self,
+        block_range: RangeInclusive<u64>,
+    ) -> Result<L2BridgeEvents, Error> {
+        let provider = self.polygon_zkevm_bridge_v2.provider();
+        let addresses = vec![
+            *self.global_exit_root_manager_l2.address(),
+            *self.polygon_zkevm_bridge_v2.address(),
+        ];
+
+        let mut events = L2BridgeEvents::default();
+        for range in bridge_events::block_ranges(block_range, self.l2_logs_max_block_range) {
+            let filter = Filter::new()
+                .address(addresses.clone())
+                .event_signature(bridge_events::event_signatures())
+                .from_block(*range.start())
+                .to_block(*range.end());
+
+            let logs = provider
+                .get_logs(&filter)
+                .await
+                .map_err(Error::L2BridgeEventsRetrievalError)?;
+            debug!(?range, "Retrieved {} l2 bridge event logs", logs.len());
+
+            for log in logs {
+                let Some(event) = L2BridgeEvent::decode(&log)? else {
+                    continue;
+                };
+                let LogPosition {
+                    block_number,
+                    block_hash,
+                    log_index,
+                    transaction_hash,
+                } = LogPosition::of(&log)?;
+                events
+                    .block_hashes
+                    .insert(block_number, block_hash.0.into());
+
+                match event {
+                    L2BridgeEvent::InsertedGer(global_exit_root) => {
+                        events.inserted_gers.push(InsertedGerEvent {
+                            global_exit_root,
+                            block_number,
+                            log_index,
+                        })
+                    }
+                    L2BridgeEvent::RemovedGer(global_exit_root) => {
+                        events.removed_gers.push(RemovedGerWithBlockNumber {
+                            global_exit_root,
+                            block_number,
+                            log_index,
+                        })
+                    }
+                    L2BridgeEvent::Claim(global_index) => {
+                        match self.resolve_claim(transaction_hash, global_index).await? {
+                            Some(bridge_exit_hash) => events.imported_bridge_exits.push(
+                                ImportedBridgeExitWithBlockNumber {
+                                    block_number,
+                                    bridge_exit_hash,
+                                    global_index: global_index.into(),
+                                    log_index,
+                                },
+                            ),
+                            None => events.unresolved_claims.push(UnresolvedClaim {
+                                global_index,
+                                block_number,
+                                log_index,
+                                tx_hash: transaction_hash,
+                            }),
+                        }
+                    }
+                    L2BridgeEvent::Unclaim(global_index) => {
+                        events.unclaims.push(UnclaimWithBlockNumber {
+                            global_index,
+                            block_number,
+                            log_index,
+                        })
+                    }
+                }
+            }
+        }
+
+        // The nodes return the logs in chain order, but nothing in the
+        // specification requires it.
+        events
+            .inserted_gers
+            .sort_by_key(|event| (event.block_number, event.log_index));
+        events.removed_gers.sort();
+        events.imported_bridge_exits.sort();
+        events.unclaims.sort();
+        events
+            .unresolved_claims
+            .sort_by_key(|claim| (claim.block_number, claim.log_index));
+
+        Ok(events)
+    }
+}
+
+impl<RpcProvider> AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: Provider + Send + Sync,
+{
+    /// Resolves the bridge exit claimed by the claim of `global_index` emitted
+    /// in the transaction `tx_hash`, from its calldata or else from its call
+    /// trace.
+    ///
+    /// Returns `None` for a claim made through another contract when the node
+    /// does not serve the call traces.
+    async fn resolve_claim(
+        &self,
+        tx_hash: B256,
+        global_index: U256,
+    ) -> Result<Option<BridgeExitHash>, Error> {
+        let provider = self.polygon_zkevm_bridge_v2.provider();
+        let transaction = provider
+            .get_transaction_by_hash(tx_hash)
+            .await
+            .map_err(Error::L2BridgeEventsRetrievalError)?
+            .ok_or(Error::ClaimTransactionNotFound { tx_hash })?;
+        if let Some(bridge_exit_hash) =
+            bridge_events::claimed_bridge_exit_hash(transaction.input(), global_index)
+        {
+            return Ok(Some(bridge_exit_hash));
+        }
+
+        let trace = provider
+            .raw_request::<_, CallFrame>(
+                "debug_traceTransaction".into(),
+                (tx_hash, serde_json::json!({ "tracer": "callTracer" })),
+            )
+            .await;
+        match trace {
+            Ok(trace) => Ok(bridge_events::claimed_bridge_exit_hash_in_trace(
+                &trace,
+                *self.polygon_zkevm_bridge_v2.address(),
+                global_index,
+            )),
+            Err(error) => {
+                warn!(%tx_hash, ?error, "Unable to trace the claim transaction");
+                Ok(None)
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<RpcProvider> RpcHealthChecker for AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: Provider + Send + Sync,
+{
+    async fn check_l1_rpc(&self) -> Result<(), Error> {
+        self.aggchain_fep
+            .provider()
+            .get_block_number()
+            .await
+            .map_err(Error::L1RpcUnreachable)?;
+
+        Ok(())
+    }
+
+    async fn check_l2_rpc(&self) -> Result<(), Error> {
+        self.polygon_zkevm_bridge_v2
+            .provider()
+            .get_block_number()
+            .await
+            .map_err(Error::L2ExecutionLayerRpcUnreachable)?;
+
+        let _: serde_json::Value = self
+            .l2_cl_client
+            .request_noparams("optimism_syncStatus")
+            .await
+            .map_err(Error::L2ConsensusLayerRpcUnreachable)?;
+
+        Ok(())
+    }
+}
+
 #[async_trait::async_trait]
 impl<RpcProvider> L2EvmStateSketchFetcher for AggchainContractsRpcClient<RpcProvider>
 where
@@ -170,7 +415,9 @@ where
                 .optimism()
                 .at_block(prev_l2_block)
                 .with_genesis(self.evm_sketch_genesis.clone())
-                .el_rpc_url(self.l2_root_provider_endpoint.clone())
+                .provider(RootProvider::<AnyNetwork>::new(
+                    self.l2_el_rpc_client.clone(),
+                ))
                 .build()
                 .await
                 .map_err(Error::HostExecutorPreBlockInitialization)?;
@@ -242,7 +489,9 @@ where
                 .optimism()
                 .at_block(new_l2_block)
                 .with_genesis(self.evm_sketch_genesis.clone())
-                .el_rpc_url(self.l2_root_provider_endpoint.clone())
+                .provider(RootProvider::<AnyNetwork>::new(
+                    self.l2_el_rpc_client.clone(),
+                ))
                 .build()
                 .await
                 .map_err(Error::HostExecutorNewBlockInitialization)?;
@@ -350,6 +599,21 @@ async fn host_execute<
 }
 
 impl<RpcProvider> AggchainContractsRpcClient<RpcProvider> {
+    fn parse_l2_sync_status(json: serde_json::Value) -> Result<L2SyncStatus, Error> {
+        fn parse_head(json: &serde_json::Value, field: &str) -> Result<u64, Error> {
+            json.get(field)
+                .and_then(|head| head.get("number"))
+                .and_then(serde_json::Value::as_u64)
+                .ok_or(Error::L2SyncStatusValueMissing(format!("{field}.number")))
+        }
+
+        Ok(L2SyncStatus {
+            unsafe_l2: parse_head(&json, "unsafe_l2")?,
+            safe_l2: parse_head(&json, "safe_l2")?,
+            finalized_l2: parse_head(&json, "finalized_l2")?,
+        })
+    }
+
     fn parse_l2_output_root(json: serde_json::Value) -> Result<L2OutputAtBlock, Error> {
         fn parse_hash(json: &serde_json::Value, field: &str) -> Result<Digest, Error> {
             let value_str = json
@@ -382,25 +646,33 @@ impl AggchainContractsRpcClient<AlloyFillProvider> {
         network_id: u32,
         config: &AggchainProofContractsConfig,
     ) -> Result<Self, crate::Error> {
-        let l1_client = build_alloy_fill_provider(
-            &config.l1_rpc_endpoint.url,
-            prover_alloy::DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS,
-            prover_alloy::DEFAULT_HTTP_RPC_NODE_BACKOFF_MAX_RETRIES,
-        )
-        .map_err(Error::ProviderInitializationError)?;
-
-        let l2_el_client = build_alloy_fill_provider(
+        let fixture = config
+            .rpc_fixture
+            .as_ref()
+            .map(RpcFixture::open)
+            .transpose()
+            .map_err(Error::ProviderInitializationError)?;
+
+        let l1_client =
+            build_alloy_fill_provider("l1", &config.l1_rpc_endpoint.endpoints, fixture.as_ref())
+                .map_err(Error::ProviderInitializationError)?;
+
+        // Shared by the contract calls and the evm state sketches.
+        let l2_el_rpc_client = build_rpc_client(
+            "l2-execution-layer",
             &config.l2_execution_layer_rpc_endpoint,
-            prover_alloy::DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS,
-            prover_alloy::DEFAULT_HTTP_RPC_NODE_BACKOFF_MAX_RETRIES,
+            fixture.as_ref(),
         )
         .map_err(Error::ProviderInitializationError)?;
+        let l2_el_client: AlloyFillProvider =
+            ProviderBuilder::new().connect_client(l2_el_rpc_client.clone());
 
-        let l2_cl_client = Arc::new(
-            HttpClient::builder()
-                .build(&config.l2_consensus_layer_rpc_endpoint)
-                .map_err(Error::RollupNodeInitError)?,
-        );
+        let l2_cl_client = build_rpc_client(
+            "l2-consensus-layer",
+            &config.l2_consensus_layer_rpc_endpoint,
+            fixture.as_ref(),
+        )
+        .map_err(Error::RollupNodeInitError)?;
 
         // Create client for global exit root manager smart contract.
         let global_exit_root_manager_l2 = GlobalExitRootManagerL2SovereignChain::new(
@@ -460,12 +732,13 @@ impl AggchainContractsRpcClient<AlloyFillProvider> {
             l2_cl_client,
             polygon_zkevm_bridge_v2,
             aggchain_fep,
-            l2_root_provider_endpoint: config.l2_execution_layer_rpc_endpoint.clone(),
+            l2_el_rpc_client,
             global_exit_root_manager_l2,
             trusted_sequencer_addr,
             static_call_caller_address: config.static_call_caller_address,
             evm_sketch_genesis: config::parse_evm_sketch_genesis(&config.evm_sketch_genesis)?,
             op_succinct_config_name,
+            l2_logs_max_block_range: config.l2_logs_max_block_range,
         })
     }
 }
@@ -1,23 +1,35 @@
 mod aggchain_contracts_rpc_client {
-    use std::str::FromStr;
+    use std::{collections::BTreeMap, str::FromStr};
 
+    use aggchain_proof_core::bridge::{BridgeConstraintsInput, BridgeWitness};
+    use aggchain_proof_types::imported_bridge_exit::ImportedBridgeExitWithBlockNumber;
     use agglayer_interop::types::Digest;
     use agglayer_primitives::{address, Address};
     use alloy::{
+        eips::BlockNumberOrTag,
         hex::{self, FromHex},
-        primitives::{keccak256, B256},
-        sol_types::{SolCall, SolValue},
+        primitives::{keccak256, Bytes, B256, U256},
+        providers::ProviderBuilder,
+        rpc::types::Log,
+        sol_types::{SolCall, SolEvent, SolValue},
     };
     use mockito::ServerGuard;
-    use prover_alloy::{AlloyFillProvider, L1RpcEndpoint};
+    use prover_alloy::{
+        build_alloy_fill_provider, build_rpc_client, AlloyFillProvider, L1RpcEndpoint, RpcFixture,
+        RpcFixtureConfig, RpcFixtureMode,
+    };
     use serde_json::json;
     use url::Url;
 
+    use super::bridge_events::claim_asset_call;
     use crate::{
-        config::AggchainProofContractsConfig,
+        bridge_events::{self, InsertedGerEvent, UnresolvedClaim},
+        config::{parse_evm_sketch_genesis, AggchainProofContractsConfig},
         contracts::{
-            AggchainFep::trustedSequencerCall, L1OpSuccinctConfigFetcher, L2LocalExitRootFetcher,
-            L2OutputAtBlockFetcher, OpSuccinctConfig,
+            AggchainFep::{self, trustedSequencerCall},
+            GlobalExitRootManagerL2SovereignChain, L1OpSuccinctConfigFetcher,
+            L2BridgeEventsFetcher, L2EvmStateSketchFetcher, L2LocalExitRootFetcher,
+            L2OutputAtBlockFetcher, L2SyncStatus, OpSuccinctConfig, PolygonZkevmBridgeV2,
         },
         AggchainContractsRpcClient,
     };
@@ -37,6 +49,12 @@ mod aggchain_contracts_rpc_client {
     }
 
     async fn aggchain_contracts_rpc_client(
+    ) -> Result<(AggchainContractsRpcClient<AlloyFillProvider>, TestServers), crate::Error> {
+        aggchain_contracts_rpc_client_with_fixture(None).await
+    }
+
+    async fn aggchain_contracts_rpc_client_with_fixture(
+        rpc_fixture: Option<RpcFixtureConfig>,
     ) -> Result<(AggchainContractsRpcClient<AlloyFillProvider>, TestServers), crate::Error> {
         let mut server_l1 = mockito::Server::new_async().await;
         let mut server_l2_el = mockito::Server::new_async().await;
@@ -108,14 +126,16 @@ mod aggchain_contracts_rpc_client {
         let mock_server_l2_cl_url = Url::parse(&server_l2_cl.url()).unwrap();
         let config = AggchainProofContractsConfig {
             l1_rpc_endpoint: mock_server_l1_url,
-            l2_execution_layer_rpc_endpoint: mock_server_l2_el_url,
-            l2_consensus_layer_rpc_endpoint: mock_server_l2_cl_url,
+            l2_execution_layer_rpc_endpoint: mock_server_l2_el_url.into(),
+            l2_consensus_layer_rpc_endpoint: mock_server_l2_cl_url.into(),
             polygon_rollup_manager: address!("0x9a676e781a523b5d0c0e43731313a708cb607508"),
             global_exit_root_manager_v2_sovereign_chain: address!(
                 "0x610178dA211FEF7D417bC0e6FeD39F05609AD788"
             ),
             static_call_caller_address: address!("0x39027D57969aD59161365e0bbd53D2F63eE5AAA6"),
             evm_sketch_genesis: "mainnet".to_string(),
+            l2_logs_max_block_range: 10_000,
+            rpc_fixture,
         };
 
         let result = AggchainContractsRpcClient::new(1, &config).await;
@@ -270,6 +290,33 @@ mod aggchain_contracts_rpc_client {
         Ok(())
     }
 
+    #[test]
+    fn parsing_l2_sync_status() -> Result<(), Box<dyn std::error::Error>> {
+        let json_l2_sync_status_str = include_str!("parsing_l2_sync_status.json");
+        let result = AggchainContractsRpcClient::<AlloyFillProvider>::parse_l2_sync_status(
+            serde_json::from_str(json_l2_sync_status_str)?,
+        )?;
+
+        assert_eq!(
+            result,
+            L2SyncStatus {
+                unsafe_l2: 1520,
+                safe_l2: 1490,
+                finalized_l2: 1300,
+            }
+        );
+
+        let result = AggchainContractsRpcClient::<AlloyFillProvider>::parse_l2_sync_status(
+            json!({ "unsafe_l2": { "number": 1520 } }),
+        );
+        assert!(matches!(
+            result,
+            Err(crate::Error::L2SyncStatusValueMissing(field)) if field == "safe_l2.number"
+        ));
+
+        Ok(())
+    }
+
     #[test_log::test(tokio::test)]
     async fn test_new_contracts_rpc_client() -> Result<(), Box<dyn std::error::Error>> {
         let result = aggchain_contracts_rpc_client().await;
@@ -314,12 +361,14 @@ mod aggchain_contracts_rpc_client {
         let mock_server_l2_url = Url::parse(&server_l2.url())?;
         let config = AggchainProofContractsConfig {
             l1_rpc_endpoint: L1RpcEndpoint::from(dummy_url()),
-            l2_execution_layer_rpc_endpoint: mock_server_l2_url.clone(),
-            l2_consensus_layer_rpc_endpoint: dummy_url(),
+            l2_execution_layer_rpc_endpoint: mock_server_l2_url.into(),
+            l2_consensus_layer_rpc_endpoint: dummy_url().into(),
             polygon_rollup_manager: dummy_address(),
             global_exit_root_manager_v2_sovereign_chain: dummy_address(),
             static_call_caller_address: address!("0x39027D57969aD59161365e0bbd53D2F63eE5AAA6"),
             evm_sketch_genesis: "mainnet".to_string(),
+            l2_logs_max_block_range: 10_000,
+            rpc_fixture: None,
         };
 
         let result = AggchainContractsRpcClient::new(1, &config).await;
@@ -344,7 +393,10 @@ mod aggchain_contracts_rpc_client {
                 "to":"0xd81e7fb88b8e3a6bae6c1b64e11f1a355641fb7c",
                 "input":"0x5ca1e165",
             },
-            "0xa"],
+            {
+                "blockHash": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                "requireCanonical": true,
+            }],
             "id": 1,
             "jsonrpc": "2.0",
         });
@@ -364,7 +416,9 @@ mod aggchain_contracts_rpc_client {
             )
             .create();
 
-        let result = contracts_client.get_l2_local_exit_root(10).await;
+        let result = contracts_client
+            .get_l2_local_exit_root(Digest([0x11; 32]))
+            .await;
 
         mock_l2.assert_async().await;
         let local_exit_root = result?;
@@ -393,7 +447,10 @@ mod aggchain_contracts_rpc_client {
                 "to":"0xd81e7fb88b8e3a6bae6c1b64e11f1a355641fb7c",
                 "input":"0x5ca1e165",
             },
-            "0xa"],
+            {
+                "blockHash": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                "requireCanonical": true,
+            }],
             "id": 1,
             "jsonrpc": "2.0",
         });
@@ -413,7 +470,9 @@ mod aggchain_contracts_rpc_client {
             )
             .create();
 
-        let result = contracts_client.get_l2_local_exit_root(10).await;
+        let result = contracts_client
+            .get_l2_local_exit_root(Digest([0x11; 32]))
+            .await;
 
         mock_l2.assert_async().await;
         match result {
@@ -515,4 +574,459 @@ mod aggchain_contracts_rpc_client {
         );
         Ok(())
     }
+
+    #[test_log::test(tokio::test)]
+    async fn replays_the_recorded_rpc_traffic() -> Result<(), Box<dyn std::error::Error>> {
+        let path = std::env::temp_dir().join(format!(
+            "aggchain-contracts-rpc-fixture-{}.json",
+            std::process::id()
+        ));
+
+        // Record the initialization of the client and an output root.
+        let (contracts_client, test_servers) =
+            aggchain_contracts_rpc_client_with_fixture(Some(RpcFixtureConfig {
+                mode: RpcFixtureMode::Record,
+                path: path.clone(),
+            }))
+            .await?;
+        let mut server_l2_cl = test_servers.server_l2_cl;
+        let mock_l2_cl = server_l2_cl
+            .mock("POST", "/")
+            .with_status(200)
+            .with_body(include_str!("get_l2_output_at_block.json"))
+            .create();
+        let recorded = contracts_client.get_l2_output_at_block(0x10).await?;
+        mock_l2_cl.assert_async().await;
+        drop(contracts_client);
+        drop(server_l2_cl);
+
+        // Replay them with all the nodes down.
+        let config = AggchainProofContractsConfig {
+            l1_rpc_endpoint: L1RpcEndpoint::from(dummy_url()),
+            l2_execution_layer_rpc_endpoint: dummy_url().into(),
+            l2_consensus_layer_rpc_endpoint: dummy_url().into(),
+            polygon_rollup_manager: address!("0x9a676e781a523b5d0c0e43731313a708cb607508"),
+            global_exit_root_manager_v2_sovereign_chain: address!(
+                "0x610178dA211FEF7D417bC0e6FeD39F05609AD788"
+            ),
+            static_call_caller_address: address!("0x39027D57969aD59161365e0bbd53D2F63eE5AAA6"),
+            evm_sketch_genesis: "mainnet".to_string(),
+            l2_logs_max_block_range: 10_000,
+            rpc_fixture: Some(RpcFixtureConfig {
+                mode: RpcFixtureMode::Replay,
+                path: path.clone(),
+            }),
+        };
+        let contracts_client = AggchainContractsRpcClient::new(1, &config).await?;
+        let replayed = contracts_client.get_l2_output_at_block(0x10).await?;
+
+        assert_eq!(replayed, recorded);
+        assert!(contracts_client.get_l2_output_at_block(0x11).await.is_err());
+
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test(flavor = "multi_thread"))]
+    async fn builds_the_sketches_from_the_recorded_sepolia_rpc_traffic(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let test_input = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../aggchain-proof-core/src/test_input");
+        let recorded: BridgeConstraintsInput = serde_json::from_reader(std::io::BufReader::new(
+            std::fs::File::open(test_input.join("bridge_constraints_input.json"))?,
+        ))?;
+
+        // Only the sepolia l2 execution layer traffic is recorded, the other
+        // nodes are down.
+        let fixture = RpcFixture::open(&RpcFixtureConfig {
+            mode: RpcFixtureMode::Replay,
+            path: test_input.join("bridge_rpc_fixture_e2e_sepolia.json"),
+        })?;
+        let l2_el_rpc_client =
+            build_rpc_client("l2-execution-layer", &dummy_url().into(), Some(&fixture))?;
+        let l2_el_client: AlloyFillProvider =
+            ProviderBuilder::new().connect_client(l2_el_rpc_client.clone());
+        let l1_client = build_alloy_fill_provider("l1", &dummy_url().into(), None)?;
+
+        let contracts_client = AggchainContractsRpcClient {
+            l2_el_rpc_client,
+            l2_cl_client: build_rpc_client("l2-consensus-layer", &dummy_url().into(), None)?,
+            polygon_zkevm_bridge_v2: PolygonZkevmBridgeV2::new(
+                address!("0xd31d479c2faff39c7e9eb05ae51e84cddeb5a94b").into(),
+                l2_el_client.clone(),
+            ),
+            global_exit_root_manager_l2: GlobalExitRootManagerL2SovereignChain::new(
+                recorded.ger_addr.into(),
+                l2_el_client,
+            ),
+            aggchain_fep: AggchainFep::new(dummy_address().into(), l1_client),
+            trusted_sequencer_addr: dummy_address(),
+            static_call_caller_address: recorded.bridge_witness.caller_address,
+            evm_sketch_genesis: parse_evm_sketch_genesis(
+                test_input.join("genesis.json").to_str().unwrap(),
+            )?,
+            op_succinct_config_name: Default::default(),
+            l2_logs_max_block_range: 10_000,
+        };
+
+        let prev_l2_block_sketch = contracts_client
+            .get_prev_l2_block_sketch(BlockNumberOrTag::Number(29188043))
+            .await?;
+        let new_l2_block_sketch = contracts_client
+            .get_new_l2_block_sketch(BlockNumberOrTag::Number(29188058))
+            .await?;
+
+        // The replayed sketches satisfy the bridge constraints of the recorded
+        // blocks.
+        BridgeConstraintsInput {
+            bridge_witness: BridgeWitness {
+                prev_l2_block_sketch,
+                new_l2_block_sketch,
+                ..recorded.bridge_witness
+            },
+            ..recorded
+        }
+        .verify()?;
+
+        Ok(())
+    }
+
+    /// Mocks the answer of the l2 execution layer to the `method` calls.
+    fn mock_l2_method(
+        server_l2_el: &mut ServerGuard,
+        method: &str,
+        id: u64,
+        response: serde_json::Value,
+    ) -> mockito::Mock {
+        let mut body = json!({ "id": id, "jsonrpc": "2.0" });
+        body.as_object_mut()
+            .unwrap()
+            .extend(response.as_object().unwrap().clone());
+
+        server_l2_el
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .match_body(mockito::Matcher::PartialJson(json!({ "method": method })))
+            .with_body(body.to_string())
+            .create()
+    }
+
+    const BRIDGE: Address = address!("0xd81e7fb88b8e3a6bae6c1b64e11f1a355641fb7c");
+    const FORWARDER: Address = address!("0x5fbdb2315678afecb367f032d93f642f64180aa3");
+    const CLAIM_TX_HASH: B256 = B256::repeat_byte(0xcc);
+
+    /// Logs of a GER inserted at block 11 and of a claim of global index 42
+    /// made through a forwarder at block 12.
+    fn bridge_event_logs() -> serde_json::Value {
+        let log = |address, data, block_number: u64, log_index| {
+            serde_json::to_value(Log {
+                inner: alloy::primitives::Log { address, data },
+                block_hash: Some(B256::repeat_byte(block_number as u8)),
+                block_number: Some(block_number),
+                block_timestamp: None,
+                transaction_hash: Some(CLAIM_TX_HASH),
+                transaction_index: Some(0),
+                log_index: Some(log_index),
+                removed: false,
+            })
+            .unwrap()
+        };
+
+        json!([
+            log(
+                address!("0x610178da211fef7d417bc0e6fed39f05609ad788"),
+                GlobalExitRootManagerL2SovereignChain::UpdateHashChainValue {
+                    newGlobalExitRoot: B256::repeat_byte(0x11),
+                    newHashChainValue: B256::repeat_byte(0x22),
+                }
+                .encode_log_data(),
+                11,
+                0,
+            ),
+            log(
+                BRIDGE,
+                PolygonZkevmBridgeV2::ClaimEvent {
+                    globalIndex: U256::from(42u64),
+                    originNetwork: 0,
+                    originAddress: Address::ZERO,
+                    destinationAddress: address!("0x8e80ffe6dc044f4a766afd6e5a8732fe0977a493"),
+                    amount: U256::from(1_000_000u64),
+                }
+                .encode_log_data(),
+                12,
+                3,
+            ),
+        ])
+    }
+
+    /// Transaction calling the forwarder, which claims on the bridge.
+    fn forwarder_transaction() -> serde_json::Value {
+        json!({
+            "hash": CLAIM_TX_HASH,
+            "type": "0x0",
+            "nonce": "0x0",
+            "gasPrice": "0x1",
+            "gas": "0x30d40",
+            "to": FORWARDER,
+            "value": "0x0",
+            "input": forwarder_input(),
+            "chainId": "0x1",
+            "v": "0x25",
+            "r": "0x1",
+            "s": "0x1",
+            "blockHash": B256::repeat_byte(12),
+            "blockNumber": "0xc",
+            "transactionIndex": "0x0",
+            "from": address!("0x39027d57969ad59161365e0bbd53d2f63ee5aaa6"),
+        })
+    }
+
+    fn forwarder_input() -> Bytes {
+        [
+            &[0xde, 0xad, 0xbe, 0xef][..],
+            &claim_asset_call(U256::from(42u64)).abi_encode()[..],
+        ]
+        .concat()
+        .into()
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn claim_through_another_contract_is_resolved_from_its_trace(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (contracts_client, test_servers) = aggchain_contracts_rpc_client().await?;
+        let mut server_l2_el = test_servers.server_l2_el;
+
+        let mock_logs = mock_l2_method(
+            &mut server_l2_el,
+            "eth_getLogs",
+            1,
+            json!({ "result": bridge_event_logs() }),
+        );
+        let mock_transaction = mock_l2_method(
+            &mut server_l2_el,
+            "eth_getTransactionByHash",
+            2,
+            json!({ "result": forwarder_transaction() }),
+        );
+        let claim = claim_asset_call(U256::from(42u64)).abi_encode();
+        let mock_trace = mock_l2_method(
+            &mut server_l2_el,
+            "debug_traceTransaction",
+            3,
+            json!({ "result": {
+                "type": "CALL",
+                "from": address!("0x39027d57969ad59161365e0bbd53d2f63ee5aaa6"),
+                "to": FORWARDER,
+                "gas": "0x30d40",
+                "gasUsed": "0x1d4c0",
+                "value": "0x0",
+                "input": forwarder_input(),
+                "calls": [{
+                    "type": "CALL",
+                    "from": FORWARDER,
+                    "to": BRIDGE,
+                    "gas": "0x2bf20",
+                    "gasUsed": "0x186a0",
+                    "value": "0x0",
+                    "input": Bytes::from(claim.clone()),
+                }],
+            }}),
+        );
+
+        let events = contracts_client.get_l2_bridge_events(11..=12).await?;
+
+        mock_logs.assert_async().await;
+        mock_transaction.assert_async().await;
+        mock_trace.assert_async().await;
+        assert_eq!(
+            events.inserted_gers,
+            vec![InsertedGerEvent {
+                global_exit_root: Digest([0x11; 32]),
+                block_number: 11,
+                log_index: 0,
+            }]
+        );
+        assert_eq!(
+            events.imported_bridge_exits,
+            vec![ImportedBridgeExitWithBlockNumber {
+                block_number: 12,
+                bridge_exit_hash: bridge_events::claimed_bridge_exit_hash(
+                    &claim,
+                    U256::from(42u64)
+                )
+                .unwrap(),
+                global_index: U256::from(42u64).into(),
+                log_index: 3,
+            }]
+        );
+        assert!(events.unresolved_claims.is_empty());
+        assert_eq!(
+            events.block_hashes,
+            BTreeMap::from([(11, Digest([11; 32])), (12, Digest([12; 32]))])
+        );
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn claim_through_another_contract_without_trace_is_left_unresolved(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (contracts_client, test_servers) = aggchain_contracts_rpc_client().await?;
+        let mut server_l2_el = test_servers.server_l2_el;
+
+        let _mock_logs = mock_l2_method(
+            &mut server_l2_el,
+            "eth_getLogs",
+            1,
+            json!({ "result": bridge_event_logs() }),
+        );
+        let _mock_transaction = mock_l2_method(
+            &mut server_l2_el,
+            "eth_getTransactionByHash",
+            2,
+            json!({ "result": forwarder_transaction() }),
+        );
+        let mock_trace = mock_l2_method(
+            &mut server_l2_el,
+            "debug_traceTransaction",
+            3,
+            json!({ "error": {
+                "code": -32601,
+                "message": "the method debug_traceTransaction does not exist/is not available",
+            }}),
+        );
+
+        let events = contracts_client.get_l2_bridge_events(11..=12).await?;
+
+        mock_trace.assert_async().await;
+        assert!(events.imported_bridge_exits.is_empty());
+        assert_eq!(
+            events.unresolved_claims,
+            vec![UnresolvedClaim {
+                global_index: U256::from(42u64),
+                block_number: 12,
+                log_index: 3,
+                tx_hash: CLAIM_TX_HASH,
+            }]
+        );
+
+        Ok(())
+    }
+}
+
+mod bridge_events {
+    use alloy::{
+        primitives::{address, keccak256, Address, Bytes, FixedBytes, U256},
+        rpc::types::trace::geth::CallFrame,
+        sol_types::{SolCall, SolValue},
+    };
+
+    use crate::{bridge_events, contracts::PolygonZkevmBridgeV2};
+
+    pub(super) fn claim_asset_call(global_index: U256) -> PolygonZkevmBridgeV2::claimAssetCall {
+        PolygonZkevmBridgeV2::claimAssetCall {
+            smtProofLocalExitRoot: [FixedBytes::ZERO; 32],
+            smtProofRollupExitRoot: [FixedBytes::ZERO; 32],
+            globalIndex: global_index,
+            mainnetExitRoot: FixedBytes::ZERO,
+            rollupExitRoot: FixedBytes::ZERO,
+            originNetwork: 0,
+            originTokenAddress: address!("0x0000000000000000000000000000000000000000"),
+            destinationNetwork: 1,
+            destinationAddress: address!("0x8e80ffe6dc044f4a766afd6e5a8732fe0977a493"),
+            amount: U256::from(1_000_000u64),
+            metadata: Bytes::from_static(b"metadata"),
+        }
+    }
+
+    #[test]
+    fn block_ranges_are_split_in_chunks() {
+        let ranges: Vec<_> = bridge_events::block_ranges(11..=35, 10).collect();
+        assert_eq!(ranges, vec![11..=20, 21..=30, 31..=35]);
+
+        let ranges: Vec<_> = bridge_events::block_ranges(7..=7, 10).collect();
+        assert_eq!(ranges, vec![7..=7]);
+    }
+
+    #[test]
+    fn claimed_bridge_exit_hash_from_claim_asset_calldata() {
+        let global_index = U256::from(42u64);
+        let call = claim_asset_call(global_index);
+
+        let expected = keccak256(
+            (
+                0u8,
+                call.originNetwork,
+                call.originTokenAddress,
+                call.destinationNetwork,
+                call.destinationAddress,
+                call.amount,
+                keccak256(&call.metadata),
+            )
+                .abi_encode_packed(),
+        );
+
+        let bridge_exit_hash =
+            bridge_events::claimed_bridge_exit_hash(&call.abi_encode(), global_index).unwrap();
+        assert_eq!(bridge_exit_hash.0, expected.0.into());
+    }
+
+    #[test]
+    fn claimed_bridge_exit_hash_rejects_other_calls() {
+        let call = claim_asset_call(U256::from(42u64));
+
+        assert!(
+            bridge_events::claimed_bridge_exit_hash(&call.abi_encode(), U256::from(43u64))
+                .is_none()
+        );
+        assert!(
+            bridge_events::claimed_bridge_exit_hash(&[0xde, 0xad, 0xbe, 0xef], U256::ZERO)
+                .is_none()
+        );
+    }
+
+    fn call_frame(to: Address, input: Vec<u8>, calls: Vec<CallFrame>) -> CallFrame {
+        CallFrame {
+            to: Some(to),
+            input: input.into(),
+            calls,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn claimed_bridge_exit_hash_from_the_trace_of_a_nested_claim() {
+        let bridge = address!("0xd81e7fb88b8e3a6bae6c1b64e11f1a355641fb7c");
+        let forwarder = address!("0x5fbdb2315678afecb367f032d93f642f64180aa3");
+        let global_index = U256::from(42u64);
+        let claim = claim_asset_call(global_index).abi_encode();
+
+        let mut reverted = call_frame(bridge, claim.clone(), vec![]);
+        reverted.error = Some("execution reverted".to_string());
+        let trace = call_frame(
+            forwarder,
+            vec![0xde, 0xad, 0xbe, 0xef],
+            vec![call_frame(
+                forwarder,
+                vec![],
+                vec![call_frame(bridge, claim.clone(), vec![])],
+            )],
+        );
+
+        assert_eq!(
+            bridge_events::claimed_bridge_exit_hash_in_trace(&trace, bridge, global_index),
+            bridge_events::claimed_bridge_exit_hash(&claim, global_index)
+        );
+        assert!(bridge_events::claimed_bridge_exit_hash_in_trace(
+            &call_frame(forwarder, vec![], vec![reverted]),
+            bridge,
+            global_index
+        )
+        .is_none());
+        assert!(
+            bridge_events::claimed_bridge_exit_hash_in_trace(&trace, forwarder, global_index)
+                .is_none()
+        );
+    }
 }
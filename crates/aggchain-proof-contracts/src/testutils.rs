@@ -0,0 +1,503 @@
+//! In-memory [`AggchainContractsClient`], for testing the aggchain proof
+//! builder and its services without any chain.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    ops::RangeInclusive,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use agglayer_interop::types::Digest;
+use agglayer_primitives::Address;
+use alloy::{eips::BlockNumberOrTag, primitives::keccak256};
+use sp1_cc_client_executor::io::EvmSketchInput;
+
+use crate::{
+    bridge_events::L2BridgeEvents,
+    contracts::{
+        GetTrustedSequencerAddress, L1OpSuccinctConfigFetcher, L2BlockHashFetcher,
+        L2BridgeEventsFetcher, L2EvmStateSketchFetcher, L2LocalExitRootFetcher, L2OutputAtBlock,
+        L2OutputAtBlockFetcher, L2SyncStatus, L2SyncStatusFetcher, OpSuccinctConfig,
+        RpcHealthChecker,
+    },
+    AggchainContractsClient, Error,
+};
+
+/// Method of the [`FakeAggchainContractsClient`], to inject faults in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FakeMethod {
+    L2BlockHash,
+    L2LocalExitRoot,
+    L2OutputAtBlock,
+    OpSuccinctConfig,
+    TrustedSequencerAddress,
+    PrevL2BlockSketch,
+    NewL2BlockSketch,
+    L2BridgeEvents,
+    L2SyncStatus,
+    CheckL1Rpc,
+    CheckL2Rpc,
+}
+
+/// L2 block served by the [`FakeAggchainContractsClient`].
+#[derive(Clone)]
+pub struct FakeL2Block {
+    pub hash: Digest,
+    pub local_exit_root: Digest,
+
+    /// Output at the block. Its `latest_block_hash` is the hash of the block.
+    pub output: L2OutputAtBlock,
+
+    /// Sketch of the block, the block has none unless set.
+    pub sketch: Option<EvmSketchInput>,
+}
+
+impl FakeL2Block {
+    /// Block with a hash derived from `block_number`, and zero roots.
+    pub fn new(block_number: u64) -> Self {
+        Self {
+            hash: Digest(keccak256(block_number.to_be_bytes()).0),
+            local_exit_root: Digest::ZERO,
+            output: L2OutputAtBlock::default(),
+            sketch: None,
+        }
+    }
+
+    pub fn set_hash(mut self, hash: Digest) -> Self {
+        self.hash = hash;
+        self
+    }
+
+    pub fn set_local_exit_root(mut self, local_exit_root: Digest) -> Self {
+        self.local_exit_root = local_exit_root;
+        self
+    }
+
+    pub fn set_output(mut self, output: L2OutputAtBlock) -> Self {
+        self.output = output;
+        self
+    }
+
+    /// Sets the sketch of the block, and the hash of the block to the one of
+    /// the sketch anchor.
+    pub fn set_sketch(mut self, sketch: EvmSketchInput) -> Self {
+        self.hash = Digest(sketch.anchor.header().hash_slow().0);
+        self.sketch = Some(sketch);
+        self
+    }
+}
+
+/// Chain data served by the [`FakeAggchainContractsClient`].
+#[derive(Clone, Default)]
+pub struct FakeChain {
+    /// Canonical L2 blocks, by number.
+    pub blocks: BTreeMap<u64, FakeL2Block>,
+
+    pub op_succinct_config: OpSuccinctConfig,
+
+    pub trusted_sequencer: Address,
+
+    /// Bridge events emitted on the L2, filtered by block range when served
+    /// along with the hashes of their blocks, which must be in `blocks`.
+    pub bridge_events: L2BridgeEvents,
+
+    /// Heads of the L2, all at the last block if not set.
+    pub sync_status: Option<L2SyncStatus>,
+}
+
+impl FakeChain {
+    /// Block `block_number`, inserted if missing.
+    pub fn block_mut(&mut self, block_number: u64) -> &mut FakeL2Block {
+        self.blocks
+            .entry(block_number)
+            .or_insert_with(|| FakeL2Block::new(block_number))
+    }
+
+    fn block(&self, block_number: u64) -> Result<&FakeL2Block, Error> {
+        self.blocks
+            .get(&block_number)
+            .ok_or(Error::L2BlockNotFound(block_number))
+    }
+
+    fn block_sketch(&self, block: BlockNumberOrTag) -> Result<EvmSketchInput, Error> {
+        let BlockNumberOrTag::Number(block_number) = block else {
+            return Err(Error::Other(eyre::eyre!(
+                "Fake l2 block sketches are only served by number, got {block}"
+            )));
+        };
+
+        self.block(block_number)?
+            .sketch
+            .clone()
+            .ok_or_else(|| Error::Other(eyre::eyre!("No sketch of the l2 block {block_number}")))
+    }
+}
+
+type Change = Box<dyn FnOnce(&mut FakeChain) + Send>;
+
+#[derive(Default)]
+struct Faults {
+    calls: HashMap<FakeMethod, usize>,
+    delays: HashMap<FakeMethod, Duration>,
+    failures: HashMap<FakeMethod, usize>,
+    changes: Vec<(FakeMethod, usize, Change)>,
+}
+
+/// [`AggchainContractsClient`] serving a [`FakeChain`] from memory.
+///
+/// Faults can be injected in each [`FakeMethod`]: a delay before it answers,
+/// failures of its next calls, and changes of the chain once it has been
+/// called a number of times, to simulate the L2 moving under a request.
+///
+/// The clones of the client share the chain and the faults, so a test can
+/// keep a clone to update the chain once the other one is in use.
+#[derive(Clone, Default)]
+pub struct FakeAggchainContractsClient {
+    chain: Arc<Mutex<FakeChain>>,
+    faults: Arc<Mutex<Faults>>,
+}
+
+impl FakeAggchainContractsClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_block(self, block_number: u64, block: FakeL2Block) -> Self {
+        self.update(|chain| {
+            chain.blocks.insert(block_number, block);
+        });
+        self
+    }
+
+    pub fn set_op_succinct_config(self, op_succinct_config: OpSuccinctConfig) -> Self {
+        self.update(|chain| chain.op_succinct_config = op_succinct_config);
+        self
+    }
+
+    pub fn set_trusted_sequencer(self, trusted_sequencer: Address) -> Self {
+        self.update(|chain| chain.trusted_sequencer = trusted_sequencer);
+        self
+    }
+
+    pub fn set_bridge_events(self, bridge_events: L2BridgeEvents) -> Self {
+        self.update(|chain| chain.bridge_events = bridge_events);
+        self
+    }
+
+    pub fn set_sync_status(self, sync_status: L2SyncStatus) -> Self {
+        self.update(|chain| chain.sync_status = Some(sync_status));
+        self
+    }
+
+    /// Delays every answer of `method`.
+    pub fn set_delay(self, method: FakeMethod, delay: Duration) -> Self {
+        self.lock_faults().delays.insert(method, delay);
+        self
+    }
+
+    /// Fails the next `count` calls of `method`.
+    pub fn set_failures(self, method: FakeMethod, count: usize) -> Self {
+        self.lock_faults().failures.insert(method, count);
+        self
+    }
+
+    /// Applies `change` to the chain right after the `calls`-th call of
+    /// `method` has been answered.
+    pub fn set_change_after(
+        self,
+        method: FakeMethod,
+        calls: usize,
+        change: impl FnOnce(&mut FakeChain) + Send + 'static,
+    ) -> Self {
+        self.lock_faults()
+            .changes
+            .push((method, calls, Box::new(change)));
+        self
+    }
+
+    /// Applies `update` to the chain.
+    pub fn update<R>(&self, update: impl FnOnce(&mut FakeChain) -> R) -> R {
+        update(&mut self.lock_chain())
+    }
+
+    /// Number of calls of `method` so far.
+    pub fn calls(&self, method: FakeMethod) -> usize {
+        self.lock_faults()
+            .calls
+            .get(&method)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    async fn answer<T>(
+        &self,
+        method: FakeMethod,
+        read: impl FnOnce(&FakeChain) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let delay = self.lock_faults().delays.get(&method).copied();
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        let mut faults = self.lock_faults();
+        let mut chain = self.lock_chain();
+
+        let calls = faults.calls.entry(method).or_default();
+        *calls += 1;
+        let calls = *calls;
+
+        let result = match faults.failures.get_mut(&method) {
+            Some(remaining) if *remaining > 0 => {
+                *remaining -= 1;
+                Err(Error::Other(eyre::eyre!(
+                    "Injected failure of {method:?}, call {calls}"
+                )))
+            }
+            _ => read(&*chain),
+        };
+
+        let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut faults.changes)
+            .into_iter()
+            .partition(|(change_method, after, _)| *change_method == method && *after == calls);
+        faults.changes = pending;
+        for (_, _, change) in due {
+            change(&mut *chain);
+        }
+
+        result
+    }
+
+    fn lock_chain(&self) -> MutexGuard<'_, FakeChain> {
+        self.chain
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn lock_faults(&self) -> MutexGuard<'_, Faults> {
+        self.faults
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl AggchainContractsClient for FakeAggchainContractsClient {}
+
+#[async_trait::async_trait]
+impl L2BlockHashFetcher for FakeAggchainContractsClient {
+    async fn get_l2_block_hash(&self, block_number: u64) -> Result<Digest, Error> {
+        self.answer(FakeMethod::L2BlockHash, |chain| {
+            Ok(chain.block(block_number)?.hash)
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl L2LocalExitRootFetcher for FakeAggchainContractsClient {
+    async fn get_l2_local_exit_root(&self, block_hash: Digest) -> Result<Digest, Error> {
+        self.answer(FakeMethod::L2LocalExitRoot, |chain| {
+            chain
+                .blocks
+                .values()
+                .find(|block| block.hash == block_hash)
+                .map(|block| block.local_exit_root)
+                .ok_or_else(|| Error::Other(eyre::eyre!("L2 block {block_hash} is not canonical")))
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl L2OutputAtBlockFetcher for FakeAggchainContractsClient {
+    async fn get_l2_output_at_block(&self, block_number: u64) -> Result<L2OutputAtBlock, Error> {
+        self.answer(FakeMethod::L2OutputAtBlock, |chain| {
+            let block = chain.block(block_number)?;
+            Ok(L2OutputAtBlock {
+                latest_block_hash: block.hash,
+                ..block.output
+            })
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl L1OpSuccinctConfigFetcher for FakeAggchainContractsClient {
+    async fn get_op_succinct_config(&self) -> Result<OpSuccinctConfig, Error> {
+        self.answer(FakeMethod::OpSuccinctConfig, |chain| {
+            Ok(chain.op_succinct_config)
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl GetTrustedSequencerAddress for FakeAggchainContractsClient {
+    async fn get_trusted_sequencer_address(&self) -> Result<Address, Error> {
+        self.answer(FakeMethod::TrustedSequencerAddress, |chain| {
+            Ok(chain.trusted_sequencer)
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl L2EvmStateSketchFetcher for FakeAggchainContractsClient {
+    async fn get_prev_l2_block_sketch(
+        &self,
+        prev_l2_block: BlockNumberOrTag,
+    ) -> Result<EvmSketchInput, Error> {
+        self.answer(FakeMethod::PrevL2BlockSketch, |chain| {
+            chain.block_sketch(prev_l2_block)
+        })
+        .await
+    }
+
+    async fn get_new_l2_block_sketch(
+        &self,
+        new_l2_block: BlockNumberOrTag,
+    ) -> Result<EvmSketchInput, Error> {
+        self.answer(FakeMethod::NewL2BlockSketch, |chain| {
+            chain.block_sketch(new_l2_block)
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl L2BridgeEventsFetcher for FakeAggchainContractsClient {
+    async fn get_l2_bridge_events(
+        &self,
+        block_range: RangeInclusive<u64>,
+    ) -> Result<L2BridgeEvents, Error> {
+        self.answer(FakeMethod::L2BridgeEvents, |chain| {
+            let events = &chain.bridge_events;
+            let mut served = L2BridgeEvents {
+                inserted_gers: events
+                    .inserted_gers
+                    .iter()
+                    .filter(|event| block_range.contains(&event.block_number))
+                    .cloned()
+                    .collect(),
+                removed_gers: events
+                    .removed_gers
+                    .iter()
+                    .filter(|event| block_range.contains(&event.block_number))
+                    .cloned()
+                    .collect(),
+                imported_bridge_exits: events
+                    .imported_bridge_exits
+                    .iter()
+                    .filter(|event| block_range.contains(&event.block_number))
+                    .cloned()
+                    .collect(),
+                unclaims: events
+                    .unclaims
+                    .iter()
+                    .filter(|event| block_range.contains(&event.block_number))
+                    .cloned()
+                    .collect(),
+                unresolved_claims: events
+                    .unresolved_claims
+                    .iter()
+                    .filter(|claim| block_range.contains(&claim.block_number))
+                    .copied()
+                    .collect(),
+                block_hashes: BTreeMap::new(),
+            };
+
+            // The events are read at the current hash of their block, which must
+            // be in the chain.
+            let block_numbers: BTreeSet<u64> = served
+                .inserted_gers
+                .iter()
+                .map(|event| event.block_number)
+                .chain(served.removed_gers.iter().map(|event| event.block_number))
+                .chain(
+                    served
+                        .imported_bridge_exits
+                        .iter()
+                        .map(|event| event.block_number),
+                )
+                .chain(served.unclaims.iter().map(|event| event.block_number))
+                .chain(
+                    served
+                        .unresolved_claims
+                        .iter()
+                        .map(|claim| claim.block_number),
+                )
+                .collect();
+            served.block_hashes = block_numbers
+                .into_iter()
+                .map(|block_number| Ok((block_number, chain.block(block_number)?.hash)))
+                .collect::<Result<_, Error>>()?;
+
+            Ok(served)
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl L2SyncStatusFetcher for FakeAggchainContractsClient {
+    async fn get_l2_sync_status(&self) -> Result<L2SyncStatus, Error> {
+        self.answer(FakeMethod::L2SyncStatus, |chain| {
+            Ok(chain.sync_status.unwrap_or_else(|| {
+                let head = chain.blocks.keys().last().copied().unwrap_or_default();
+                L2SyncStatus {
+                    unsafe_l2: head,
+                    safe_l2: head,
+                    finalized_l2: head,
+                }
+            }))
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl RpcHealthChecker for FakeAggchainContractsClient {
+    async fn check_l1_rpc(&self) -> Result<(), Error> {
+        self.answer(FakeMethod::CheckL1Rpc, |_| Ok(())).await
+    }
+
+    async fn check_l2_rpc(&self) -> Result<(), Error> {
+        self.answer(FakeMethod::CheckL2Rpc, |_| Ok(())).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn faults_are_injected_per_method() {
+        let client = FakeAggchainContractsClient::new()
+            .set_block(1, FakeL2Block::new(1).set_local_exit_root(Digest([1; 32])))
+            .set_failures(FakeMethod::L2BlockHash, 1)
+            .set_change_after(FakeMethod::L2LocalExitRoot, 1, |chain| {
+                chain.block_mut(1).local_exit_root = Digest([2; 32]);
+            });
+
+        assert!(client.get_l2_block_hash(1).await.is_err());
+        let hash = client.get_l2_block_hash(1).await.unwrap();
+        assert_eq!(hash, FakeL2Block::new(1).hash);
+        assert!(matches!(
+            client.get_l2_block_hash(2).await,
+            Err(Error::L2BlockNotFound(2))
+        ));
+
+        assert_eq!(
+            client.get_l2_local_exit_root(hash).await.unwrap(),
+            Digest([1; 32])
+        );
+        assert_eq!(
+            client.get_l2_local_exit_root(hash).await.unwrap(),
+            Digest([2; 32])
+        );
+
+        assert_eq!(client.calls(FakeMethod::L2BlockHash), 3);
+        assert_eq!(client.get_l2_sync_status().await.unwrap().safe_l2, 1);
+    }
+}
@@ -476,8 +476,9 @@ mod tests {
     use std::{collections::HashMap, fs::File, io::BufReader, str::FromStr};
 
     use agglayer_primitives::U256;
-    use alloy::rpc::types::BlockNumberOrTag;
+    use alloy::{network::AnyNetwork, providers::RootProvider, rpc::types::BlockNumberOrTag};
     use alloy_primitives::hex;
+    use prover_alloy::{RpcFixture, RpcFixtureConfig, RpcFixtureMode};
     use serde_json::Value;
     use sp1_cc_client_executor::Genesis;
     use sp1_cc_host_executor::EvmSketch;
@@ -487,11 +488,10 @@ mod tests {
     use super::*;
 
     #[tokio::test(flavor = "multi_thread")]
-    #[ignore = "e2e test, sepolia provider needed"]
     async fn test_bridge_constraints() -> Result<(), Box<dyn std::error::Error>> {
         // Initialize the environment variables.
         dotenvy::dotenv().ok();
-        tracing_subscriber::fmt().with_test_writer().init();
+        let _ = tracing_subscriber::fmt().with_test_writer().try_init();
 
         println!("Starting bridge constraints test...");
 
@@ -668,12 +668,27 @@ mod tests {
             })
             .collect();
 
-        // Instantiate the EvmSketch for the prev and new L2 blocks
+        // Instantiate the EvmSketch for the prev and new L2 blocks. The rpc
+        // traffic is recorded when an rpc url is provided, and replayed from
+        // the recording otherwise.
+        let (rpc_url_l2, fixture_mode) = match std::env::var(format!("RPC_{chain_id_l2}")) {
+            Ok(rpc_url_l2) => (
+                rpc_url_l2.parse::<Url>().expect("Invalid URL format"),
+                RpcFixtureMode::Record,
+            ),
+            Err(_) => (Url::parse("http://0.0.0.0:0")?, RpcFixtureMode::Replay),
+        };
         let (prev_l2_block_executor, new_l2_block_executor) = {
-            let rpc_url_l2 = std::env::var(format!("RPC_{chain_id_l2}"))
-                .expect("RPC URL must be defined")
-                .parse::<Url>()
-                .expect("Invalid URL format");
+            let fixture = RpcFixture::open(&RpcFixtureConfig {
+                mode: fixture_mode,
+                path: std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("src/test_input/bridge_rpc_fixture_e2e_sepolia.json"),
+            })?;
+            let rpc_client_l2 = prover_alloy::build_rpc_client(
+                "l2-execution-layer",
+                &rpc_url_l2.into(),
+                Some(&fixture),
+            )?;
 
             let evm_sketch = |block_number: u64| {
                 // Remove comment lines from the JSON string before parsing
@@ -691,7 +706,7 @@ mod tests {
                     .optimism()
                     .at_block(BlockNumberOrTag::Number(block_number))
                     .with_genesis(Genesis::Custom(genesis_parsed.config))
-                    .el_rpc_url(rpc_url_l2.clone())
+                    .provider(RootProvider::<AnyNetwork>::new(rpc_client_l2.clone()))
             };
 
             let prev = evm_sketch(initial_block_number).build().await?;
@@ -880,14 +895,30 @@ mod tests {
 
         println!("Bridge constraints test completed successfully.");
 
-        // Serialize bridge_data_input into JSON and write it to a file.
-        let file_path = std::path::Path::new("src/test_input/bridge_constraints_input.json");
-        let file = std::fs::File::create(file_path)
-            .expect("Failed to create the bridge constraints input file");
-        serde_json::to_writer_pretty(file, &bridge_data_input)
-            .expect("Failed to write bridge_data_input to file");
-
-        println!("Bridge constraints input file created at: {file_path:?}");
+        let file_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/test_input/bridge_constraints_input.json");
+        if fixture_mode == RpcFixtureMode::Record {
+            // Serialize bridge_data_input into JSON and write it to a file.
+            let file = std::fs::File::create(&file_path)
+                .expect("Failed to create the bridge constraints input file");
+            serde_json::to_writer_pretty(file, &bridge_data_input)
+                .expect("Failed to write bridge_data_input to file");
+
+            println!("Bridge constraints input file created at: {file_path:?}");
+        } else {
+            // The replayed sketches are anchored at the blocks of the recorded
+            // input file.
+            let recorded: BridgeConstraintsInput =
+                serde_json::from_reader(BufReader::new(File::open(&file_path)?))?;
+            assert_eq!(
+                bridge_data_input.prev_l2_block_hash,
+                recorded.prev_l2_block_hash
+            );
+            assert_eq!(
+                bridge_data_input.new_l2_block_hash,
+                recorded.new_l2_block_hash
+            );
+        }
 
         assert_bridge_data(bridge_data_input);
 
@@ -948,9 +979,11 @@ mod tests {
         // 1. Ask someone from Agglayer team to give you the required Quiknode RPC URL
         // 2. Put it into an environment variable: `export RPC_11155420=https://dawn-maximum-dream.optimism-sepolia.quiknode.pro/[censored]`
         // 3. Run `cargo test --workspace -- bridge::tests::test_bridge_constraints
-        //    --exact --show-output --include-ignored` (Or you can limit to `--package
-        //    aggchain-proof-core --lib` if your cargo folder is not filled yet)
-        // 4. The file should then be ready for committing
+        //    --exact --show-output` (Or you can limit to `--package aggchain-proof-core
+        //    --lib` if your cargo folder is not filled yet)
+        // 4. The file should then be ready for committing, along with the rpc traffic
+        //    recorded to `bridge_rpc_fixture_e2e_sepolia.json`, which the e2e test
+        //    replays when the RPC URL is not set
         // Note that it is possible the RPC no longer has the required blocks available
         // for proof getting.
         // In this case, you can use the script here to regenerate the tests:
@@ -305,7 +305,7 @@ impl FepInputs {
 
 /// Compute output root as defined here:
 /// https://specs.optimism.io/protocol/proposals.html#l2-output-commitment-construction
-pub(crate) fn compute_output_root(
+pub fn compute_output_root(
     state_root: [u8; 32],
     withdrawal_storage_root: [u8; 32],
     block_hash: [u8; 32],
@@ -1,3 +1,5 @@
+use std::{fmt, time::Duration};
+
 use aggchain_proof_builder::config::AggchainProofBuilderConfig;
 use proposer_service::config::ProposerServiceConfig;
 use serde::{Deserialize, Serialize};
@@ -16,6 +18,71 @@ pub struct AggchainProofServiceConfig {
     /// without rebuilding the aggkit-prover image.
     #[serde(default, skip_serializing_if = "OpSuccinctVkeyConfig::is_empty")]
     pub op_succinct: OpSuccinctVkeyConfig,
+
+    /// Finality required from the L2 blocks before proving them.
+    #[serde(default)]
+    pub l2_finality: L2FinalityConfig,
+}
+
+/// Head of the L2 chain, as reported by the rollup node, that the proven
+/// blocks must be at or behind.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum L2FinalityPolicy {
+    /// Prove any block, the rollup node is not queried.
+    #[default]
+    Unsafe,
+
+    /// Prove the blocks derived from the L1.
+    Safe,
+
+    /// Prove the blocks derived from finalized L1 blocks.
+    Finalized,
+}
+
+impl fmt::Display for L2FinalityPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Unsafe => "unsafe",
+            Self::Safe => "safe",
+            Self::Finalized => "finalized",
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct L2FinalityConfig {
+    /// Head the requested end block must be at or behind.
+    #[serde(default)]
+    pub policy: L2FinalityPolicy,
+
+    /// How long a request beyond the head waits for it to catch up before
+    /// being rejected. Requests are rejected right away when zero.
+    #[serde(default, with = "prover_utils::with::HumanDuration")]
+    pub wait_timeout: Duration,
+
+    /// Interval between two polls of the rollup node sync status while
+    /// waiting.
+    #[serde(
+        default = "default_l2_finality_poll_interval",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub poll_interval: Duration,
+}
+
+impl Default for L2FinalityConfig {
+    fn default() -> Self {
+        Self {
+            policy: L2FinalityPolicy::default(),
+            wait_timeout: Duration::ZERO,
+            poll_interval: default_l2_finality_poll_interval(),
+        }
+    }
+}
+
+fn default_l2_finality_poll_interval() -> Duration {
+    Duration::from_secs(5)
 }
 
 /// Optional overrides of the op-succinct verification key material derived from
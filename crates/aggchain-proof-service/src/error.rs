@@ -1,3 +1,5 @@
+use crate::config::L2FinalityPolicy;
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("Unable to create alloy provider")]
@@ -12,6 +14,9 @@ pub enum Error {
     #[error("Proposer service request failed")]
     ProposerServiceRequestFailed(#[source] proposer_service::Error),
 
+    #[error("Proposer service is unhealthy")]
+    ProposerServiceUnhealthy(#[source] proposer_service::Error),
+
     #[error("Unable to setup aggchain proof builder")]
     AggchainProofBuilderInitFailed(#[source] eyre::Error),
 
@@ -21,6 +26,19 @@ pub enum Error {
     #[error("Aggchain proof builder service request failed")]
     AggchainProofBuilderRequestFailed(#[source] aggchain_proof_builder::Error),
 
+    #[error("Aggchain proof builder is unhealthy")]
+    AggchainProofBuilderUnhealthy(#[source] aggchain_proof_builder::Error),
+
+    #[error("Unable to retrieve the l2 sync status")]
+    L2SyncStatusRetrievalFailed(#[source] aggchain_proof_contracts::Error),
+
+    #[error("L2 block {block_number} is beyond the {policy} head {head}")]
+    L2BlockNotFinal {
+        block_number: u64,
+        policy: L2FinalityPolicy,
+        head: u64,
+    },
+
     #[error("Unable to setup aggchain contracts client")]
     ContractsClientInitFailed(#[source] aggchain_proof_contracts::Error),
 
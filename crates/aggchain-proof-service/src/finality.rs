@@ -0,0 +1,157 @@
+//! Gating of the proof requests on the finality of the L2 blocks.
+
+use std::sync::Arc;
+
+use aggchain_proof_contracts::contracts::L2SyncStatusFetcher;
+use tokio::time::Instant;
+use tracing::debug;
+
+use crate::{
+    config::{L2FinalityConfig, L2FinalityPolicy},
+    error::Error,
+};
+
+/// Holds back the requests for L2 blocks beyond the configured
+/// [`L2FinalityPolicy`].
+#[derive(Clone)]
+pub(crate) struct L2FinalityGate {
+    sync_status_fetcher: Arc<dyn L2SyncStatusFetcher + Send + Sync>,
+    config: L2FinalityConfig,
+}
+
+impl L2FinalityGate {
+    pub(crate) fn new(
+        sync_status_fetcher: Arc<dyn L2SyncStatusFetcher + Send + Sync>,
+        config: L2FinalityConfig,
+    ) -> Self {
+        Self {
+            sync_status_fetcher,
+            config,
+        }
+    }
+
+    /// Waits until `block_number` is at or behind the head of the policy,
+    /// failing with [`Error::L2BlockNotFinal`] once the wait timeout elapses.
+    pub(crate) async fn wait_for(&self, block_number: u64) -> Result<(), Error> {
+        let policy = self.config.policy;
+        if policy == L2FinalityPolicy::Unsafe {
+            return Ok(());
+        }
+
+        let deadline = Instant::now() + self.config.wait_timeout;
+        loop {
+            let sync_status = self
+                .sync_status_fetcher
+                .get_l2_sync_status()
+                .await
+                .map_err(Error::L2SyncStatusRetrievalFailed)?;
+            let head = match policy {
+                L2FinalityPolicy::Unsafe => sync_status.unsafe_l2,
+                L2FinalityPolicy::Safe => sync_status.safe_l2,
+                L2FinalityPolicy::Finalized => sync_status.finalized_l2,
+            };
+
+            if block_number <= head {
+                return Ok(());
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Error::L2BlockNotFinal {
+                    block_number,
+                    policy,
+                    head,
+                });
+            }
+
+            debug!(%block_number, %policy, %head, "Waiting for the l2 head to catch up");
+            tokio::time::sleep(self.config.poll_interval.min(deadline - now)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicU64, Ordering},
+        time::Duration,
+    };
+
+    use aggchain_proof_contracts::contracts::L2SyncStatus;
+
+    use super::*;
+
+    /// Rollup node whose safe head moves forward by one block on every poll.
+    struct AdvancingRollupNode {
+        safe_l2: AtomicU64,
+    }
+
+    #[async_trait::async_trait]
+    impl L2SyncStatusFetcher for AdvancingRollupNode {
+        async fn get_l2_sync_status(
+            &self,
+        ) -> Result<L2SyncStatus, aggchain_proof_contracts::Error> {
+            let safe_l2 = self.safe_l2.fetch_add(1, Ordering::Relaxed);
+            Ok(L2SyncStatus {
+                unsafe_l2: safe_l2 + 10,
+                safe_l2,
+                finalized_l2: 0,
+            })
+        }
+    }
+
+    fn gate(policy: L2FinalityPolicy, wait_timeout: Duration) -> L2FinalityGate {
+        L2FinalityGate::new(
+            Arc::new(AdvancingRollupNode {
+                safe_l2: AtomicU64::new(100),
+            }),
+            L2FinalityConfig {
+                policy,
+                wait_timeout,
+                poll_interval: Duration::from_secs(1),
+            },
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn blocks_behind_the_head_pass() {
+        gate(L2FinalityPolicy::Safe, Duration::ZERO)
+            .wait_for(100)
+            .await
+            .unwrap();
+        gate(L2FinalityPolicy::Unsafe, Duration::ZERO)
+            .wait_for(1_000)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn blocks_beyond_the_head_are_rejected_without_wait_timeout() {
+        let result = gate(L2FinalityPolicy::Finalized, Duration::ZERO)
+            .wait_for(1)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::L2BlockNotFinal {
+                block_number: 1,
+                policy: L2FinalityPolicy::Finalized,
+                head: 0,
+            })
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn requests_wait_for_the_head_to_catch_up() {
+        let gate = gate(L2FinalityPolicy::Safe, Duration::from_secs(10));
+
+        gate.wait_for(105).await.unwrap();
+        assert!(matches!(
+            gate.wait_for(200).await,
+            Err(Error::L2BlockNotFinal {
+                block_number: 200,
+                ..
+            })
+        ));
+    }
+}
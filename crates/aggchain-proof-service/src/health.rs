@@ -0,0 +1,37 @@
+use std::{future::Future, sync::Arc};
+
+use futures::{future::BoxFuture, FutureExt as _};
+
+use crate::error::Error;
+
+type HealthCheck = Arc<dyn Fn() -> BoxFuture<'static, Result<(), Error>> + Send + Sync>;
+
+/// Health checks of the downstream dependencies of the
+/// [`AggchainProofService`](crate::service::AggchainProofService): the
+/// proposer, the L1 and L2 nodes and the prover backend.
+#[derive(Clone, Default)]
+pub struct AggchainProofServiceHealth {
+    checks: Vec<HealthCheck>,
+}
+
+impl AggchainProofServiceHealth {
+    /// Registers a check run against a clone of `target`.
+    pub(crate) fn add_check<T, F, Fut>(&mut self, target: T, check: F)
+    where
+        T: Clone + Send + Sync + 'static,
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), Error>> + Send + 'static,
+    {
+        self.checks
+            .push(Arc::new(move || check(target.clone()).boxed()));
+    }
+
+    /// Runs every check, stopping at the first failing one.
+    pub async fn check(&self) -> Result<(), Error> {
+        for check in &self.checks {
+            check().await?;
+        }
+
+        Ok(())
+    }
+}
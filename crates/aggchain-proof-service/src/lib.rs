@@ -2,6 +2,8 @@ pub mod config;
 
 mod custom_chain_data;
 mod error;
+mod finality;
+pub mod health;
 pub mod service;
 
 pub use aggchain_proof_builder::AGGCHAIN_PROOF_ELF;
@@ -6,20 +6,27 @@ use std::{
 };
 
 use aggchain_proof_builder::{AggchainProofBuilder, FepVerification};
-use aggchain_proof_contracts::AggchainContractsRpcClient;
-use aggchain_proof_types::{AggchainProofInputs, OptimisticAggchainProofInputs};
+use aggchain_proof_contracts::{
+    contracts::GetTrustedSequencerAddress, AggchainContractsClient, AggchainContractsRpcClient,
+};
+use aggchain_proof_core::full_execution_proof::AggchainParamsValues;
+use aggchain_proof_types::{
+    AggchainProofInputs, OptimisticAggchainProofInputs, ProofProgress, ProofStage,
+};
+use agglayer_evm_client::GetBlockNumber;
 use agglayer_interop::types::Digest;
 use alloy_primitives::B256;
 use futures::FutureExt as _;
 use proposer_client::FepProposerRequest;
 use proposer_service::ProposerService;
-use sp1_sdk::HashableKey as _;
+use sp1_sdk::{HashableKey as _, SP1VerifyingKey};
 use tower::{util::BoxCloneService, Service as _, ServiceExt as _};
 use tracing::{debug, info};
 use unified_bridge::AggchainProofPublicValues;
 
 use crate::{
     config::AggchainProofServiceConfig, custom_chain_data::compute_custom_chain_data, error::Error,
+    finality::L2FinalityGate, health::AggchainProofServiceHealth,
 };
 
 /// A request for the AggchainProofService to generate the
@@ -27,9 +34,17 @@ use crate::{
 #[derive(Clone, Debug)]
 pub enum AggchainProofServiceRequest {
     /// Aggchain proof request information
-    Normal(AggchainProofInputs),
+    Normal {
+        inputs: AggchainProofInputs,
+        /// Observer of the stages of the request.
+        progress: ProofProgress,
+    },
     /// Optimistic aggchain proof request information
-    Optimistic(OptimisticAggchainProofInputs),
+    Optimistic {
+        inputs: OptimisticAggchainProofInputs,
+        /// Observer of the stages of the request.
+        progress: ProofProgress,
+    },
 }
 
 /// Resulting generated Aggchain proof
@@ -64,6 +79,29 @@ pub struct AggchainProofServiceResponse {
 
     /// The AggchainProof's public inputs that were produced by the prover.
     pub public_values: AggchainProofPublicValues,
+
+    /// Output root (new state root) at the end block.
+    pub output_root: Digest,
+
+    /// Values hashed into the aggchain params.
+    pub aggchain_params_values: AggchainParamsValues,
+
+    /// Id of the request of the aggchain proof on the sp1 proving network,
+    /// if proven there.
+    pub network_request_id: Option<B256>,
+}
+
+/// Health check of the proposer service, generic over its proposer client.
+async fn check_proposer_health<L1Rpc, ProposerClient>(
+    proposer_service: ProposerService<L1Rpc, ProposerClient>,
+) -> Result<(), Error>
+where
+    ProposerClient: proposer_client::ProposerClient + Send + Sync,
+{
+    proposer_service
+        .check_health()
+        .await
+        .map_err(Error::ProposerServiceUnhealthy)
 }
 
 /// The Aggchain proof service is responsible for orchestrating an Aggchain
@@ -84,12 +122,49 @@ pub struct AggchainProofService {
         aggchain_proof_builder::AggchainProofBuilderResponse,
         aggchain_proof_builder::Error,
     >,
+    pub(crate) health: AggchainProofServiceHealth,
+    pub(crate) aggchain_vkey: Arc<SP1VerifyingKey>,
+    pub(crate) l2_finality: L2FinalityGate,
 }
 
 impl AggchainProofService {
     pub async fn new(config: &AggchainProofServiceConfig) -> Result<Self, Error> {
         debug!("Initializing AggchainProofService");
 
+        let client = prover_alloy::AlloyProvider::new(
+            "l1",
+            &config.proposer_service.l1_rpc_endpoint.endpoints,
+        )
+        .map_err(Error::AlloyProviderInitializationFailed)?;
+        let l1_rpc_client = Arc::new(client);
+        debug!("L1 RPC client initialized");
+
+        let contract_l1_client = Arc::new(
+            AggchainContractsRpcClient::new(
+                config.aggchain_proof_builder.network_id,
+                &config.aggchain_proof_builder.contracts,
+            )
+            .await
+            .map_err(Error::ContractsClientInitFailed)?,
+        );
+        debug!("Contract L1 client initialized");
+
+        Self::new_with_clients(config, l1_rpc_client, contract_l1_client).await
+    }
+
+    /// Creates the service over the given L1 node and contracts clients
+    /// instead of the ones of the configuration, so that the whole pipeline
+    /// can run against fake chains.
+    pub async fn new_with_clients<L1Rpc, ContractsClient>(
+        config: &AggchainProofServiceConfig,
+        l1_rpc_client: Arc<L1Rpc>,
+        contract_l1_client: Arc<ContractsClient>,
+    ) -> Result<Self, Error>
+    where
+        L1Rpc: GetBlockNumber<Error: Into<eyre::Error>> + Send + Sync + 'static,
+        ContractsClient:
+            AggchainContractsClient + GetTrustedSequencerAddress + Send + Sync + 'static,
+    {
         // Install the optional op-succinct vkey overrides from configuration before
         // constructing the services, so the proposer service (host-side
         // verification) and the proof builder (recursive verification) both read
@@ -134,65 +209,73 @@ impl AggchainProofService {
             "Resolved op-succinct verification keys",
         );
 
-        let client = prover_alloy::AlloyProvider::new(
-            &config.proposer_service.l1_rpc_endpoint.url,
-            prover_alloy::DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS,
-            prover_alloy::DEFAULT_HTTP_RPC_NODE_BACKOFF_MAX_RETRIES,
-        )
-        .map_err(Error::AlloyProviderInitializationFailed)?;
-        let l1_rpc_client = Arc::new(client);
-        debug!("L1 RPC client initialized");
-
-        let contract_l1_client = Arc::new(
-            AggchainContractsRpcClient::new(
-                config.aggchain_proof_builder.network_id,
-                &config.aggchain_proof_builder.contracts,
-            )
-            .await
-            .map_err(Error::ContractsClientInitFailed)?,
-        );
-        debug!("Contract L1 client initialized");
+        let mut health = AggchainProofServiceHealth::default();
 
         let proposer_service = if config.proposer_service.mock {
+            let proposer_service =
+                ProposerService::new_mock(&config.proposer_service, l1_rpc_client)
+                    .await
+                    .map_err(Error::ProposerServiceInitFailed)?;
+            health.add_check(proposer_service.clone(), check_proposer_health);
+
             tower::ServiceBuilder::new()
-                .service(
-                    ProposerService::new_mock(&config.proposer_service, l1_rpc_client)
-                        .await
-                        .map_err(Error::ProposerServiceInitFailed)?,
-                )
+                .service(proposer_service)
                 .boxed_clone()
         } else {
+            let proposer_service =
+                ProposerService::new_network(&config.proposer_service, l1_rpc_client)
+                    .await
+                    .map_err(Error::ProposerServiceInitFailed)?;
+            health.add_check(proposer_service.clone(), check_proposer_health);
+
             tower::ServiceBuilder::new()
-                .service(
-                    ProposerService::new_network(&config.proposer_service, l1_rpc_client)
-                        .await
-                        .map_err(Error::ProposerServiceInitFailed)?,
-                )
+                .service(proposer_service)
                 .boxed_clone()
         };
         debug!("ProposerService initialized");
 
-        let aggchain_proof_builder = tower::ServiceBuilder::new()
-            .service(
-                AggchainProofBuilder::new(
-                    &config.aggchain_proof_builder,
-                    contract_l1_client.clone(),
-                )
+        let aggchain_proof_builder =
+            AggchainProofBuilder::new(&config.aggchain_proof_builder, contract_l1_client.clone())
                 .await
-                .map_err(Error::AggchainProofBuilderInitFailed)?,
-            )
+                .map_err(Error::AggchainProofBuilderInitFailed)?;
+        let aggchain_vkey = aggchain_proof_builder.aggchain_vkey();
+        health.add_check(aggchain_proof_builder.clone(), |builder| async move {
+            builder
+                .check_health()
+                .await
+                .map_err(Error::AggchainProofBuilderUnhealthy)
+        });
+
+        let aggchain_proof_builder = tower::ServiceBuilder::new()
+            .service(aggchain_proof_builder)
             .boxed_clone();
         debug!("AggchainProofBuilder initialized");
 
+        let l2_finality = L2FinalityGate::new(contract_l1_client, config.l2_finality.clone());
+
         Ok(AggchainProofService {
             proposer_service,
             aggchain_proof_builder,
+            health,
+            aggchain_vkey,
+            l2_finality,
         })
     }
 
+    /// Health checks of the dependencies of the service.
+    pub fn health(&self) -> AggchainProofServiceHealth {
+        self.health.clone()
+    }
+
+    /// Verification key of the aggchain proof program in effect.
+    pub fn aggchain_vkey(&self) -> Arc<SP1VerifyingKey> {
+        self.aggchain_vkey.clone()
+    }
+
     fn handle_normal_request(
         &mut self,
         aggchain_proof_inputs: AggchainProofInputs,
+        progress: ProofProgress,
     ) -> AggchainProofServiceFuture {
         let l1_block_hash = aggchain_proof_inputs.l1_info_tree_leaf.inner.block_hash;
 
@@ -204,11 +287,19 @@ impl AggchainProofService {
 
         let mut proposer_service = self.proposer_service.clone();
         let mut proof_builder = self.aggchain_proof_builder.clone();
+        let l2_finality = self.l2_finality.clone();
 
         async move {
             let last_proven_block = aggchain_proof_inputs.last_proven_block;
+            // The end block picked by the proposer is at most the requested one.
+            progress.report(ProofStage::WaitingForL2Finality);
+            l2_finality
+                .wait_for(aggchain_proof_inputs.requested_end_block)
+                .await?;
+
             // The ProposerResponse contains the start and end block number
             // It also contains the generated proof.
+            progress.report(ProofStage::WaitingForAggregationProof);
             let aggregation_proof_response = proposer_service
                 .call(proposer_request)
                 .await
@@ -222,6 +313,7 @@ impl AggchainProofService {
                     },
                     end_block: aggregation_proof_response.end_block,
                     aggchain_proof_inputs,
+                    progress,
                 };
 
             let end_block = aggchain_proof_builder_request.end_block;
@@ -231,6 +323,7 @@ impl AggchainProofService {
                 .await
                 .map_err(Error::AggchainProofBuilderRequestFailed)?;
 
+            let output_root = aggchain_proof_response.output_root.0;
             let custom_chain_data =
                 compute_custom_chain_data(aggchain_proof_response.output_root, end_block);
 
@@ -243,6 +336,9 @@ impl AggchainProofService {
                 local_exit_root_hash: aggchain_proof_response.new_local_exit_root,
                 custom_chain_data,
                 public_values: aggchain_proof_response.public_values,
+                output_root,
+                aggchain_params_values: aggchain_proof_response.aggchain_params_values,
+                network_request_id: aggchain_proof_response.network_request_id,
             })
         }
         .boxed()
@@ -254,11 +350,17 @@ impl AggchainProofService {
             aggchain_proof_inputs,
             signature_optimistic_mode,
         }: OptimisticAggchainProofInputs,
+        progress: ProofProgress,
     ) -> AggchainProofServiceFuture {
         let mut proof_builder = self.aggchain_proof_builder.clone();
+        let l2_finality = self.l2_finality.clone();
 
         async move {
             let last_proven_block = aggchain_proof_inputs.last_proven_block;
+            progress.report(ProofStage::WaitingForL2Finality);
+            l2_finality
+                .wait_for(aggchain_proof_inputs.requested_end_block)
+                .await?;
 
             let aggchain_proof_builder_request =
                 aggchain_proof_builder::AggchainProofBuilderRequest {
@@ -268,6 +370,7 @@ impl AggchainProofService {
                     // In optimistic mode, the end_block is the one defined in the request.
                     end_block: aggchain_proof_inputs.requested_end_block,
                     aggchain_proof_inputs,
+                    progress,
                 };
 
             let end_block = aggchain_proof_builder_request.end_block;
@@ -277,6 +380,7 @@ impl AggchainProofService {
                 .await
                 .map_err(Error::AggchainProofBuilderRequestFailed)?;
 
+            let output_root = aggchain_proof_response.output_root.0;
             let custom_chain_data =
                 compute_custom_chain_data(aggchain_proof_response.output_root, end_block);
 
@@ -289,6 +393,9 @@ impl AggchainProofService {
                 local_exit_root_hash: aggchain_proof_response.new_local_exit_root,
                 custom_chain_data,
                 public_values: aggchain_proof_response.public_values,
+                output_root,
+                aggchain_params_values: aggchain_proof_response.aggchain_params_values,
+                network_request_id: aggchain_proof_response.network_request_id,
             })
         }
         .boxed()
@@ -316,11 +423,11 @@ impl tower::Service<AggchainProofServiceRequest> for AggchainProofService {
 
     fn call(&mut self, req: AggchainProofServiceRequest) -> Self::Future {
         match req {
-            AggchainProofServiceRequest::Normal(aggchain_proof_inputs) => {
-                self.handle_normal_request(aggchain_proof_inputs)
+            AggchainProofServiceRequest::Normal { inputs, progress } => {
+                self.handle_normal_request(inputs, progress)
             }
-            AggchainProofServiceRequest::Optimistic(optimistic_aggchain_proof_inputs) => {
-                self.handle_optimistic_request(optimistic_aggchain_proof_inputs)
+            AggchainProofServiceRequest::Optimistic { inputs, progress } => {
+                self.handle_optimistic_request(inputs, progress)
             }
         }
     }
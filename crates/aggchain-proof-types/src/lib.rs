@@ -1,8 +1,10 @@
 pub mod imported_bridge_exit;
 pub mod inserted_ger;
+mod progress;
 mod proof;
 pub mod removed_ger;
 pub mod unclaim;
 
 pub use aggchain_proof_core::vkey_hash::HashU32;
+pub use progress::*;
 pub use proof::*;
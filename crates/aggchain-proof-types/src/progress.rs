@@ -0,0 +1,39 @@
+use std::{fmt, sync::Arc};
+
+/// Stage of the generation of an aggchain proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofStage {
+    /// Waiting for the L2 end block to reach the configured finality.
+    WaitingForL2Finality,
+    /// Waiting for the proposer to deliver the aggregation proof of the range.
+    WaitingForAggregationProof,
+    /// Fetching the bridge events and the chain data from the L1 and L2 nodes.
+    FetchingChainData,
+    /// Generating the aggchain proof.
+    Proving,
+}
+
+/// Observer of the stages an aggchain proof request goes through.
+///
+/// The default observer ignores the stages.
+#[derive(Clone, Default)]
+pub struct ProofProgress(Option<Arc<dyn Fn(ProofStage) + Send + Sync>>);
+
+impl ProofProgress {
+    pub fn new(report: impl Fn(ProofStage) + Send + Sync + 'static) -> Self {
+        Self(Some(Arc::new(report)))
+    }
+
+    /// Reports that the request entered the given stage.
+    pub fn report(&self, stage: ProofStage) {
+        if let Some(report) = &self.0 {
+            report(stage);
+        }
+    }
+}
+
+impl fmt::Debug for ProofProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProofProgress").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,144 @@
+use std::future::Future;
+
+use aggchain_proof_types::{AggchainProofInputs, OptimisticAggchainProofInputs};
+use aggkit_prover_types::v1::{
+    aggchain_proof_service_client::AggchainProofServiceClient, GenerateAggchainProofRequest,
+    GenerateOptimisticAggchainProofRequest,
+};
+use prover_logger::propagation::{TraceContextLayer, TraceContextService};
+use tonic::{transport::Channel, Code, Status};
+use tower::Layer as _;
+use tracing::{error, warn};
+
+use crate::{AggchainProofResponse, AggkitProverClientConfig, Error};
+
+/// Status codes of the requests refused for a transient reason, which are
+/// retried.
+const RETRYABLE_CODES: &[Code] = &[Code::Unavailable, Code::ResourceExhausted, Code::Aborted];
+
+type GrpcClient = AggchainProofServiceClient<TraceContextService<Channel>>;
+
+/// Client of the aggkit prover, requesting aggchain proofs from typed inputs.
+///
+/// Requests refused with a retryable status code are retried with an
+/// exponential backoff, and each attempt is bounded by the configured request
+/// timeout, which is also propagated to the prover as the gRPC deadline.
+#[derive(Clone)]
+pub struct AggkitProverClient {
+    client: GrpcClient,
+    config: AggkitProverClientConfig,
+}
+
+impl AggkitProverClient {
+    /// Connects to the prover at the configured endpoint.
+    pub async fn connect(config: AggkitProverClientConfig) -> Result<Self, Error> {
+        let channel = Channel::builder(config.endpoint.clone())
+            .connect_timeout(config.connect_timeout)
+            .connect()
+            .await
+            .inspect_err(|e| error!("Error connecting to aggkit prover gRPC: {e}"))
+            .map_err(Error::Connect)?;
+
+        Ok(Self::new(channel, config))
+    }
+
+    /// Creates a client over an existing channel. The endpoint and connect
+    /// timeout of the configuration are unused.
+    pub fn new(channel: Channel, config: AggkitProverClientConfig) -> Self {
+        Self {
+            client: AggchainProofServiceClient::new(TraceContextLayer.layer(channel)),
+            config,
+        }
+    }
+
+    pub async fn generate_aggchain_proof(
+        &self,
+        inputs: AggchainProofInputs,
+    ) -> Result<AggchainProofResponse, Error> {
+        let request = GenerateAggchainProofRequest::from(inputs);
+
+        let response = self
+            .call_with_retries(
+                "generate_aggchain_proof",
+                request,
+                |mut client, request| async move { client.generate_aggchain_proof(request).await },
+            )
+            .await?;
+
+        AggchainProofResponse::try_from(response).map_err(Error::InvalidResponse)
+    }
+
+    pub async fn generate_optimistic_aggchain_proof(
+        &self,
+        inputs: OptimisticAggchainProofInputs,
+    ) -> Result<AggchainProofResponse, Error> {
+        let request = GenerateOptimisticAggchainProofRequest::from(inputs);
+
+        let response = self
+            .call_with_retries(
+                "generate_optimistic_aggchain_proof",
+                request,
+                |mut client, request| async move {
+                    client.generate_optimistic_aggchain_proof(request).await
+                },
+            )
+            .await?;
+
+        AggchainProofResponse::try_from(response).map_err(Error::InvalidResponse)
+    }
+
+    async fn call_with_retries<Req, Res, F, Fut>(
+        &self,
+        method: &'static str,
+        request: Req,
+        call: F,
+    ) -> Result<Res, Error>
+    where
+        Req: Clone,
+        F: Fn(GrpcClient, tonic::Request<Req>) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<Res>, Status>>,
+    {
+        let mut delay = self.config.initial_backoff;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+
+            let mut grpc_request = tonic::Request::new(request.clone());
+            grpc_request.set_timeout(self.config.request_timeout);
+
+            let result = tokio::time::timeout(
+                self.config.request_timeout,
+                call(self.client.clone(), grpc_request),
+            )
+            .await
+            .unwrap_or_else(|_| {
+                Err(Status::deadline_exceeded(format!(
+                    "No response after {:?}",
+                    self.config.request_timeout
+                )))
+            });
+
+            let status = match result {
+                Ok(response) => return Ok(response.into_inner()),
+                Err(status) => status,
+            };
+
+            if attempt > self.config.max_retries || !RETRYABLE_CODES.contains(&status.code()) {
+                return Err(Error::Request {
+                    attempts: attempt,
+                    status: Box::new(status),
+                });
+            }
+
+            warn!(
+                method,
+                attempt,
+                retry_in = ?delay,
+                "Aggkit prover request failed: {status}"
+            );
+            tokio::time::sleep(delay).await;
+            delay = delay.saturating_mul(2).min(self.config.max_backoff);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::{str::FromStr as _, time::Duration};
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::GrpcUri;
+
+/// The default aggkit prover endpoint.
+const DEFAULT_ENDPOINT: &str = "http://127.0.0.1:8081";
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct AggkitProverClientConfig {
+    /// The aggkit prover gRPC endpoint.
+    #[serde(default = "default_endpoint")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub endpoint: GrpcUri,
+
+    /// Timeout of the connection to the prover.
+    #[serde(default = "default_connect_timeout")]
+    #[serde(with = "prover_utils::with::HumanDuration")]
+    pub connect_timeout: Duration,
+
+    /// Deadline of each attempt at a request, propagated to the prover.
+    #[serde(default = "default_request_timeout")]
+    #[serde(with = "prover_utils::with::HumanDuration")]
+    pub request_timeout: Duration,
+
+    /// Number of retries of a request refused for a transient reason.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: usize,
+
+    /// Delay before the first retry. The delay is doubled after each failed
+    /// attempt.
+    #[serde(default = "default_initial_backoff")]
+    #[serde(with = "prover_utils::with::HumanDuration")]
+    pub initial_backoff: Duration,
+
+    /// Upper bound of the delay between two attempts.
+    #[serde(default = "default_max_backoff")]
+    #[serde(with = "prover_utils::with::HumanDuration")]
+    pub max_backoff: Duration,
+}
+
+impl Default for AggkitProverClientConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: default_endpoint(),
+            connect_timeout: default_connect_timeout(),
+            request_timeout: default_request_timeout(),
+            max_retries: default_max_retries(),
+            initial_backoff: default_initial_backoff(),
+            max_backoff: default_max_backoff(),
+        }
+    }
+}
+
+fn default_endpoint() -> GrpcUri {
+    GrpcUri::from_str(DEFAULT_ENDPOINT).unwrap()
+}
+
+const fn default_connect_timeout() -> Duration {
+    Duration::from_secs(10)
+}
+
+const fn default_request_timeout() -> Duration {
+    Duration::from_secs(3600)
+}
+
+const fn default_max_retries() -> usize {
+    3
+}
+
+const fn default_initial_backoff() -> Duration {
+    Duration::from_secs(1)
+}
+
+const fn default_max_backoff() -> Duration {
+    Duration::from_secs(30)
+}
@@ -0,0 +1,32 @@
+use aggkit_prover_types::error::AggchainProofContextError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Error initializing grpc connection")]
+    Connect(#[source] tonic::transport::Error),
+
+    #[error("Aggkit prover request failed after {attempts} attempt(s)")]
+    Request {
+        attempts: usize,
+        #[source]
+        status: Box<tonic::Status>,
+    },
+
+    #[error("Invalid aggkit prover response")]
+    InvalidResponse(#[source] ResponseError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ResponseError {
+    #[error("Missing `{0}` in the response")]
+    MissingField(&'static str),
+
+    #[error("Conversion of `{field}` failed")]
+    InvalidField {
+        field: &'static str,
+        source: eyre::Error,
+    },
+
+    #[error("Invalid aggchain proof context")]
+    InvalidContext(#[from] AggchainProofContextError),
+}
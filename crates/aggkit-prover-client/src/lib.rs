@@ -0,0 +1,23 @@
+//! Typed client of the aggkit prover gRPC service.
+//!
+//! The client converts the aggchain proof inputs into the protobuf requests,
+//! retries the requests refused for transient reasons, and decodes the
+//! responses back into typed values.
+
+pub use aggkit_prover_types::conversion::v1::context::AggchainProofContext;
+pub use tonic::transport::Uri as GrpcUri;
+
+pub use crate::{
+    client::AggkitProverClient,
+    config::AggkitProverClientConfig,
+    error::{Error, ResponseError},
+    response::{AggchainProofResponse, Sp1StarkProof},
+};
+
+pub mod client;
+pub mod config;
+pub mod error;
+pub mod response;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use aggchain_proof_core::full_execution_proof::AggchainParamsValues;
+use aggkit_prover_types::{
+    conversion::v1::context::AggchainProofContext,
+    v1::{self, GenerateAggchainProofResponse, GenerateOptimisticAggchainProofResponse},
+};
+use agglayer_interop::{
+    grpc::v1::{aggchain_proof::Proof, AggchainProof, FixedBytes32},
+    types::{bincode, Digest},
+};
+use prost::bytes::Bytes;
+use unified_bridge::AggchainProofPublicValues;
+
+use crate::error::ResponseError as Error;
+
+/// SP1 STARK proof generated by the prover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sp1StarkProof {
+    /// Version of the SP1 circuit the proof was generated with.
+    pub version: String,
+    /// Bincode serialized proof.
+    pub proof: Bytes,
+    /// Bincode serialized verifying key of the aggchain proof program.
+    pub vkey: Bytes,
+}
+
+/// Aggchain proof returned by the prover, decoded from either of the
+/// `GenerateAggchainProof` and `GenerateOptimisticAggchainProof` responses.
+#[derive(Debug, Clone)]
+pub struct AggchainProofResponse {
+    pub proof: Sp1StarkProof,
+    pub aggchain_params: Digest,
+    pub public_values: AggchainProofPublicValues,
+    /// Last block proven before this proof.
+    pub last_proven_block: u64,
+    /// Last block included in this proof.
+    pub end_block: u64,
+    pub local_exit_root_hash: Digest,
+    pub custom_chain_data: Bytes,
+    /// L2 output root at the end block, not reported by the provers predating
+    /// the typed response fields.
+    pub output_root: Option<Digest>,
+    /// Values hashed into the aggchain params, not reported by the provers
+    /// predating the typed response fields.
+    pub aggchain_params_values: Option<AggchainParamsValues>,
+    /// Id of the request of the proof on the sp1 proving network, if proven
+    /// there.
+    pub network_request_id: Option<Digest>,
+    /// Context of the request the proof was generated for.
+    pub context: AggchainProofContext,
+}
+
+impl TryFrom<GenerateAggchainProofResponse> for AggchainProofResponse {
+    type Error = Error;
+
+    fn try_from(value: GenerateAggchainProofResponse) -> Result<Self, Self::Error> {
+        decode(
+            value.aggchain_proof,
+            value.local_exit_root_hash,
+            value.custom_chain_data,
+            TypedValues {
+                block_range: Some((value.last_proven_block, value.end_block)),
+                public_values: value.public_values,
+                output_root: value.output_root,
+                aggchain_params_values: value.aggchain_params_values,
+                network_request_id: value.network_request_id,
+            },
+        )
+    }
+}
+
+impl TryFrom<GenerateOptimisticAggchainProofResponse> for AggchainProofResponse {
+    type Error = Error;
+
+    fn try_from(value: GenerateOptimisticAggchainProofResponse) -> Result<Self, Self::Error> {
+        // The provers predating the typed fields report the block range of the
+        // optimistic proofs in the context only.
+        let block_range = value
+            .public_values
+            .is_some()
+            .then_some((value.last_proven_block, value.end_block));
+
+        decode(
+            value.aggchain_proof,
+            value.local_exit_root_hash,
+            value.custom_chain_data,
+            TypedValues {
+                block_range,
+                public_values: value.public_values,
+                output_root: value.output_root,
+                aggchain_params_values: value.aggchain_params_values,
+                network_request_id: value.network_request_id,
+            },
+        )
+    }
+}
+
+/// Typed response fields, preferred over their counterparts in the aggchain
+/// proof context when set.
+struct TypedValues {
+    block_range: Option<(u64, u64)>,
+    public_values: Option<v1::AggchainProofPublicValues>,
+    output_root: Option<FixedBytes32>,
+    aggchain_params_values: Option<v1::AggchainParamsValues>,
+    network_request_id: Option<FixedBytes32>,
+}
+
+fn decode(
+    aggchain_proof: Option<AggchainProof>,
+    local_exit_root_hash: Option<FixedBytes32>,
+    custom_chain_data: Bytes,
+    typed: TypedValues,
+) -> Result<AggchainProofResponse, Error> {
+    let aggchain_proof = aggchain_proof.ok_or(Error::MissingField("aggchain_proof"))?;
+
+    let Some(Proof::Sp1Stark(proof)) = aggchain_proof.proof else {
+        return Err(Error::MissingField("aggchain_proof.proof"));
+    };
+
+    let context = AggchainProofContext::try_from(&aggchain_proof.context)?;
+
+    let public_values = match typed.public_values {
+        Some(public_values) => public_values
+            .try_into()
+            .map_err(|error| Error::InvalidField {
+                field: "public_values",
+                source: eyre::Error::from(error),
+            })?,
+        None => decode_public_values(&aggchain_proof.context)?,
+    };
+
+    let (last_proven_block, end_block) = match typed.block_range {
+        Some(block_range) => block_range,
+        None => (
+            context.last_proven_block,
+            context
+                .end_block
+                .ok_or(Error::MissingField("aggchain_proof.context.end_block"))?,
+        ),
+    };
+
+    let aggchain_params_values = typed
+        .aggchain_params_values
+        .map(AggchainParamsValues::try_from)
+        .transpose()
+        .map_err(|error| Error::InvalidField {
+            field: "aggchain_params_values",
+            source: eyre::Error::from(error),
+        })?;
+
+    Ok(AggchainProofResponse {
+        proof: Sp1StarkProof {
+            version: proof.version,
+            proof: proof.proof,
+            vkey: proof.vkey,
+        },
+        aggchain_params: digest(
+            aggchain_proof.aggchain_params,
+            "aggchain_proof.aggchain_params",
+        )?,
+        public_values,
+        last_proven_block,
+        end_block,
+        local_exit_root_hash: digest(local_exit_root_hash, "local_exit_root_hash")?,
+        custom_chain_data,
+        output_root: typed
+            .output_root
+            .map(|output_root| digest(Some(output_root), "output_root"))
+            .transpose()?,
+        aggchain_params_values,
+        network_request_id: typed
+            .network_request_id
+            .map(|network_request_id| digest(Some(network_request_id), "network_request_id"))
+            .transpose()?,
+        context,
+    })
+}
+
+/// Decodes the public values reported in the context by the provers predating
+/// the typed response fields.
+fn decode_public_values(
+    context: &HashMap<String, Bytes>,
+) -> Result<AggchainProofPublicValues, Error> {
+    const FIELD: &str = "aggchain_proof.context.public_values";
+
+    let public_values = context
+        .get("public_values")
+        .ok_or(Error::MissingField(FIELD))?;
+
+    bincode::sp1_compatible()
+        .deserialize(public_values)
+        .map_err(|error| Error::InvalidField {
+            field: FIELD,
+            source: eyre::Error::from(error),
+        })
+}
+
+fn digest(value: Option<FixedBytes32>, field: &'static str) -> Result<Digest, Error> {
+    value
+        .ok_or(Error::MissingField(field))?
+        .try_into()
+        .map_err(|error| Error::InvalidField {
+            field,
+            source: eyre::Error::from(error),
+        })
+}
@@ -0,0 +1,354 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use aggchain_proof_core::full_execution_proof::AggchainParamsValues;
+use aggchain_proof_types::{
+    imported_bridge_exit::{BridgeExitHash, ImportedBridgeExitWithBlockNumber},
+    inserted_ger::{InsertedGer, InsertedGerWithBlockNumber},
+    unclaim::UnclaimWithBlockNumber,
+    AggchainProofInputs,
+};
+use aggkit_prover_types::{
+    conversion::v1::context::Contextualize as _,
+    v1::{
+        aggchain_proof_service_server::{AggchainProofService, AggchainProofServiceServer},
+        GenerateAggchainProofRequest, GenerateAggchainProofResponse,
+        GenerateOptimisticAggchainProofRequest, GenerateOptimisticAggchainProofResponse,
+    },
+};
+use agglayer_interop::{
+    grpc::v1::{aggchain_proof::Proof, AggchainProof, FixedBytes32, Sp1StarkProof},
+    types::{bincode, Digest, L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof, U256},
+};
+use alloy_primitives::{Address, B256};
+use prost::bytes::Bytes;
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::{transport::Server, Code, Request, Response, Status};
+use unified_bridge::AggchainProofPublicValues;
+
+use crate::{AggchainProofResponse, AggkitProverClient, AggkitProverClientConfig, Error};
+
+/// Prover failing the first requests with `code`, then echoing the request
+/// context along with the proof.
+struct FlakyProver {
+    failures: AtomicUsize,
+    code: Code,
+    requests: Arc<AtomicUsize>,
+}
+
+#[tonic::async_trait]
+impl AggchainProofService for FlakyProver {
+    async fn generate_aggchain_proof(
+        &self,
+        request: Request<GenerateAggchainProofRequest>,
+    ) -> Result<Response<GenerateAggchainProofResponse>, Status> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        if self
+            .failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+        {
+            return Err(Status::new(self.code, "flaky prover"));
+        }
+
+        let inputs = AggchainProofInputs::try_from(request.into_inner())
+            .map_err(|error| Status::invalid_argument(error.to_string()))?;
+        let end_block = inputs.requested_end_block;
+
+        Ok(Response::new(GenerateAggchainProofResponse {
+            aggchain_proof: Some(aggchain_proof(legacy_context(&inputs, end_block))),
+            last_proven_block: inputs.last_proven_block,
+            end_block,
+            local_exit_root_hash: Some(Digest([7; 32]).into()),
+            custom_chain_data: Bytes::from_static(b"custom chain data"),
+            public_values: Some(public_values().into()),
+            output_root: Some(Digest([4; 32]).into()),
+            aggchain_params_values: Some(aggchain_params_values().into()),
+            network_request_id: None,
+        }))
+    }
+
+    async fn generate_optimistic_aggchain_proof(
+        &self,
+        _request: Request<GenerateOptimisticAggchainProofRequest>,
+    ) -> Result<Response<GenerateOptimisticAggchainProofResponse>, Status> {
+        Err(Status::unimplemented("not used by the tests"))
+    }
+}
+
+/// Context reported by the provers predating the typed response fields.
+fn legacy_context(inputs: &AggchainProofInputs, end_block: u64) -> HashMap<String, Bytes> {
+    let mut context = inputs.context();
+    context.insert(
+        "public_values".to_owned(),
+        Bytes::from(
+            bincode::sp1_compatible()
+                .serialize(&public_values())
+                .unwrap(),
+        ),
+    );
+    context.insert("local_exit_root_hash".to_owned(), Bytes::from(vec![7; 32]));
+    context.insert(
+        "end_block".to_owned(),
+        Bytes::from(end_block.to_be_bytes().to_vec()),
+    );
+    context
+}
+
+fn aggchain_proof(context: HashMap<String, Bytes>) -> AggchainProof {
+    AggchainProof {
+        aggchain_params: Some(Digest([8; 32]).into()),
+        signature: None,
+        context,
+        proof: Some(Proof::Sp1Stark(Sp1StarkProof {
+            version: "v-test".to_owned(),
+            proof: Bytes::from_static(b"proof"),
+            vkey: Bytes::from_static(b"vkey"),
+        })),
+    }
+}
+
+fn aggchain_params_values() -> AggchainParamsValues {
+    AggchainParamsValues {
+        l2_pre_root: B256::repeat_byte(0x11),
+        claim_root: B256::repeat_byte(0x04),
+        claim_block_num: alloy_primitives::U256::from(110),
+        rollup_config_hash: B256::repeat_byte(0x12),
+        optimistic_mode: false,
+        trusted_sequencer: Address::repeat_byte(0x13),
+        range_vkey_commitment: B256::repeat_byte(0x14),
+        aggregation_vkey_hash: B256::repeat_byte(0x15),
+    }
+}
+
+fn public_values() -> AggchainProofPublicValues {
+    AggchainProofPublicValues {
+        prev_local_exit_root: Digest([1; 32]),
+        new_local_exit_root: Digest([7; 32]),
+        l1_info_root: Digest([2; 32]),
+        origin_network: 1u32.into(),
+        commit_imported_bridge_exits: Digest([3; 32]),
+        aggchain_params: Digest([8; 32]),
+    }
+}
+
+fn l1_leaf() -> L1InfoTreeLeaf {
+    L1InfoTreeLeaf {
+        l1_info_tree_index: 42,
+        rer: Digest([4; 32]),
+        mer: Digest([5; 32]),
+        inner: L1InfoTreeLeafInner {
+            global_exit_root: Digest([6; 32]),
+            block_hash: Digest([9; 32]),
+            timestamp: 1_700_000_000,
+        },
+    }
+}
+
+fn inputs() -> AggchainProofInputs {
+    let mut global_index = [0u8; 32];
+    global_index[31] = 5;
+
+    AggchainProofInputs {
+        last_proven_block: 100,
+        requested_end_block: 110,
+        l1_info_tree_root_hash: Digest([2; 32]),
+        l1_info_tree_leaf: l1_leaf(),
+        l1_info_tree_merkle_proof: MerkleProof::new(Digest([2; 32]), [Digest::default(); 32]),
+        // Base64 encoded GERs may contain slashes.
+        ger_leaves: HashMap::from([(
+            "Bgb/Bg==".to_owned(),
+            InsertedGerWithBlockNumber {
+                block_number: 105,
+                inserted_ger: InsertedGer {
+                    proof_ger_l1root: MerkleProof::new(Digest([2; 32]), [Digest::default(); 32]),
+                    l1_leaf: l1_leaf(),
+                },
+                log_index: 3,
+            },
+        )]),
+        imported_bridge_exits: vec![ImportedBridgeExitWithBlockNumber {
+            block_number: 107,
+            bridge_exit_hash: BridgeExitHash(Digest([10; 32])),
+            global_index: FixedBytes32 {
+                value: Bytes::from(global_index.to_vec()),
+            }
+            .try_into()
+            .unwrap(),
+            log_index: 1,
+        }],
+        removed_gers: vec![],
+        unclaims: vec![UnclaimWithBlockNumber {
+            global_index: U256::from(5u64),
+            block_number: 108,
+            log_index: 2,
+        }],
+    }
+}
+
+async fn client(prover: FlakyProver, max_retries: usize) -> AggkitProverClient {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(
+        Server::builder()
+            .add_service(AggchainProofServiceServer::new(prover))
+            .serve_with_incoming(TcpListenerStream::new(listener)),
+    );
+
+    AggkitProverClient::connect(AggkitProverClientConfig {
+        endpoint: endpoint.parse().unwrap(),
+        max_retries,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(20),
+        ..Default::default()
+    })
+    .await
+    .unwrap()
+}
+
+fn flaky_prover(failures: usize, code: Code) -> (FlakyProver, Arc<AtomicUsize>) {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let prover = FlakyProver {
+        failures: AtomicUsize::new(failures),
+        code,
+        requests: requests.clone(),
+    };
+
+    (prover, requests)
+}
+
+#[test]
+fn requests_round_trip_through_the_proto_messages() {
+    let inputs = inputs();
+
+    let decoded =
+        AggchainProofInputs::try_from(GenerateAggchainProofRequest::from(inputs.clone())).unwrap();
+
+    assert_eq!(decoded.context(), inputs.context());
+    assert_eq!(
+        decoded.l1_info_tree_merkle_proof,
+        inputs.l1_info_tree_merkle_proof
+    );
+    assert_eq!(decoded.ger_leaves, inputs.ger_leaves);
+    assert_eq!(decoded.imported_bridge_exits, inputs.imported_bridge_exits);
+    assert_eq!(decoded.unclaims, inputs.unclaims);
+}
+
+#[tokio::test]
+async fn transient_failures_are_retried_and_the_response_decoded() {
+    let (prover, requests) = flaky_prover(2, Code::Unavailable);
+    let client = client(prover, 3).await;
+
+    let response = client.generate_aggchain_proof(inputs()).await.unwrap();
+
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+    assert_eq!(response.last_proven_block, 100);
+    assert_eq!(response.end_block, 110);
+    assert_eq!(response.aggchain_params, Digest([8; 32]));
+    assert_eq!(response.local_exit_root_hash, Digest([7; 32]));
+    assert_eq!(response.proof.version, "v-test");
+    assert_eq!(response.public_values.l1_info_root, Digest([2; 32]));
+
+    let context = response.context;
+    assert_eq!(context.requested_end_block, 110);
+    assert_eq!(context.l1_info_tree_index, 42);
+    assert_eq!(context.l1_info_tree_block_hash, Digest([9; 32]));
+    assert_eq!(context.l1_info_tree_timestamp, 1_700_000_000);
+    assert_eq!(context.ger_leaves["Bgb/Bg=="].block_number, 105);
+    assert_eq!(context.ger_leaves["Bgb/Bg=="].l1_leaf_index, 42);
+    assert_eq!(context.imported_bridge_exits.len(), 1);
+    assert_eq!(
+        context.imported_bridge_exits[0].global_index,
+        U256::from(5u64)
+    );
+    assert_eq!(context.end_block, Some(110));
+
+    assert_eq!(response.output_root, Some(Digest([4; 32])));
+    assert_eq!(
+        response.aggchain_params_values,
+        Some(aggchain_params_values())
+    );
+}
+
+#[test]
+fn typed_fields_take_precedence_over_the_context() {
+    let typed_public_values = AggchainProofPublicValues {
+        l1_info_root: Digest([0x22; 32]),
+        ..public_values()
+    };
+
+    let response = AggchainProofResponse::try_from(GenerateOptimisticAggchainProofResponse {
+        aggchain_proof: Some(aggchain_proof(legacy_context(&inputs(), 110))),
+        local_exit_root_hash: Some(Digest([7; 32]).into()),
+        custom_chain_data: Bytes::new(),
+        last_proven_block: 100,
+        end_block: 108,
+        public_values: Some(typed_public_values.into()),
+        output_root: Some(Digest([4; 32]).into()),
+        aggchain_params_values: Some(aggchain_params_values().into()),
+        network_request_id: Some(Digest([0x33; 32]).into()),
+    })
+    .unwrap();
+
+    assert_eq!(response.public_values.l1_info_root, Digest([0x22; 32]));
+    assert_eq!(response.end_block, 108);
+    assert_eq!(response.output_root, Some(Digest([4; 32])));
+    assert_eq!(
+        response.aggchain_params_values,
+        Some(aggchain_params_values())
+    );
+    assert_eq!(response.network_request_id, Some(Digest([0x33; 32])));
+}
+
+#[test]
+fn legacy_responses_are_decoded_from_the_context() {
+    let response = AggchainProofResponse::try_from(GenerateOptimisticAggchainProofResponse {
+        aggchain_proof: Some(aggchain_proof(legacy_context(&inputs(), 110))),
+        local_exit_root_hash: Some(Digest([7; 32]).into()),
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert_eq!(response.public_values.l1_info_root, Digest([2; 32]));
+    assert_eq!(response.last_proven_block, 100);
+    assert_eq!(response.end_block, 110);
+    assert_eq!(response.output_root, None);
+    assert_eq!(response.aggchain_params_values, None);
+    assert_eq!(response.network_request_id, None);
+}
+
+#[tokio::test]
+async fn non_retryable_failures_are_returned() {
+    let (prover, requests) = flaky_prover(1, Code::InvalidArgument);
+    let client = client(prover, 3).await;
+
+    let error = client.generate_aggchain_proof(inputs()).await.unwrap_err();
+
+    assert!(
+        matches!(&error, Error::Request { attempts: 1, status } if status.code() == Code::InvalidArgument),
+        "{error:?}"
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn retries_are_bounded() {
+    let (prover, requests) = flaky_prover(usize::MAX, Code::Unavailable);
+    let client = client(prover, 2).await;
+
+    let error = client.generate_aggchain_proof(inputs()).await.unwrap_err();
+
+    assert!(
+        matches!(&error, Error::Request { attempts: 3, status } if status.code() == Code::Unavailable),
+        "{error:?}"
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+}
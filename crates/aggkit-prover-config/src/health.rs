@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the background health checks of the downstream
+/// dependencies (L1/L2 nodes, proposer and prover backend).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct HealthConfig {
+    /// Interval between two rounds of health checks.
+    #[serde(default = "default_check_interval")]
+    #[serde(with = "prover_utils::with::HumanDuration")]
+    pub check_interval: Duration,
+
+    /// Time after which a health check is considered failed.
+    #[serde(default = "default_check_timeout")]
+    #[serde(with = "prover_utils::with::HumanDuration")]
+    pub check_timeout: Duration,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            check_interval: default_check_interval(),
+            check_timeout: default_check_timeout(),
+        }
+    }
+}
+
+const fn default_check_interval() -> Duration {
+    Duration::from_secs(30)
+}
+
+const fn default_check_timeout() -> Duration {
+    Duration::from_secs(10)
+}
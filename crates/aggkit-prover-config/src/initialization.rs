@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the retries of the aggchain proof service initialization,
+/// done in the background while the RPC server is already up.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct InitializationConfig {
+    /// Delay before retrying after the first failed attempt. The delay is
+    /// doubled after each failure.
+    #[serde(default = "default_initial_backoff")]
+    #[serde(with = "prover_utils::with::HumanDuration")]
+    pub initial_backoff: Duration,
+
+    /// Upper bound of the delay between two attempts.
+    #[serde(default = "default_max_backoff")]
+    #[serde(with = "prover_utils::with::HumanDuration")]
+    pub max_backoff: Duration,
+}
+
+impl Default for InitializationConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff: default_initial_backoff(),
+            max_backoff: default_max_backoff(),
+        }
+    }
+}
+
+const fn default_initial_backoff() -> Duration {
+    Duration::from_secs(1)
+}
+
+const fn default_max_backoff() -> Duration {
+    Duration::from_secs(60)
+}
@@ -8,8 +8,13 @@ use prover_config::{NetworkProverConfig, ProverType};
 use prover_logger::log::Log;
 use serde::{Deserialize, Serialize};
 
-pub use crate::{shutdown::ShutdownConfig, telemetry::TelemetryConfig};
+pub use crate::{
+    health::HealthConfig, initialization::InitializationConfig, shutdown::ShutdownConfig,
+    telemetry::TelemetryConfig,
+};
 
+pub mod health;
+pub mod initialization;
 pub mod shutdown;
 pub(crate) mod telemetry;
 
@@ -38,6 +43,14 @@ pub struct ProverConfig {
     #[serde(default)]
     pub shutdown: ShutdownConfig,
 
+    /// Health checks of the downstream dependencies.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub health: HealthConfig,
+
+    /// Retries of the aggchain proof service initialization.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub initialization: InitializationConfig,
+
     #[serde(default)]
     pub aggchain_proof_service: AggchainProofServiceConfig,
 
@@ -57,6 +70,8 @@ impl Default for ProverConfig {
             log: Log::default(),
             telemetry: TelemetryConfig::default(),
             shutdown: ShutdownConfig::default(),
+            health: HealthConfig::default(),
+            initialization: InitializationConfig::default(),
             aggchain_proof_service: AggchainProofServiceConfig::default(),
             primary_prover: ProverType::NetworkProver(NetworkProverConfig::default()),
             fallback_prover: None,
@@ -93,6 +108,19 @@ pub struct GrpcConfig {
         default = "default_max_encoding_message_size"
     )]
     pub max_encoding_message_size: usize,
+    /// Compression encodings accepted on incoming requests.
+    #[serde(
+        skip_serializing_if = "same_as_default_compression_encodings",
+        default = "default_compression_encodings"
+    )]
+    pub accept_compressed: Vec<CompressionEncoding>,
+    /// Compression encodings the server may use for its responses, when the
+    /// client advertises support for them.
+    #[serde(
+        skip_serializing_if = "same_as_default_compression_encodings",
+        default = "default_compression_encodings"
+    )]
+    pub send_compressed: Vec<CompressionEncoding>,
 }
 
 impl Default for GrpcConfig {
@@ -100,10 +128,20 @@ impl Default for GrpcConfig {
         Self {
             max_decoding_message_size: default_max_decoding_message_size(),
             max_encoding_message_size: default_max_encoding_message_size(),
+            accept_compressed: default_compression_encodings(),
+            send_compressed: default_compression_encodings(),
         }
     }
 }
 
+/// The gRPC message compression encodings.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionEncoding {
+    Gzip,
+    Zstd,
+}
+
 #[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub struct ClientProverConfig {
@@ -127,6 +165,14 @@ fn same_as_default_max_encoding_message_size(value: &usize) -> bool {
     *value == default_max_encoding_message_size()
 }
 
+fn default_compression_encodings() -> Vec<CompressionEncoding> {
+    vec![CompressionEncoding::Gzip, CompressionEncoding::Zstd]
+}
+
+fn same_as_default_compression_encodings(value: &[CompressionEncoding]) -> bool {
+    value == default_compression_encodings()
+}
+
 const fn default_socket_addr() -> SocketAddr {
     SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8081)
 }
@@ -8,12 +8,20 @@ pub struct ShutdownConfig {
     #[serde(default = "default_runtime_shutdown_timeout")]
     #[serde(with = "prover_utils::with::HumanDuration")]
     pub runtime_timeout: Duration,
+
+    /// Time given to the in-flight proof requests to complete once a shutdown
+    /// is requested, before they are cancelled. New requests are refused
+    /// during that time.
+    #[serde(default = "default_drain_timeout")]
+    #[serde(with = "prover_utils::with::HumanDuration")]
+    pub drain_timeout: Duration,
 }
 
 impl Default for ShutdownConfig {
     fn default() -> Self {
         Self {
             runtime_timeout: default_runtime_shutdown_timeout(),
+            drain_timeout: default_drain_timeout(),
         }
     }
 }
@@ -21,3 +29,7 @@ impl Default for ShutdownConfig {
 const fn default_runtime_shutdown_timeout() -> Duration {
     Duration::from_secs(30)
 }
+
+const fn default_drain_timeout() -> Duration {
+    Duration::from_secs(60 * 60)
+}
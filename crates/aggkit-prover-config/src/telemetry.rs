@@ -9,12 +9,18 @@ use super::DEFAULT_IP;
 pub struct TelemetryConfig {
     #[serde(rename = "prometheus-addr", default = "default_metrics_api_addr")]
     pub addr: SocketAddr,
+
+    /// The address of the admin HTTP server, served on the metrics runtime.
+    /// The admin server is disabled when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin_addr: Option<SocketAddr>,
 }
 
 impl Default for TelemetryConfig {
     fn default() -> Self {
         Self {
             addr: default_metrics_api_addr(),
+            admin_addr: None,
         }
     }
 }
@@ -3,6 +3,10 @@ use std::path::Path;
 use aggkit_prover_config::ProverConfig as Config;
 use insta::assert_toml_snapshot;
 use pretty_assertions::assert_eq;
+use prover_logger::{
+    log::{LogLevel, LogOutput, LogRotation},
+    LogFormat,
+};
 
 #[test]
 fn empty_rpcs() {
@@ -43,3 +47,83 @@ fn prover_grpc_max_decoding_message_size() {
 
     assert_eq!(config.grpc.max_decoding_message_size, 100 * 1024 * 1024);
 }
+
+#[test]
+fn prover_grpc_compression() {
+    let input = "./tests/fixtures/validate_config/prover_grpc_compression.toml";
+
+    let config: Config = toml::from_str(&std::fs::read_to_string(input).unwrap()).unwrap();
+
+    assert_toml_snapshot!(config, {
+        ".storage.*" => insta::dynamic_redaction(|value, path| {
+            if path.to_string() != "storage.db-path" {
+                if let insta::internals::Content::String(path) = value {
+                    return insta::internals::Content::String(path.replace(Path::new("./").canonicalize().unwrap().to_str().unwrap(), "/tmp/agglayer"));
+                }
+            }
+
+            value
+        }),
+    });
+
+    assert_eq!(config.grpc.max_encoding_message_size, 64 * 1024 * 1024);
+    assert_eq!(
+        config.grpc.accept_compressed,
+        vec![aggkit_prover_config::CompressionEncoding::Zstd]
+    );
+    assert!(config.grpc.send_compressed.is_empty());
+}
+
+#[test]
+fn prover_health_checks() {
+    let input = "./tests/fixtures/validate_config/prover_health_checks.toml";
+
+    let config: Config = toml::from_str(&std::fs::read_to_string(input).unwrap()).unwrap();
+
+    assert_eq!(
+        config.health.check_interval,
+        std::time::Duration::from_secs(60)
+    );
+    assert_eq!(
+        config.health.check_timeout,
+        std::time::Duration::from_secs(5)
+    );
+
+    assert_eq!(
+        config.initialization.initial_backoff,
+        std::time::Duration::from_millis(500)
+    );
+    assert_eq!(
+        config.initialization.max_backoff,
+        std::time::Duration::from_secs(120)
+    );
+
+    let serialized = toml::to_string(&config).unwrap();
+    assert!(
+        serialized.contains("check-interval = \"1m\""),
+        "{serialized}"
+    );
+    assert!(serialized.contains("max-backoff = \"2m\""), "{serialized}");
+}
+
+#[test]
+fn prover_log_outputs() {
+    let input = "./tests/fixtures/validate_config/prover_log_outputs.toml";
+
+    let config: Config = toml::from_str(&std::fs::read_to_string(input).unwrap()).unwrap();
+
+    let [stdout, file] = &config.log.outputs[..] else {
+        panic!("expected two log outputs: {:?}", config.log.outputs);
+    };
+    assert_eq!(stdout.output, LogOutput::Stdout);
+    assert_eq!(stdout.format, None);
+
+    assert_eq!(
+        file.output,
+        LogOutput::File("/var/log/aggkit-prover/prover.log".into())
+    );
+    assert_eq!(file.format, Some(LogFormat::Json));
+    assert_eq!(file.level, Some(LogLevel::Debug));
+    assert_eq!(file.rotation, LogRotation::Daily);
+    assert_eq!(file.max_files, std::num::NonZeroUsize::new(7));
+}
@@ -84,3 +84,27 @@ impl TryFrom<v1::GenerateAggchainProofRequest> for AggchainProofInputs {
         })
     }
 }
+
+impl From<AggchainProofInputs> for v1::GenerateAggchainProofRequest {
+    fn from(value: AggchainProofInputs) -> Self {
+        Self {
+            last_proven_block: value.last_proven_block,
+            requested_end_block: value.requested_end_block,
+            l1_info_tree_root_hash: Some(value.l1_info_tree_root_hash.into()),
+            l1_info_tree_leaf: Some(value.l1_info_tree_leaf.into()),
+            l1_info_tree_merkle_proof: Some(value.l1_info_tree_merkle_proof.into()),
+            ger_leaves: value
+                .ger_leaves
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect(),
+            imported_bridge_exits: value
+                .imported_bridge_exits
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            removed_gers: value.removed_gers.into_iter().map(Into::into).collect(),
+            unclaims: value.unclaims.into_iter().map(Into::into).collect(),
+        }
+    }
+}
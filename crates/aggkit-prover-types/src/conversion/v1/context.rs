@@ -1,8 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use aggchain_proof_types::{AggchainProofInputs, OptimisticAggchainProofInputs};
-use agglayer_interop::types::U256;
+use agglayer_interop::types::{Digest, U256};
 use prost::bytes::Bytes;
+
+use crate::error::AggchainProofContextError as Error;
 macro_rules! context_fields {
     ($context:ident, [ $( $key:ident : $value:expr ),* $(,)? ]) => {{
         $(
@@ -89,3 +91,211 @@ impl Contextualize for AggchainProofInputs {
         context
     }
 }
+
+/// Typed view of the context attached to an aggchain proof: the entries
+/// produced by [`Contextualize`] from the request, along with the ones added by
+/// the prover once the proof is generated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggchainProofContext {
+    pub last_proven_block: u64,
+    pub requested_end_block: u64,
+    pub l1_info_tree_root_hash: Digest,
+    pub l1_info_tree_index: u32,
+    pub l1_info_tree_rer: Digest,
+    pub l1_info_tree_mer: Digest,
+    pub l1_info_tree_ger: Digest,
+    pub l1_info_tree_block_hash: Digest,
+    pub l1_info_tree_timestamp: u64,
+    /// Inserted GERs, keyed by their base64 encoded digest.
+    pub ger_leaves: BTreeMap<String, GerLeafContext>,
+    pub imported_bridge_exits: Vec<ImportedBridgeExitContext>,
+    /// Bincode serialized public values of the aggchain proof.
+    pub public_values: Option<Bytes>,
+    pub local_exit_root_hash: Option<Digest>,
+    pub end_block: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GerLeafContext {
+    pub block_number: u64,
+    pub log_index: u64,
+    pub l1_leaf_index: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportedBridgeExitContext {
+    pub block_number: u64,
+    pub bridge_exit_hash: Digest,
+    pub global_index: U256,
+}
+
+impl TryFrom<&HashMap<String, Bytes>> for AggchainProofContext {
+    type Error = Error;
+
+    fn try_from(context: &HashMap<String, Bytes>) -> Result<Self, Self::Error> {
+        let mut ger_leaves: BTreeMap<String, PartialGerLeaf> = BTreeMap::new();
+        let mut imported_bridge_exits: BTreeMap<usize, PartialImportedBridgeExit> = BTreeMap::new();
+
+        for (key, value) in context {
+            if let Some(entry) = key.strip_prefix("ger/") {
+                // The base64 encoded GER may itself contain slashes.
+                let (name, field) = entry
+                    .rsplit_once('/')
+                    .ok_or_else(|| Error::InvalidKey { key: key.clone() })?;
+                let leaf = ger_leaves.entry(name.to_owned()).or_default();
+                match field {
+                    "block_number" => leaf.block_number = Some(decode(key, value)?),
+                    "log_index" => leaf.log_index = Some(decode(key, value)?),
+                    "l1_leaf_index" => leaf.l1_leaf_index = Some(decode(key, value)?),
+                    _ => return Err(Error::InvalidKey { key: key.clone() }),
+                }
+            } else if let Some(entry) = key.strip_prefix("ibe/") {
+                let (index, field) = entry
+                    .split_once('/')
+                    .and_then(|(index, field)| Some((index.parse::<usize>().ok()?, field)))
+                    .ok_or_else(|| Error::InvalidKey { key: key.clone() })?;
+                let exit = imported_bridge_exits.entry(index).or_default();
+                match field {
+                    "block_number" => exit.block_number = Some(decode(key, value)?),
+                    "bridge_exit_hash" => exit.bridge_exit_hash = Some(decode(key, value)?),
+                    "global_index" => exit.global_index = Some(decode(key, value)?),
+                    _ => return Err(Error::InvalidKey { key: key.clone() }),
+                }
+            }
+        }
+
+        let ger_leaves = ger_leaves
+            .into_iter()
+            .map(|(name, leaf)| {
+                let key = |field: &str| format!("ger/{name}/{field}");
+                let leaf = GerLeafContext {
+                    block_number: leaf
+                        .block_number
+                        .ok_or_else(|| missing(key("block_number")))?,
+                    log_index: leaf.log_index.ok_or_else(|| missing(key("log_index")))?,
+                    l1_leaf_index: leaf
+                        .l1_leaf_index
+                        .ok_or_else(|| missing(key("l1_leaf_index")))?,
+                };
+
+                Ok((name, leaf))
+            })
+            .collect::<Result<_, Error>>()?;
+
+        let imported_bridge_exits = imported_bridge_exits
+            .into_iter()
+            .enumerate()
+            .map(|(position, (index, exit))| {
+                let key = |field: &str| format!("ibe/{position}/{field}");
+                if index != position {
+                    return Err(missing(key("block_number")));
+                }
+
+                Ok(ImportedBridgeExitContext {
+                    block_number: exit
+                        .block_number
+                        .ok_or_else(|| missing(key("block_number")))?,
+                    bridge_exit_hash: exit
+                        .bridge_exit_hash
+                        .ok_or_else(|| missing(key("bridge_exit_hash")))?,
+                    global_index: exit
+                        .global_index
+                        .ok_or_else(|| missing(key("global_index")))?,
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            last_proven_block: required(context, "last_proven_block")?,
+            requested_end_block: required(context, "requested_end_block")?,
+            l1_info_tree_root_hash: required(context, "l1_info_tree_root_hash")?,
+            l1_info_tree_index: required(context, "l1_info_tree_index")?,
+            l1_info_tree_rer: required(context, "l1_info_tree_rer")?,
+            l1_info_tree_mer: required(context, "l1_info_tree_mer")?,
+            l1_info_tree_ger: required(context, "l1_info_tree_ger")?,
+            l1_info_tree_block_hash: required(context, "l1_info_tree_block_hash")?,
+            l1_info_tree_timestamp: required(context, "l1_info_tree_timestamp")?,
+            ger_leaves,
+            imported_bridge_exits,
+            public_values: context.get("public_values").cloned(),
+            local_exit_root_hash: optional(context, "local_exit_root_hash")?,
+            end_block: optional(context, "end_block")?,
+        })
+    }
+}
+
+#[derive(Default)]
+struct PartialGerLeaf {
+    block_number: Option<u64>,
+    log_index: Option<u64>,
+    l1_leaf_index: Option<u32>,
+}
+
+#[derive(Default)]
+struct PartialImportedBridgeExit {
+    block_number: Option<u64>,
+    bridge_exit_hash: Option<Digest>,
+    global_index: Option<U256>,
+}
+
+/// Fixed size big-endian encoding of the context values.
+trait ContextValue: Sized {
+    const SIZE: usize;
+
+    fn from_be_slice(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! int_context_value {
+    ($($int:ty),*) => {
+        $(
+            impl ContextValue for $int {
+                const SIZE: usize = std::mem::size_of::<$int>();
+
+                fn from_be_slice(bytes: &[u8]) -> Option<Self> {
+                    bytes.try_into().ok().map(<$int>::from_be_bytes)
+                }
+            }
+        )*
+    };
+}
+
+int_context_value!(u32, u64);
+
+impl ContextValue for Digest {
+    const SIZE: usize = 32;
+
+    fn from_be_slice(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok().map(Digest)
+    }
+}
+
+impl ContextValue for U256 {
+    const SIZE: usize = 32;
+
+    fn from_be_slice(bytes: &[u8]) -> Option<Self> {
+        <[u8; 32]>::try_from(bytes).ok().map(U256::from_be_bytes)
+    }
+}
+
+fn decode<T: ContextValue>(key: &str, value: &[u8]) -> Result<T, Error> {
+    T::from_be_slice(value).ok_or_else(|| Error::InvalidLength {
+        key: key.to_owned(),
+        expected: T::SIZE,
+        got: value.len(),
+    })
+}
+
+fn required<T: ContextValue>(context: &HashMap<String, Bytes>, key: &str) -> Result<T, Error> {
+    optional(context, key)?.ok_or_else(|| missing(key.to_owned()))
+}
+
+fn optional<T: ContextValue>(
+    context: &HashMap<String, Bytes>,
+    key: &str,
+) -> Result<Option<T>, Error> {
+    context.get(key).map(|value| decode(key, value)).transpose()
+}
+
+fn missing(key: String) -> Error {
+    Error::MissingEntry { key }
+}
@@ -2,6 +2,7 @@ use aggchain_proof_types::imported_bridge_exit::{
     BridgeExitHash, ImportedBridgeExitWithBlockNumber,
 };
 
+use super::u256_to_fixed_bytes32;
 use crate::{error::AggchainProofRequestError as Error, v1};
 
 impl TryFrom<v1::ImportedBridgeExitWithBlockNumber> for ImportedBridgeExitWithBlockNumber {
@@ -36,3 +37,14 @@ impl TryFrom<v1::ImportedBridgeExitWithBlockNumber> for ImportedBridgeExitWithBl
         })
     }
 }
+
+impl From<ImportedBridgeExitWithBlockNumber> for v1::ImportedBridgeExitWithBlockNumber {
+    fn from(value: ImportedBridgeExitWithBlockNumber) -> Self {
+        Self {
+            block_number: value.block_number,
+            global_index: Some(u256_to_fixed_bytes32(value.global_index.into())),
+            bridge_exit_hash: Some(value.bridge_exit_hash.0.into()),
+            log_index: value.log_index,
+        }
+    }
+}
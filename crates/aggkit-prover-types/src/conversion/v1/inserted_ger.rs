@@ -51,3 +51,22 @@ impl TryFrom<v1::ProvenInsertedGer> for InsertedGer {
         })
     }
 }
+
+impl From<InsertedGerWithBlockNumber> for v1::ProvenInsertedGerWithBlockNumber {
+    fn from(value: InsertedGerWithBlockNumber) -> Self {
+        Self {
+            block_number: value.block_number,
+            proven_inserted_ger: Some(value.inserted_ger.into()),
+            log_index: value.log_index,
+        }
+    }
+}
+
+impl From<InsertedGer> for v1::ProvenInsertedGer {
+    fn from(value: InsertedGer) -> Self {
+        Self {
+            proof_ger_l1root: Some(value.proof_ger_l1root.into()),
+            l1_leaf: Some(value.l1_leaf.into()),
+        }
+    }
+}
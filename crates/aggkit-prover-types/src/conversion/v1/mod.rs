@@ -1,8 +1,27 @@
+use agglayer_interop::{grpc::v1::FixedBytes32, types::U256};
+use prost::bytes::Bytes;
+
 pub mod context;
 
 mod aggchain_proof_inputs;
 mod imported_bridge_exit;
 mod inserted_ger;
 mod optimistic_aggchain_proof_types;
+mod public_values;
 mod removed_ger;
 mod unclaim;
+
+/// Encodes a 256-bit integer as the big-endian `FixedBytes32` expected on the
+/// wire, e.g. for the global indexes.
+fn u256_to_fixed_bytes32(value: U256) -> FixedBytes32 {
+    FixedBytes32 {
+        value: Bytes::from(
+            value
+                .as_le_bytes()
+                .iter()
+                .rev()
+                .copied()
+                .collect::<Vec<_>>(),
+        ),
+    }
+}
@@ -1,4 +1,6 @@
 use aggchain_proof_types::OptimisticAggchainProofInputs;
+use agglayer_interop::grpc::v1::FixedBytes65;
+use prost::bytes::Bytes;
 
 use crate::{error::AggchainProofRequestError as Error, v1};
 
@@ -32,3 +34,14 @@ impl TryFrom<v1::GenerateOptimisticAggchainProofRequest> for OptimisticAggchainP
         })
     }
 }
+
+impl From<OptimisticAggchainProofInputs> for v1::GenerateOptimisticAggchainProofRequest {
+    fn from(value: OptimisticAggchainProofInputs) -> Self {
+        Self {
+            aggchain_proof_request: Some(value.aggchain_proof_inputs.into()),
+            optimistic_mode_signature: Some(FixedBytes65 {
+                value: Bytes::from(value.signature_optimistic_mode.as_bytes().to_vec()),
+            }),
+        }
+    }
+}
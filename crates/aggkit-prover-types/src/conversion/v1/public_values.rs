@@ -0,0 +1,108 @@
+use aggchain_proof_core::full_execution_proof::AggchainParamsValues;
+use agglayer_interop::{
+    grpc::v1::{FixedBytes20, FixedBytes32},
+    types::Digest,
+};
+use alloy_primitives::{Address, B256, U256};
+use prost::bytes::Bytes;
+use unified_bridge::AggchainProofPublicValues;
+
+use crate::{error::AggchainProofResponseError as Error, v1};
+
+impl From<AggchainProofPublicValues> for v1::AggchainProofPublicValues {
+    fn from(value: AggchainProofPublicValues) -> Self {
+        Self {
+            prev_local_exit_root: Some(value.prev_local_exit_root.into()),
+            new_local_exit_root: Some(value.new_local_exit_root.into()),
+            l1_info_root: Some(value.l1_info_root.into()),
+            origin_network: value.origin_network.into(),
+            commit_imported_bridge_exits: Some(value.commit_imported_bridge_exits.into()),
+            aggchain_params: Some(value.aggchain_params.into()),
+        }
+    }
+}
+
+impl TryFrom<v1::AggchainProofPublicValues> for AggchainProofPublicValues {
+    type Error = Error;
+
+    fn try_from(value: v1::AggchainProofPublicValues) -> Result<Self, Self::Error> {
+        Ok(Self {
+            prev_local_exit_root: digest(value.prev_local_exit_root, "prev_local_exit_root")?,
+            new_local_exit_root: digest(value.new_local_exit_root, "new_local_exit_root")?,
+            l1_info_root: digest(value.l1_info_root, "l1_info_root")?,
+            origin_network: value.origin_network.into(),
+            commit_imported_bridge_exits: digest(
+                value.commit_imported_bridge_exits,
+                "commit_imported_bridge_exits",
+            )?,
+            aggchain_params: digest(value.aggchain_params, "aggchain_params")?,
+        })
+    }
+}
+
+impl From<AggchainParamsValues> for v1::AggchainParamsValues {
+    fn from(value: AggchainParamsValues) -> Self {
+        Self {
+            l2_pre_root: Some(b256_to_fixed_bytes32(value.l2_pre_root)),
+            claim_root: Some(b256_to_fixed_bytes32(value.claim_root)),
+            claim_block_num: Some(b256_to_fixed_bytes32(B256::from(
+                value.claim_block_num.to_be_bytes::<32>(),
+            ))),
+            rollup_config_hash: Some(b256_to_fixed_bytes32(value.rollup_config_hash)),
+            optimistic_mode: value.optimistic_mode,
+            trusted_sequencer: Some(FixedBytes20 {
+                value: Bytes::copy_from_slice(value.trusted_sequencer.as_slice()),
+            }),
+            range_vkey_commitment: Some(b256_to_fixed_bytes32(value.range_vkey_commitment)),
+            aggregation_vkey_hash: Some(b256_to_fixed_bytes32(value.aggregation_vkey_hash)),
+        }
+    }
+}
+
+impl TryFrom<v1::AggchainParamsValues> for AggchainParamsValues {
+    type Error = Error;
+
+    fn try_from(value: v1::AggchainParamsValues) -> Result<Self, Self::Error> {
+        let trusted_sequencer = value.trusted_sequencer.ok_or_else(|| Error::MissingField {
+            field_path: "trusted_sequencer".to_string(),
+        })?;
+        let trusted_sequencer =
+            Address::try_from(trusted_sequencer.value.as_ref()).map_err(|error| {
+                Error::InvalidField {
+                    field_path: "trusted_sequencer".to_string(),
+                    source: eyre::Error::from(error),
+                }
+            })?;
+
+        Ok(Self {
+            l2_pre_root: b256(value.l2_pre_root, "l2_pre_root")?,
+            claim_root: b256(value.claim_root, "claim_root")?,
+            claim_block_num: U256::from_be_bytes(b256(value.claim_block_num, "claim_block_num")?.0),
+            rollup_config_hash: b256(value.rollup_config_hash, "rollup_config_hash")?,
+            optimistic_mode: value.optimistic_mode,
+            trusted_sequencer,
+            range_vkey_commitment: b256(value.range_vkey_commitment, "range_vkey_commitment")?,
+            aggregation_vkey_hash: b256(value.aggregation_vkey_hash, "aggregation_vkey_hash")?,
+        })
+    }
+}
+
+fn b256_to_fixed_bytes32(value: B256) -> FixedBytes32 {
+    Digest(value.0).into()
+}
+
+fn b256(value: Option<FixedBytes32>, field_path: &str) -> Result<B256, Error> {
+    digest(value, field_path).map(|digest| B256::from(digest.0))
+}
+
+fn digest(value: Option<FixedBytes32>, field_path: &str) -> Result<Digest, Error> {
+    value
+        .ok_or_else(|| Error::MissingField {
+            field_path: field_path.to_string(),
+        })?
+        .try_into()
+        .map_err(|error| Error::InvalidField {
+            field_path: field_path.to_string(),
+            source: eyre::Error::from(error),
+        })
+}
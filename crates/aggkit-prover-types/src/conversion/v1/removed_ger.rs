@@ -24,3 +24,13 @@ impl TryFrom<v1::RemovedGer> for RemovedGerWithBlockNumber {
         })
     }
 }
+
+impl From<RemovedGerWithBlockNumber> for v1::RemovedGer {
+    fn from(value: RemovedGerWithBlockNumber) -> Self {
+        Self {
+            global_exit_root: Some(value.global_exit_root.into()),
+            block_number: value.block_number,
+            log_index: value.log_index,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use aggchain_proof_types::unclaim::UnclaimWithBlockNumber;
 
+use super::u256_to_fixed_bytes32;
 use crate::{error::AggchainProofRequestError as Error, v1};
 
 impl TryFrom<v1::Unclaim> for UnclaimWithBlockNumber {
@@ -24,3 +25,13 @@ impl TryFrom<v1::Unclaim> for UnclaimWithBlockNumber {
         })
     }
 }
+
+impl From<UnclaimWithBlockNumber> for v1::Unclaim {
+    fn from(value: UnclaimWithBlockNumber) -> Self {
+        Self {
+            global_index: Some(u256_to_fixed_bytes32(value.global_index)),
+            block_number: value.block_number,
+            log_index: value.log_index,
+        }
+    }
+}
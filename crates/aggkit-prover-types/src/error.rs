@@ -133,3 +133,35 @@ impl AggchainProofRequestError {
         }
     }
 }
+
+/// Represents the errors that could happen when decoding the context attached
+/// to an aggchain proof.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum AggchainProofContextError {
+    #[error("Missing context entry `{key}`")]
+    MissingEntry { key: String },
+
+    #[error("Invalid context entry `{key}`: expected {expected} bytes, got {got}")]
+    InvalidLength {
+        key: String,
+        expected: usize,
+        got: usize,
+    },
+
+    #[error("Unexpected context entry `{key}`")]
+    InvalidKey { key: String },
+}
+
+/// Represents the errors that could happen when decoding the typed values of
+/// an aggchain proof response.
+#[derive(thiserror::Error, Debug)]
+pub enum AggchainProofResponseError {
+    #[error("Missing `{field_path}`")]
+    MissingField { field_path: String },
+
+    #[error("Invalid `{field_path}`")]
+    InvalidField {
+        field_path: String,
+        source: eyre::Error,
+    },
+}
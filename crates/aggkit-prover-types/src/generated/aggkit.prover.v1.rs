@@ -41,6 +41,10 @@ pub struct GenerateOptimisticAggchainProofRequest {
     pub optimistic_mode_signature: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes65>,
 }
 /// The aggchain proof response message.
+///
+/// The `context` of the aggchain proof carries some of the values below under
+/// string keys. It is only kept for backwards compatibility, new clients should
+/// read the typed fields instead.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenerateAggchainProofResponse {
     /// Aggchain proof.
@@ -58,8 +62,25 @@ pub struct GenerateAggchainProofResponse {
     /// Custom chain data.
     #[prost(bytes="bytes", tag="5")]
     pub custom_chain_data: ::prost::bytes::Bytes,
+    /// Public values committed by the aggchain proof.
+    #[prost(message, optional, tag="6")]
+    pub public_values: ::core::option::Option<AggchainProofPublicValues>,
+    /// L2 output root at the end block.
+    #[prost(message, optional, tag="7")]
+    pub output_root: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// Preimage of the aggchain params.
+    #[prost(message, optional, tag="8")]
+    pub aggchain_params_values: ::core::option::Option<AggchainParamsValues>,
+    /// Id of the request of the aggchain proof on the sp1 proving network, set
+    /// when proven there.
+    #[prost(message, optional, tag="9")]
+    pub network_request_id: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
 }
 /// The optimistic aggchain proof response message.
+///
+/// The `context` of the aggchain proof carries some of the values below under
+/// string keys. It is only kept for backwards compatibility, new clients should
+/// read the typed fields instead.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenerateOptimisticAggchainProofResponse {
     /// Aggchain proof.
@@ -71,6 +92,25 @@ pub struct GenerateOptimisticAggchainProofResponse {
     /// Custom chain data.
     #[prost(bytes="bytes", tag="3")]
     pub custom_chain_data: ::prost::bytes::Bytes,
+    /// The last proven block before the computed aggchain proof.
+    #[prost(uint64, tag="4")]
+    pub last_proven_block: u64,
+    /// The end block of the aggchain proof.
+    #[prost(uint64, tag="5")]
+    pub end_block: u64,
+    /// Public values committed by the aggchain proof.
+    #[prost(message, optional, tag="6")]
+    pub public_values: ::core::option::Option<AggchainProofPublicValues>,
+    /// L2 output root at the end block.
+    #[prost(message, optional, tag="7")]
+    pub output_root: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// Preimage of the aggchain params.
+    #[prost(message, optional, tag="8")]
+    pub aggchain_params_values: ::core::option::Option<AggchainParamsValues>,
+    /// Id of the request of the aggchain proof on the sp1 proving network, set
+    /// when proven there.
+    #[prost(message, optional, tag="9")]
+    pub network_request_id: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
 }
 /// Imported bridge exit with block number.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -137,9 +177,59 @@ pub struct Unclaim {
     #[prost(uint64, tag="3")]
     pub log_index: u64,
 }
+/// Public values committed by the aggchain proof.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggchainProofPublicValues {
+    /// Local exit root before the proven block range.
+    #[prost(message, optional, tag="1")]
+    pub prev_local_exit_root: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// Local exit root after the proven block range.
+    #[prost(message, optional, tag="2")]
+    pub new_local_exit_root: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// L1 info tree root the proof is anchored to.
+    #[prost(message, optional, tag="3")]
+    pub l1_info_root: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// Network id of the aggchain.
+    #[prost(uint32, tag="4")]
+    pub origin_network: u32,
+    /// Commitment to the imported bridge exits claimed in the block range.
+    #[prost(message, optional, tag="5")]
+    pub commit_imported_bridge_exits: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// Aggchain params, the hash of the `AggchainParamsValues`.
+    #[prost(message, optional, tag="6")]
+    pub aggchain_params: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+}
+/// Values hashed into the aggchain params.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggchainParamsValues {
+    /// L2 output root at the last proven block.
+    #[prost(message, optional, tag="1")]
+    pub l2_pre_root: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// L2 output root at the end block.
+    #[prost(message, optional, tag="2")]
+    pub claim_root: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// The end block, as a big-endian uint256.
+    #[prost(message, optional, tag="3")]
+    pub claim_block_num: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// Hash of the L2 rollup config.
+    #[prost(message, optional, tag="4")]
+    pub rollup_config_hash: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// Whether the proof was generated in optimistic mode.
+    #[prost(bool, tag="5")]
+    pub optimistic_mode: bool,
+    /// Address of the trusted sequencer.
+    #[prost(message, optional, tag="6")]
+    pub trusted_sequencer: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes20>,
+    /// Commitment to the range program verifying keys.
+    #[prost(message, optional, tag="7")]
+    pub range_vkey_commitment: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// Hash of the aggregation program verifying key.
+    #[prost(message, optional, tag="8")]
+    pub aggregation_vkey_hash: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+}
 /// Encoded file descriptor set for the `aggkit.prover.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xc4, 0x3a, 0x0a, 0x30, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2f, 0x70, 0x72, 0x6f, 0x76,
+    0x0a, 0x82, 0x62, 0x0a, 0x30, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2f, 0x70, 0x72, 0x6f, 0x76,
     0x65, 0x72, 0x2f, 0x76, 0x31, 0x2f, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70,
     0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
     0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x10, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72,
@@ -222,7 +312,7 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65,
     0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x36, 0x35, 0x52, 0x17, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69,
     0x73, 0x74, 0x69, 0x63, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72,
-    0x65, 0x22, 0xbf, 0x02, 0x0a, 0x1d, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67,
+    0x65, 0x22, 0x90, 0x05, 0x0a, 0x1d, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67,
     0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f,
     0x6e, 0x73, 0x65, 0x12, 0x4f, 0x0a, 0x0e, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f,
     0x70, 0x72, 0x6f, 0x6f, 0x66, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x28, 0x2e, 0x61, 0x67,
@@ -242,372 +332,688 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x6f, 0x6f, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12, 0x2a, 0x0a, 0x11, 0x63, 0x75, 0x73, 0x74, 0x6f,
     0x6d, 0x5f, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x64, 0x61, 0x74, 0x61, 0x18, 0x05, 0x20, 0x01,
     0x28, 0x0c, 0x52, 0x0f, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x43, 0x68, 0x61, 0x69, 0x6e, 0x44,
-    0x61, 0x74, 0x61, 0x22, 0x80, 0x02, 0x0a, 0x27, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65,
-    0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61,
-    0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
-    0x4f, 0x0a, 0x0e, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70, 0x72, 0x6f, 0x6f,
-    0x66, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x28, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79,
+    0x61, 0x74, 0x61, 0x12, 0x50, 0x0a, 0x0d, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x5f, 0x76, 0x61,
+    0x6c, 0x75, 0x65, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2b, 0x2e, 0x61, 0x67, 0x67,
+    0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x67,
+    0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x50, 0x75, 0x62, 0x6c, 0x69,
+    0x63, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x52, 0x0c, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x56,
+    0x61, 0x6c, 0x75, 0x65, 0x73, 0x12, 0x48, 0x0a, 0x0b, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x5f,
+    0x72, 0x6f, 0x6f, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67,
+    0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79,
+    0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65,
+    0x73, 0x33, 0x32, 0x52, 0x0a, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x52, 0x6f, 0x6f, 0x74, 0x12,
+    0x5c, 0x0a, 0x16, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70, 0x61, 0x72, 0x61,
+    0x6d, 0x73, 0x5f, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x26, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e,
+    0x76, 0x31, 0x2e, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x61, 0x72, 0x61, 0x6d,
+    0x73, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x52, 0x14, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69,
+    0x6e, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x12, 0x55, 0x0a,
+    0x12, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x5f, 0x69, 0x64, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70,
+    0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73,
+    0x33, 0x32, 0x52, 0x10, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x49, 0x64, 0x22, 0x9a, 0x05, 0x0a, 0x27, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
+    0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x4f, 0x0a, 0x0e, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x28, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61,
+    0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65,
+    0x73, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f,
+    0x6f, 0x66, 0x52, 0x0d, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f,
+    0x66, 0x12, 0x58, 0x0a, 0x14, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f,
+    0x72, 0x6f, 0x6f, 0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72,
+    0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65,
+    0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x11, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x45,
+    0x78, 0x69, 0x74, 0x52, 0x6f, 0x6f, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12, 0x2a, 0x0a, 0x11, 0x63,
+    0x75, 0x73, 0x74, 0x6f, 0x6d, 0x5f, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x64, 0x61, 0x74, 0x61,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x43, 0x68,
+    0x61, 0x69, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x12, 0x2a, 0x0a, 0x11, 0x6c, 0x61, 0x73, 0x74, 0x5f,
+    0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x0f, 0x6c, 0x61, 0x73, 0x74, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x42, 0x6c,
+    0x6f, 0x63, 0x6b, 0x12, 0x1b, 0x0a, 0x09, 0x65, 0x6e, 0x64, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x65, 0x6e, 0x64, 0x42, 0x6c, 0x6f, 0x63, 0x6b,
+    0x12, 0x50, 0x0a, 0x0d, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x5f, 0x76, 0x61, 0x6c, 0x75, 0x65,
+    0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2b, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74,
+    0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x56, 0x61,
+    0x6c, 0x75, 0x65, 0x73, 0x52, 0x0c, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x56, 0x61, 0x6c, 0x75,
+    0x65, 0x73, 0x12, 0x48, 0x0a, 0x0b, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x5f, 0x72, 0x6f, 0x6f,
+    0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79,
     0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73,
-    0x2e, 0x76, 0x31, 0x2e, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f,
-    0x66, 0x52, 0x0d, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66,
-    0x12, 0x58, 0x0a, 0x14, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x72,
-    0x6f, 0x6f, 0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27,
-    0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f,
-    0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64,
-    0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x11, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78,
-    0x69, 0x74, 0x52, 0x6f, 0x6f, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12, 0x2a, 0x0a, 0x11, 0x63, 0x75,
-    0x73, 0x74, 0x6f, 0x6d, 0x5f, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x64, 0x61, 0x74, 0x61, 0x18,
-    0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x43, 0x68, 0x61,
-    0x69, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x22, 0x82, 0x02, 0x0a, 0x21, 0x49, 0x6d, 0x70, 0x6f, 0x72,
-    0x74, 0x65, 0x64, 0x42, 0x72, 0x69, 0x64, 0x67, 0x65, 0x45, 0x78, 0x69, 0x74, 0x57, 0x69, 0x74,
-    0x68, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c,
-    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01,
-    0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12,
-    0x4a, 0x0a, 0x0c, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18,
-    0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
-    0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76,
-    0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0b,
-    0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x51, 0x0a, 0x10, 0x62,
-    0x72, 0x69, 0x64, 0x67, 0x65, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18,
-    0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
-    0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76,
-    0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0e,
-    0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x45, 0x78, 0x69, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12, 0x1b,
-    0x0a, 0x09, 0x6c, 0x6f, 0x67, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x04, 0x20, 0x01, 0x28,
-    0x04, 0x52, 0x08, 0x6c, 0x6f, 0x67, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x22, 0xb7, 0x01, 0x0a, 0x20,
-    0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x45,
-    0x52, 0x57, 0x69, 0x74, 0x68, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72,
-    0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72,
-    0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d,
-    0x62, 0x65, 0x72, 0x12, 0x53, 0x0a, 0x13, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x69, 0x6e,
-    0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x67, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
-    0x32, 0x23, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72,
-    0x2e, 0x76, 0x31, 0x2e, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74,
-    0x65, 0x64, 0x47, 0x45, 0x52, 0x52, 0x11, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73,
-    0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x65, 0x72, 0x12, 0x1b, 0x0a, 0x09, 0x6c, 0x6f, 0x67, 0x5f,
-    0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x6c, 0x6f, 0x67,
-    0x49, 0x6e, 0x64, 0x65, 0x78, 0x22, 0xb4, 0x01, 0x0a, 0x11, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e,
-    0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x45, 0x52, 0x12, 0x50, 0x0a, 0x10, 0x70,
-    0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x67, 0x65, 0x72, 0x5f, 0x6c, 0x31, 0x72, 0x6f, 0x6f, 0x74, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
-    0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76,
-    0x31, 0x2e, 0x4d, 0x65, 0x72, 0x6b, 0x6c, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x0e, 0x70,
-    0x72, 0x6f, 0x6f, 0x66, 0x47, 0x65, 0x72, 0x4c, 0x31, 0x72, 0x6f, 0x6f, 0x74, 0x12, 0x4d, 0x0a,
-    0x07, 0x6c, 0x31, 0x5f, 0x6c, 0x65, 0x61, 0x66, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x34,
-    0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f,
-    0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x31, 0x49, 0x6e, 0x66,
-    0x6f, 0x54, 0x72, 0x65, 0x65, 0x4c, 0x65, 0x61, 0x66, 0x57, 0x69, 0x74, 0x68, 0x43, 0x6f, 0x6e,
-    0x74, 0x65, 0x78, 0x74, 0x52, 0x06, 0x6c, 0x31, 0x4c, 0x65, 0x61, 0x66, 0x22, 0x9f, 0x01, 0x0a,
-    0x0a, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x47, 0x45, 0x52, 0x12, 0x51, 0x0a, 0x10, 0x67,
-    0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x72, 0x6f, 0x6f, 0x74, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
-    0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76,
-    0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0e,
-    0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x52, 0x6f, 0x6f, 0x74, 0x12, 0x21,
-    0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x02,
-    0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65,
-    0x72, 0x12, 0x1b, 0x0a, 0x09, 0x6c, 0x6f, 0x67, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x03,
-    0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x6c, 0x6f, 0x67, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x22, 0x95,
-    0x01, 0x0a, 0x07, 0x55, 0x6e, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x12, 0x4a, 0x0a, 0x0c, 0x67, 0x6c,
-    0x6f, 0x62, 0x61, 0x6c, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+    0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32,
+    0x52, 0x0a, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x52, 0x6f, 0x6f, 0x74, 0x12, 0x5c, 0x0a, 0x16,
+    0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x5f,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x61,
+    0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e,
+    0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x56, 0x61,
+    0x6c, 0x75, 0x65, 0x73, 0x52, 0x14, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x61,
+    0x72, 0x61, 0x6d, 0x73, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x12, 0x55, 0x0a, 0x12, 0x6e, 0x65,
+    0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64,
+    0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
+    0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
+    0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52,
+    0x10, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49,
+    0x64, 0x22, 0x82, 0x02, 0x0a, 0x21, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x42, 0x72,
+    0x69, 0x64, 0x67, 0x65, 0x45, 0x78, 0x69, 0x74, 0x57, 0x69, 0x74, 0x68, 0x42, 0x6c, 0x6f, 0x63,
+    0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x4a, 0x0a, 0x0c, 0x67, 0x6c,
+    0x6f, 0x62, 0x61, 0x6c, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
     0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65,
     0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78,
     0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0b, 0x67, 0x6c, 0x6f, 0x62, 0x61,
-    0x6c, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f,
-    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c,
-    0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x1b, 0x0a, 0x09, 0x6c, 0x6f, 0x67,
-    0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x6c, 0x6f,
-    0x67, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x32, 0xa9, 0x02, 0x0a, 0x14, 0x41, 0x67, 0x67, 0x63, 0x68,
-    0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12,
-    0x78, 0x0a, 0x15, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68,
-    0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x2e, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69,
-    0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65,
-    0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f,
-    0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69,
-    0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65,
-    0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f,
-    0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x96, 0x01, 0x0a, 0x1f, 0x47, 0x65,
-    0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63,
-    0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x38, 0x2e,
-    0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31,
-    0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73,
-    0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66,
-    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x39, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74,
-    0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72,
-    0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67,
-    0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-    0x73, 0x65, 0x42, 0x96, 0x01, 0x0a, 0x14, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69,
-    0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x42, 0x1c, 0x41, 0x67, 0x67,
-    0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0xa2, 0x02, 0x03, 0x41, 0x50,
-    0x58, 0xaa, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x50, 0x72, 0x6f, 0x76, 0x65,
-    0x72, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x5c, 0x50, 0x72,
-    0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x1c, 0x41, 0x67, 0x67, 0x6b, 0x69, 0x74,
-    0x5c, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65,
-    0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x12, 0x41, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x3a,
-    0x3a, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x3a, 0x3a, 0x56, 0x31, 0x4a, 0xd1, 0x20, 0x0a, 0x07,
-    0x12, 0x05, 0x00, 0x00, 0x8f, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00,
-    0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x19, 0x0a, 0x09, 0x0a, 0x02, 0x03,
-    0x00, 0x12, 0x03, 0x04, 0x00, 0x32, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x05, 0x00,
-    0x2f, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x02, 0x12, 0x03, 0x06, 0x00, 0x2f, 0x0a, 0x09, 0x0a, 0x02,
-    0x03, 0x03, 0x12, 0x03, 0x07, 0x00, 0x36, 0x0a, 0x34, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x04, 0x0a,
-    0x00, 0x10, 0x01, 0x1a, 0x28, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x66, 0x6f,
-    0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x67, 0x67,
-    0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x1c, 0x0a, 0x48, 0x0a, 0x04, 0x06, 0x00, 0x02,
-    0x00, 0x12, 0x03, 0x0c, 0x02, 0x62, 0x1a, 0x3b, 0x20, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
-    0x65, 0x73, 0x20, 0x61, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72,
-    0x6f, 0x6f, 0x66, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20,
-    0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x62, 0x6c, 0x6f, 0x63,
-    0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x06,
-    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0c, 0x1c, 0x38, 0x0a,
-    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0c, 0x43, 0x60, 0x0a, 0x55, 0x0a,
-    0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x04, 0x0f, 0x02, 0x80, 0x01, 0x1a, 0x47, 0x20, 0x47, 0x65,
-    0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6d,
-    0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70,
-    0x72, 0x6f, 0x6f, 0x66, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e,
-    0x20, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x62, 0x6c, 0x6f,
-    0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0f,
-    0x06, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0f, 0x26, 0x4c,
-    0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0f, 0x57, 0x7e, 0x0a, 0x40,
-    0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x13, 0x00, 0x2e, 0x01, 0x1a, 0x34, 0x20, 0x54, 0x68, 0x65,
+    0x6c, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x51, 0x0a, 0x10, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65,
+    0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65,
+    0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78,
+    0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0e, 0x62, 0x72, 0x69, 0x64, 0x67,
+    0x65, 0x45, 0x78, 0x69, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12, 0x1b, 0x0a, 0x09, 0x6c, 0x6f, 0x67,
+    0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x6c, 0x6f,
+    0x67, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x22, 0xb7, 0x01, 0x0a, 0x20, 0x50, 0x72, 0x6f, 0x76, 0x65,
+    0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x45, 0x52, 0x57, 0x69, 0x74, 0x68,
+    0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x53,
+    0x0a, 0x13, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65,
+    0x64, 0x5f, 0x67, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x61, 0x67,
+    0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x50,
+    0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x45, 0x52,
+    0x52, 0x11, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64,
+    0x47, 0x65, 0x72, 0x12, 0x1b, 0x0a, 0x09, 0x6c, 0x6f, 0x67, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x6c, 0x6f, 0x67, 0x49, 0x6e, 0x64, 0x65, 0x78,
+    0x22, 0xb4, 0x01, 0x0a, 0x11, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72,
+    0x74, 0x65, 0x64, 0x47, 0x45, 0x52, 0x12, 0x50, 0x0a, 0x10, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f,
+    0x67, 0x65, 0x72, 0x5f, 0x6c, 0x31, 0x72, 0x6f, 0x6f, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x26, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65,
+    0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4d, 0x65, 0x72,
+    0x6b, 0x6c, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x0e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x47,
+    0x65, 0x72, 0x4c, 0x31, 0x72, 0x6f, 0x6f, 0x74, 0x12, 0x4d, 0x0a, 0x07, 0x6c, 0x31, 0x5f, 0x6c,
+    0x65, 0x61, 0x66, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x34, 0x2e, 0x61, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70,
+    0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x31, 0x49, 0x6e, 0x66, 0x6f, 0x54, 0x72, 0x65, 0x65,
+    0x4c, 0x65, 0x61, 0x66, 0x57, 0x69, 0x74, 0x68, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52,
+    0x06, 0x6c, 0x31, 0x4c, 0x65, 0x61, 0x66, 0x22, 0x9f, 0x01, 0x0a, 0x0a, 0x52, 0x65, 0x6d, 0x6f,
+    0x76, 0x65, 0x64, 0x47, 0x45, 0x52, 0x12, 0x51, 0x0a, 0x10, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c,
+    0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x72, 0x6f, 0x6f, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65,
+    0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78,
+    0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0e, 0x67, 0x6c, 0x6f, 0x62, 0x61,
+    0x6c, 0x45, 0x78, 0x69, 0x74, 0x52, 0x6f, 0x6f, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x1b, 0x0a, 0x09,
+    0x6c, 0x6f, 0x67, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x08, 0x6c, 0x6f, 0x67, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x22, 0x95, 0x01, 0x0a, 0x07, 0x55, 0x6e,
+    0x63, 0x6c, 0x61, 0x69, 0x6d, 0x12, 0x4a, 0x0a, 0x0c, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x5f,
+    0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67,
+    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74,
+    0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74,
+    0x65, 0x73, 0x33, 0x32, 0x52, 0x0b, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x49, 0x6e, 0x64, 0x65,
+    0x78, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65,
+    0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75,
+    0x6d, 0x62, 0x65, 0x72, 0x12, 0x1b, 0x0a, 0x09, 0x6c, 0x6f, 0x67, 0x5f, 0x69, 0x6e, 0x64, 0x65,
+    0x78, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x6c, 0x6f, 0x67, 0x49, 0x6e, 0x64, 0x65,
+    0x78, 0x22, 0xfb, 0x03, 0x0a, 0x19, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72,
+    0x6f, 0x6f, 0x66, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x12,
+    0x58, 0x0a, 0x14, 0x70, 0x72, 0x65, 0x76, 0x5f, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x5f, 0x65, 0x78,
+    0x69, 0x74, 0x5f, 0x72, 0x6f, 0x6f, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e,
+    0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70,
+    0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42,
+    0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x11, 0x70, 0x72, 0x65, 0x76, 0x4c, 0x6f, 0x63, 0x61,
+    0x6c, 0x45, 0x78, 0x69, 0x74, 0x52, 0x6f, 0x6f, 0x74, 0x12, 0x56, 0x0a, 0x13, 0x6e, 0x65, 0x77,
+    0x5f, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x72, 0x6f, 0x6f, 0x74,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
+    0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
+    0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52,
+    0x10, 0x6e, 0x65, 0x77, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x52, 0x6f, 0x6f,
+    0x74, 0x12, 0x49, 0x0a, 0x0c, 0x6c, 0x31, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x5f, 0x72, 0x6f, 0x6f,
+    0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79,
+    0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73,
+    0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32,
+    0x52, 0x0a, 0x6c, 0x31, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x6f, 0x6f, 0x74, 0x12, 0x25, 0x0a, 0x0e,
+    0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x5f, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x0d, 0x52, 0x0d, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x4e, 0x65, 0x74, 0x77,
+    0x6f, 0x72, 0x6b, 0x12, 0x68, 0x0a, 0x1c, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x5f, 0x69, 0x6d,
+    0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x5f, 0x65, 0x78,
+    0x69, 0x74, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70,
+    0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73,
+    0x33, 0x32, 0x52, 0x19, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74,
+    0x65, 0x64, 0x42, 0x72, 0x69, 0x64, 0x67, 0x65, 0x45, 0x78, 0x69, 0x74, 0x73, 0x12, 0x50, 0x0a,
+    0x0f, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73,
+    0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
+    0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
+    0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52,
+    0x0e, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x22,
+    0x88, 0x05, 0x0a, 0x14, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x61, 0x72, 0x61,
+    0x6d, 0x73, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x12, 0x47, 0x0a, 0x0b, 0x6c, 0x32, 0x5f, 0x70,
+    0x72, 0x65, 0x5f, 0x72, 0x6f, 0x6f, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e,
+    0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70,
+    0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42,
+    0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x09, 0x6c, 0x32, 0x50, 0x72, 0x65, 0x52, 0x6f, 0x6f,
+    0x74, 0x12, 0x46, 0x0a, 0x0a, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x5f, 0x72, 0x6f, 0x6f, 0x74, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76,
+    0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x09,
+    0x63, 0x6c, 0x61, 0x69, 0x6d, 0x52, 0x6f, 0x6f, 0x74, 0x12, 0x4f, 0x0a, 0x0f, 0x63, 0x6c, 0x61,
+    0x69, 0x6d, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e,
+    0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46,
+    0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0d, 0x63, 0x6c, 0x61,
+    0x69, 0x6d, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x12, 0x55, 0x0a, 0x12, 0x72, 0x6f,
+    0x6c, 0x6c, 0x75, 0x70, 0x5f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x5f, 0x68, 0x61, 0x73, 0x68,
+    0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
+    0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
+    0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52,
+    0x10, 0x72, 0x6f, 0x6c, 0x6c, 0x75, 0x70, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x48, 0x61, 0x73,
+    0x68, 0x12, 0x27, 0x0a, 0x0f, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x5f,
+    0x6d, 0x6f, 0x64, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0e, 0x6f, 0x70, 0x74, 0x69,
+    0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x4d, 0x6f, 0x64, 0x65, 0x12, 0x54, 0x0a, 0x11, 0x74, 0x72,
+    0x75, 0x73, 0x74, 0x65, 0x64, 0x5f, 0x73, 0x65, 0x71, 0x75, 0x65, 0x6e, 0x63, 0x65, 0x72, 0x18,
+    0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76,
+    0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x32, 0x30, 0x52, 0x10,
+    0x74, 0x72, 0x75, 0x73, 0x74, 0x65, 0x64, 0x53, 0x65, 0x71, 0x75, 0x65, 0x6e, 0x63, 0x65, 0x72,
+    0x12, 0x5b, 0x0a, 0x15, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x5f, 0x76, 0x6b, 0x65, 0x79, 0x5f, 0x63,
+    0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x6d, 0x65, 0x6e, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72,
+    0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65,
+    0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x13, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x56,
+    0x6b, 0x65, 0x79, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x6d, 0x65, 0x6e, 0x74, 0x12, 0x5b, 0x0a,
+    0x15, 0x61, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x76, 0x6b, 0x65,
+    0x79, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e,
+    0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79,
+    0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x13, 0x61, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x56, 0x6b, 0x65, 0x79, 0x48, 0x61, 0x73, 0x68, 0x32, 0xa9, 0x02, 0x0a, 0x14, 0x41,
+    0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x53, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x12, 0x78, 0x0a, 0x15, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41,
+    0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x2e, 0x2e, 0x61,
+    0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e,
+    0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e,
+    0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f, 0x2e, 0x61,
+    0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e,
+    0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e,
+    0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x96, 0x01,
+    0x0a, 0x1f, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69,
+    0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f,
+    0x66, 0x12, 0x38, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65,
+    0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74,
+    0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50,
+    0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x39, 0x2e, 0x61, 0x67,
+    0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47,
+    0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69,
+    0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x96, 0x01, 0x0a, 0x14, 0x63, 0x6f, 0x6d, 0x2e, 0x61,
+    0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x42,
+    0x1c, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x47, 0x65,
+    0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0xa2,
+    0x02, 0x03, 0x41, 0x50, 0x58, 0xaa, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x50,
+    0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6b, 0x69,
+    0x74, 0x5c, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x1c, 0x41, 0x67,
+    0x67, 0x6b, 0x69, 0x74, 0x5c, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31, 0x5c, 0x47,
+    0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x12, 0x41, 0x67, 0x67,
+    0x6b, 0x69, 0x74, 0x3a, 0x3a, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x3a, 0x3a, 0x56, 0x31, 0x4a,
+    0x9b, 0x39, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0xb7, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c,
+    0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x19, 0x0a,
+    0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x32, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x05, 0x00, 0x2f, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x02, 0x12, 0x03, 0x06, 0x00, 0x2f,
+    0x0a, 0x09, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x07, 0x00, 0x36, 0x0a, 0x34, 0x0a, 0x02, 0x06,
+    0x00, 0x12, 0x04, 0x0a, 0x00, 0x10, 0x01, 0x1a, 0x28, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6e, 0x67,
+    0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x1c, 0x0a, 0x48, 0x0a,
+    0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x62, 0x1a, 0x3b, 0x20, 0x47, 0x65, 0x6e,
+    0x65, 0x72, 0x61, 0x74, 0x65, 0x73, 0x20, 0x61, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69,
+    0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67, 0x69,
+    0x76, 0x65, 0x6e, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x0c, 0x06, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03,
+    0x0c, 0x1c, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0c, 0x43,
+    0x60, 0x0a, 0x55, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x04, 0x0f, 0x02, 0x80, 0x01, 0x1a,
+    0x47, 0x20, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x6f,
+    0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61,
+    0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67,
+    0x69, 0x76, 0x65, 0x6e, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e,
+    0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x0f, 0x06, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12,
+    0x03, 0x0f, 0x26, 0x4c, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0f,
+    0x57, 0x7e, 0x0a, 0x40, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x13, 0x00, 0x2e, 0x01, 0x1a, 0x34,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x6d, 0x65, 0x73,
+    0x73, 0x61, 0x67, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
+    0x69, 0x6e, 0x67, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x13, 0x08, 0x24,
+    0x0a, 0x48, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x15, 0x02, 0x1f, 0x1a, 0x3b, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x15, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x15, 0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x15, 0x1d, 0x1e, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x18, 0x02,
+    0x21, 0x1a, 0x3e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x78, 0x20, 0x65, 0x6e, 0x64, 0x20,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x18, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x18, 0x09, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x18, 0x1f, 0x20, 0x0a, 0x28, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x02, 0x12, 0x03, 0x1b, 0x02, 0x44, 0x1a, 0x1b, 0x20, 0x4c, 0x31, 0x20, 0x49, 0x6e,
+    0x66, 0x6f, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x2e, 0x20, 0x28, 0x68,
+    0x61, 0x73, 0x68, 0x29, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x06, 0x12, 0x03,
+    0x1b, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x29,
+    0x3f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x42, 0x43, 0x0a,
+    0x20, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x1e, 0x02, 0x4c, 0x1a, 0x13, 0x20, 0x4c,
+    0x31, 0x20, 0x49, 0x6e, 0x66, 0x6f, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x66,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x06, 0x12, 0x03, 0x1e, 0x02, 0x35, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1e, 0x36, 0x47, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x1e, 0x4a, 0x4b, 0x0a, 0x2d, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x04, 0x12, 0x03, 0x21, 0x02, 0x46, 0x1a, 0x20, 0x20, 0x4c, 0x31, 0x20, 0x49, 0x6e,
+    0x66, 0x6f, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x20, 0x28,
+    0x5b, 0x33, 0x32, 0x5d, 0x68, 0x61, 0x73, 0x68, 0x29, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x04, 0x06, 0x12, 0x03, 0x21, 0x02, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04,
+    0x01, 0x12, 0x03, 0x21, 0x28, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12,
+    0x03, 0x21, 0x44, 0x45, 0x0a, 0x77, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x24, 0x02,
+    0x3f, 0x1a, 0x6a, 0x20, 0x4d, 0x61, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x47,
+    0x45, 0x52, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x67, 0x65,
+    0x72, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x3a, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x47, 0x45, 0x52, 0x20, 0x28, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x29, 0x20, 0x69,
+    0x73, 0x20, 0x61, 0x20, 0x62, 0x61, 0x73, 0x65, 0x36, 0x34, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64,
+    0x65, 0x64, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x47, 0x45, 0x52, 0x20, 0x64, 0x69, 0x67, 0x65, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x05, 0x06, 0x12, 0x03, 0x24, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x24, 0x30, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x05, 0x03, 0x12, 0x03, 0x24, 0x3d, 0x3e, 0x0a, 0x1b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12,
+    0x03, 0x27, 0x02, 0x47, 0x1a, 0x0e, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78,
+    0x69, 0x74, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x04, 0x12, 0x03, 0x27,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x06, 0x12, 0x03, 0x27, 0x0b, 0x2c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x27, 0x2d, 0x42, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x27, 0x45, 0x46, 0x0a, 0x29, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x2a, 0x02, 0x27, 0x1a, 0x1c, 0x20, 0x41, 0x72, 0x72, 0x61,
+    0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64,
+    0x20, 0x47, 0x45, 0x52, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x04,
+    0x12, 0x03, 0x2a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x06, 0x12, 0x03,
+    0x2a, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x2a, 0x16,
+    0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x03, 0x12, 0x03, 0x2a, 0x25, 0x26, 0x0a,
+    0x25, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x2d, 0x02, 0x20, 0x1a, 0x18, 0x20, 0x41,
+    0x72, 0x72, 0x61, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x6e, 0x63, 0x6c,
+    0x61, 0x69, 0x6d, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x04, 0x12,
+    0x03, 0x2d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x06, 0x12, 0x03, 0x2d,
+    0x0b, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x2d, 0x13, 0x1b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x03, 0x12, 0x03, 0x2d, 0x1e, 0x1f, 0x0a, 0x4b,
+    0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x31, 0x00, 0x35, 0x01, 0x1a, 0x3f, 0x20, 0x54, 0x68, 0x65,
     0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
     0x20, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6e, 0x67, 0x20,
+    0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x01, 0x01, 0x12, 0x03, 0x31, 0x08, 0x2e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12,
+    0x03, 0x32, 0x02, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x32,
+    0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x32, 0x1f, 0x35,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x32, 0x38, 0x39, 0x0a, 0x36,
+    0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x34, 0x02, 0x47, 0x1a, 0x29, 0x20, 0x53, 0x69,
+    0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x22,
+    0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x4d, 0x6f, 0x64, 0x65, 0x22, 0x20,
+    0x63, 0x61, 0x73, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12,
+    0x03, 0x34, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x34,
+    0x29, 0x42, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x34, 0x45, 0x46,
+    0x0a, 0xee, 0x01, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x3c, 0x00, 0x58, 0x01, 0x1a, 0xe1, 0x01,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72,
+    0x6f, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x6d, 0x65, 0x73,
+    0x73, 0x61, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x60, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x78, 0x74, 0x60, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67,
+    0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x63, 0x61, 0x72, 0x72,
+    0x69, 0x65, 0x73, 0x20, 0x73, 0x6f, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x77, 0x20, 0x75, 0x6e, 0x64,
+    0x65, 0x72, 0x0a, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x6b, 0x65, 0x79, 0x73, 0x2e,
+    0x20, 0x49, 0x74, 0x20, 0x69, 0x73, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x6b, 0x65, 0x70, 0x74,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x77, 0x61, 0x72, 0x64, 0x73, 0x20, 0x63,
+    0x6f, 0x6d, 0x70, 0x61, 0x74, 0x69, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x2c, 0x20, 0x6e, 0x65,
+    0x77, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64,
+    0x0a, 0x20, 0x72, 0x65, 0x61, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x64,
+    0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x69, 0x6e, 0x73, 0x74, 0x65, 0x61, 0x64, 0x2e,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x3c, 0x08, 0x25, 0x0a, 0x1e, 0x0a,
+    0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x3e, 0x02, 0x3d, 0x1a, 0x11, 0x20, 0x41, 0x67, 0x67,
+    0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x3e, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3e, 0x2a, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x3e, 0x3b, 0x3c, 0x0a, 0x48, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x41, 0x02, 0x1f, 0x1a, 0x3b, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20,
+    0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x62, 0x65, 0x66,
+    0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x75, 0x74, 0x65, 0x64,
+    0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x41, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x41, 0x09, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x41, 0x1d, 0x1e, 0x0a, 0x33, 0x0a, 0x04, 0x04,
+    0x02, 0x02, 0x02, 0x12, 0x03, 0x44, 0x02, 0x17, 0x1a, 0x26, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65,
+    0x6e, 0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
     0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x13, 0x08, 0x24, 0x0a, 0x48, 0x0a, 0x04,
-    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x15, 0x02, 0x1f, 0x1a, 0x3b, 0x20, 0x54, 0x68, 0x65, 0x20,
-    0x6c, 0x61, 0x73, 0x74, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20, 0x62, 0x6c, 0x6f, 0x63,
-    0x6b, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71,
-    0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20,
-    0x70, 0x72, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x15, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x15,
-    0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x15, 0x1d, 0x1e,
-    0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x18, 0x02, 0x21, 0x1a, 0x3e, 0x20,
-    0x54, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x78, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63,
-    0x6b, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x69,
-    0x73, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x18, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x18, 0x09, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x18, 0x1f, 0x20, 0x0a, 0x28, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12,
-    0x03, 0x1b, 0x02, 0x44, 0x1a, 0x1b, 0x20, 0x4c, 0x31, 0x20, 0x49, 0x6e, 0x66, 0x6f, 0x20, 0x74,
-    0x72, 0x65, 0x65, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x2e, 0x20, 0x28, 0x68, 0x61, 0x73, 0x68, 0x29,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x06, 0x12, 0x03, 0x1b, 0x02, 0x28, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x29, 0x3f, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x42, 0x43, 0x0a, 0x20, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x03, 0x12, 0x03, 0x1e, 0x02, 0x4c, 0x1a, 0x13, 0x20, 0x4c, 0x31, 0x20, 0x49, 0x6e,
-    0x66, 0x6f, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x03, 0x06, 0x12, 0x03, 0x1e, 0x02, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1e, 0x36, 0x47, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x03, 0x03, 0x12, 0x03, 0x1e, 0x4a, 0x4b, 0x0a, 0x2d, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12,
-    0x03, 0x21, 0x02, 0x46, 0x1a, 0x20, 0x20, 0x4c, 0x31, 0x20, 0x49, 0x6e, 0x66, 0x6f, 0x20, 0x74,
-    0x72, 0x65, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x20, 0x28, 0x5b, 0x33, 0x32, 0x5d,
-    0x68, 0x61, 0x73, 0x68, 0x29, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x06, 0x12,
-    0x03, 0x21, 0x02, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x21,
-    0x28, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x21, 0x44, 0x45,
-    0x0a, 0x77, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x24, 0x02, 0x3f, 0x1a, 0x6a, 0x20,
-    0x4d, 0x61, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x47, 0x45, 0x52, 0x20, 0x77,
-    0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x67, 0x65, 0x72, 0x20, 0x6c, 0x65,
-    0x61, 0x66, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x3a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x47, 0x45,
-    0x52, 0x20, 0x28, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x29, 0x20, 0x69, 0x73, 0x20, 0x61, 0x20,
-    0x62, 0x61, 0x73, 0x65, 0x36, 0x34, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x20, 0x73,
-    0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x47, 0x45, 0x52,
-    0x20, 0x64, 0x69, 0x67, 0x65, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x05, 0x06, 0x12, 0x03, 0x24, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x01,
-    0x12, 0x03, 0x24, 0x30, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12, 0x03,
-    0x24, 0x3d, 0x3e, 0x0a, 0x1b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x27, 0x02, 0x47,
-    0x1a, 0x0e, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x73, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x06, 0x12, 0x03, 0x27, 0x0b, 0x2c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x27, 0x2d, 0x42, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x06, 0x03, 0x12, 0x03, 0x27, 0x45, 0x46, 0x0a, 0x29, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x07,
-    0x12, 0x03, 0x2a, 0x02, 0x27, 0x1a, 0x1c, 0x20, 0x41, 0x72, 0x72, 0x61, 0x79, 0x20, 0x6f, 0x66,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x47, 0x45, 0x52,
-    0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x04, 0x12, 0x03, 0x2a, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x06, 0x12, 0x03, 0x2a, 0x0b, 0x15, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x2a, 0x16, 0x22, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x07, 0x03, 0x12, 0x03, 0x2a, 0x25, 0x26, 0x0a, 0x25, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x08, 0x12, 0x03, 0x2d, 0x02, 0x20, 0x1a, 0x18, 0x20, 0x41, 0x72, 0x72, 0x61, 0x79,
-    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x6e, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x73,
-    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x04, 0x12, 0x03, 0x2d, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x06, 0x12, 0x03, 0x2d, 0x0b, 0x12, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x2d, 0x13, 0x1b, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x08, 0x03, 0x12, 0x03, 0x2d, 0x1e, 0x1f, 0x0a, 0x4b, 0x0a, 0x02, 0x04, 0x01,
-    0x12, 0x04, 0x31, 0x00, 0x35, 0x01, 0x1a, 0x3f, 0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71,
-    0x75, 0x65, 0x73, 0x74, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x66, 0x6f, 0x72,
-    0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x70, 0x74, 0x69,
-    0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20,
-    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03,
-    0x31, 0x08, 0x2e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x32, 0x02, 0x3a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x32, 0x02, 0x1e, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x32, 0x1f, 0x35, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x32, 0x38, 0x39, 0x0a, 0x36, 0x0a, 0x04, 0x04, 0x01,
-    0x02, 0x01, 0x12, 0x03, 0x34, 0x02, 0x47, 0x1a, 0x29, 0x20, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74,
-    0x75, 0x72, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x22, 0x4f, 0x70, 0x74, 0x69,
-    0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x4d, 0x6f, 0x64, 0x65, 0x22, 0x20, 0x63, 0x61, 0x73, 0x65,
-    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x34, 0x02, 0x28,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x34, 0x29, 0x42, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x34, 0x45, 0x46, 0x0a, 0x32, 0x0a, 0x02,
-    0x04, 0x02, 0x12, 0x04, 0x38, 0x00, 0x47, 0x01, 0x1a, 0x26, 0x20, 0x54, 0x68, 0x65, 0x20, 0x61,
-    0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x72, 0x65,
-    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x0a,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x38, 0x08, 0x25, 0x0a, 0x1e, 0x0a, 0x04,
-    0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x3a, 0x02, 0x3d, 0x1a, 0x11, 0x20, 0x41, 0x67, 0x67, 0x63,
-    0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x3a, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x3a, 0x2a, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
-    0x03, 0x12, 0x03, 0x3a, 0x3b, 0x3c, 0x0a, 0x48, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x3d, 0x02, 0x1f, 0x1a, 0x3b, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x70,
-    0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x62, 0x65, 0x66, 0x6f,
-    0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x75, 0x74, 0x65, 0x64, 0x20,
-    0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3d, 0x02, 0x08, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3d, 0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3d, 0x1d, 0x1e, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x02,
-    0x02, 0x02, 0x12, 0x03, 0x40, 0x02, 0x17, 0x1a, 0x26, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x6e,
-    0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61,
-    0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12, 0x03, 0x40, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x40, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x40, 0x15, 0x16, 0x0a, 0x24, 0x0a, 0x04, 0x04, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x43, 0x02, 0x42, 0x1a, 0x17, 0x20, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x20, 0x65,
-    0x78, 0x69, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x68, 0x61, 0x73, 0x68, 0x2e, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x06, 0x12, 0x03, 0x43, 0x02, 0x28, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x43, 0x29, 0x3d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x43, 0x40, 0x41, 0x0a, 0x21, 0x0a, 0x04, 0x04, 0x02, 0x02,
-    0x04, 0x12, 0x03, 0x46, 0x02, 0x1e, 0x1a, 0x14, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20,
-    0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x04, 0x05, 0x12, 0x03, 0x46, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x04, 0x01, 0x12, 0x03, 0x46, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04,
-    0x03, 0x12, 0x03, 0x46, 0x1c, 0x1d, 0x0a, 0x3d, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x4a, 0x00,
-    0x53, 0x01, 0x1a, 0x31, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73,
-    0x74, 0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f,
-    0x6f, 0x66, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x6d, 0x65, 0x73, 0x73,
-    0x61, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x4a, 0x08,
-    0x2f, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x4c, 0x02, 0x3d, 0x1a, 0x11,
-    0x20, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x4c, 0x02, 0x29, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4c, 0x2a, 0x38, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4c, 0x3b, 0x3c, 0x0a, 0x24, 0x0a, 0x04, 0x04,
-    0x03, 0x02, 0x01, 0x12, 0x03, 0x4f, 0x02, 0x42, 0x1a, 0x17, 0x20, 0x4c, 0x6f, 0x63, 0x61, 0x6c,
-    0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x68, 0x61, 0x73, 0x68, 0x2e,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x4f, 0x02, 0x28, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4f, 0x29, 0x3d, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4f, 0x40, 0x41, 0x0a, 0x21, 0x0a, 0x04, 0x04,
-    0x03, 0x02, 0x02, 0x12, 0x03, 0x52, 0x02, 0x1e, 0x1a, 0x14, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f,
-    0x6d, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x52, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x52, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x02, 0x03, 0x12, 0x03, 0x52, 0x1c, 0x1d, 0x0a, 0x35, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04,
-    0x56, 0x00, 0x62, 0x01, 0x1a, 0x29, 0x20, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20,
-    0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68,
-    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x2e, 0x0a, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x56, 0x08, 0x29, 0x0a, 0x3c, 0x0a, 0x04, 0x04,
-    0x04, 0x02, 0x00, 0x12, 0x03, 0x58, 0x02, 0x1a, 0x1a, 0x2f, 0x20, 0x54, 0x68, 0x65, 0x20, 0x62,
-    0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x72, 0x69, 0x64,
-    0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x00, 0x05, 0x12, 0x03, 0x58, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x58, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x58, 0x18, 0x19, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x5b, 0x02, 0x3a,
-    0x1a, 0x2b, 0x20, 0x47, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20,
-    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20,
-    0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x5b, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5b, 0x29, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x5b, 0x38, 0x39, 0x0a, 0x20, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x02, 0x12,
-    0x03, 0x5e, 0x02, 0x3e, 0x1a, 0x13, 0x20, 0x42, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78,
-    0x69, 0x74, 0x20, 0x68, 0x61, 0x73, 0x68, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x02, 0x06, 0x12, 0x03, 0x5e, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x5e, 0x29, 0x39, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03,
-    0x5e, 0x3c, 0x3d, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x03, 0x12, 0x03, 0x61, 0x02, 0x17,
-    0x1a, 0x3e, 0x20, 0x4c, 0x6f, 0x67, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x77, 0x69, 0x74,
-    0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x69,
-    0x6e, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x61, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65,
-    0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x68, 0x61, 0x70, 0x70, 0x65, 0x6e, 0x65, 0x64, 0x2e, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x05, 0x12, 0x03, 0x61, 0x02, 0x08, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x01, 0x12, 0x03, 0x61, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x04, 0x02, 0x03, 0x03, 0x12, 0x03, 0x61, 0x15, 0x16, 0x0a, 0x2d, 0x0a, 0x02, 0x04, 0x05,
-    0x12, 0x04, 0x65, 0x00, 0x6e, 0x01, 0x1a, 0x21, 0x20, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65,
-    0x64, 0x20, 0x47, 0x45, 0x52, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
-    0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01,
-    0x12, 0x03, 0x65, 0x08, 0x28, 0x0a, 0x2b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x67,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12, 0x03, 0x44, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x44, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x44, 0x15, 0x16, 0x0a, 0x24, 0x0a, 0x04, 0x04, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x47, 0x02, 0x42, 0x1a, 0x17, 0x20, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x20,
+    0x65, 0x78, 0x69, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x68, 0x61, 0x73, 0x68, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x06, 0x12, 0x03, 0x47, 0x02, 0x28, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x47, 0x29, 0x3d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x47, 0x40, 0x41, 0x0a, 0x21, 0x0a, 0x04, 0x04, 0x02,
+    0x02, 0x04, 0x12, 0x03, 0x4a, 0x02, 0x1e, 0x1a, 0x14, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d,
+    0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x04, 0x05, 0x12, 0x03, 0x4a, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x4a, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x04, 0x03, 0x12, 0x03, 0x4a, 0x1c, 0x1d, 0x0a, 0x3d, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x4d, 0x02, 0x2e, 0x1a, 0x30, 0x20, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x76, 0x61,
+    0x6c, 0x75, 0x65, 0x73, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x62,
+    0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70,
+    0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x06, 0x12,
+    0x03, 0x4d, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x01, 0x12, 0x03, 0x4d,
+    0x1c, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x03, 0x12, 0x03, 0x4d, 0x2c, 0x2d,
+    0x0a, 0x2f, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x06, 0x12, 0x03, 0x50, 0x02, 0x39, 0x1a, 0x22, 0x20,
+    0x4c, 0x32, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x61,
+    0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x06, 0x06, 0x12, 0x03, 0x50, 0x02, 0x28, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x06, 0x01, 0x12, 0x03, 0x50, 0x29, 0x34, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x06, 0x03, 0x12, 0x03, 0x50, 0x37, 0x38, 0x0a, 0x2f, 0x0a, 0x04, 0x04,
+    0x02, 0x02, 0x07, 0x12, 0x03, 0x53, 0x02, 0x32, 0x1a, 0x22, 0x20, 0x50, 0x72, 0x65, 0x69, 0x6d,
+    0x61, 0x67, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x07, 0x06, 0x12, 0x03, 0x53, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x07, 0x01, 0x12, 0x03, 0x53, 0x17, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x07,
+    0x03, 0x12, 0x03, 0x53, 0x30, 0x31, 0x0a, 0x6a, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x08, 0x12, 0x03,
+    0x57, 0x02, 0x40, 0x1a, 0x5d, 0x20, 0x49, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61,
+    0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x6f, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x70, 0x31, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69, 0x6e, 0x67,
+    0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2c, 0x20, 0x73, 0x65, 0x74, 0x0a, 0x20, 0x77,
+    0x68, 0x65, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x08, 0x06, 0x12, 0x03, 0x57, 0x02, 0x28,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x08, 0x01, 0x12, 0x03, 0x57, 0x29, 0x3b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x08, 0x03, 0x12, 0x03, 0x57, 0x3e, 0x3f, 0x0a, 0xf9, 0x01, 0x0a,
+    0x02, 0x04, 0x03, 0x12, 0x04, 0x5f, 0x00, 0x7b, 0x01, 0x1a, 0xec, 0x01, 0x20, 0x54, 0x68, 0x65,
+    0x20, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x60, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x60, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x20, 0x63, 0x61, 0x72, 0x72, 0x69, 0x65, 0x73, 0x20, 0x73, 0x6f, 0x6d, 0x65, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x20, 0x62, 0x65,
+    0x6c, 0x6f, 0x77, 0x20, 0x75, 0x6e, 0x64, 0x65, 0x72, 0x0a, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e,
+    0x67, 0x20, 0x6b, 0x65, 0x79, 0x73, 0x2e, 0x20, 0x49, 0x74, 0x20, 0x69, 0x73, 0x20, 0x6f, 0x6e,
+    0x6c, 0x79, 0x20, 0x6b, 0x65, 0x70, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x62, 0x61, 0x63, 0x6b,
+    0x77, 0x61, 0x72, 0x64, 0x73, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x61, 0x74, 0x69, 0x62, 0x69, 0x6c,
+    0x69, 0x74, 0x79, 0x2c, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x73,
+    0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c, 0x64, 0x0a, 0x20, 0x72, 0x65, 0x61, 0x64, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x64, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x69,
+    0x6e, 0x73, 0x74, 0x65, 0x61, 0x64, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12,
+    0x03, 0x5f, 0x08, 0x2f, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x61, 0x02,
+    0x3d, 0x1a, 0x11, 0x20, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x61,
+    0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x61, 0x2a, 0x38,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x61, 0x3b, 0x3c, 0x0a, 0x24,
+    0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x64, 0x02, 0x42, 0x1a, 0x17, 0x20, 0x4c, 0x6f,
+    0x63, 0x61, 0x6c, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x68, 0x61,
+    0x73, 0x68, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x64,
+    0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x64, 0x29, 0x3d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x64, 0x40, 0x41, 0x0a, 0x21,
+    0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x67, 0x02, 0x1e, 0x1a, 0x14, 0x20, 0x43, 0x75,
+    0x73, 0x74, 0x6f, 0x6d, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x67, 0x02, 0x07, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x67, 0x08, 0x19, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x67, 0x1c, 0x1d, 0x0a, 0x48, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x03, 0x12, 0x03, 0x6a, 0x02, 0x1f, 0x1a, 0x3b, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c,
+    0x61, 0x73, 0x74, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x70,
+    0x75, 0x74, 0x65, 0x64, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72,
+    0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x05, 0x12, 0x03,
+    0x6a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x01, 0x12, 0x03, 0x6a, 0x09,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x03, 0x12, 0x03, 0x6a, 0x1d, 0x1e, 0x0a,
+    0x33, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x04, 0x12, 0x03, 0x6d, 0x02, 0x17, 0x1a, 0x26, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x05, 0x12, 0x03, 0x6d,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x01, 0x12, 0x03, 0x6d, 0x09, 0x12,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x03, 0x12, 0x03, 0x6d, 0x15, 0x16, 0x0a, 0x3d,
+    0x0a, 0x04, 0x04, 0x03, 0x02, 0x05, 0x12, 0x03, 0x70, 0x02, 0x2e, 0x1a, 0x30, 0x20, 0x50, 0x75,
+    0x62, 0x6c, 0x69, 0x63, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x20, 0x63, 0x6f, 0x6d, 0x6d,
+    0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67,
+    0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x05, 0x06, 0x12, 0x03, 0x70, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x05, 0x01, 0x12, 0x03, 0x70, 0x1c, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x05, 0x03, 0x12, 0x03, 0x70, 0x2c, 0x2d, 0x0a, 0x2f, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x06, 0x12,
+    0x03, 0x73, 0x02, 0x39, 0x1a, 0x22, 0x20, 0x4c, 0x32, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74,
+    0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x64,
+    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06,
+    0x06, 0x12, 0x03, 0x73, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06, 0x01, 0x12,
+    0x03, 0x73, 0x29, 0x34, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06, 0x03, 0x12, 0x03, 0x73,
+    0x37, 0x38, 0x0a, 0x2f, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x07, 0x12, 0x03, 0x76, 0x02, 0x32, 0x1a,
+    0x22, 0x20, 0x50, 0x72, 0x65, 0x69, 0x6d, 0x61, 0x67, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6d,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x06, 0x12, 0x03, 0x76, 0x02,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x01, 0x12, 0x03, 0x76, 0x17, 0x2d, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x03, 0x12, 0x03, 0x76, 0x30, 0x31, 0x0a, 0x6a, 0x0a,
+    0x04, 0x04, 0x03, 0x02, 0x08, 0x12, 0x03, 0x7a, 0x02, 0x40, 0x1a, 0x5d, 0x20, 0x49, 0x64, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70,
+    0x72, 0x6f, 0x6f, 0x66, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x70, 0x31, 0x20,
+    0x70, 0x72, 0x6f, 0x76, 0x69, 0x6e, 0x67, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2c,
+    0x20, 0x73, 0x65, 0x74, 0x0a, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65,
+    0x6e, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x08, 0x06, 0x12, 0x03, 0x7a, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x01,
+    0x12, 0x03, 0x7a, 0x29, 0x3b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x03, 0x12, 0x03,
+    0x7a, 0x3e, 0x3f, 0x0a, 0x36, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x05, 0x7e, 0x00, 0x8a, 0x01, 0x01,
+    0x1a, 0x29, 0x20, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x72, 0x69, 0x64,
+    0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x04, 0x01, 0x12, 0x03, 0x7e, 0x08, 0x29, 0x0a, 0x3d, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12,
+    0x04, 0x80, 0x01, 0x02, 0x1a, 0x1a, 0x2f, 0x20, 0x54, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20,
+    0x65, 0x78, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0x80, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0x80, 0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x04, 0x80,
+    0x01, 0x18, 0x19, 0x0a, 0x39, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x04, 0x83, 0x01, 0x02,
+    0x3a, 0x1a, 0x2b, 0x20, 0x47, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64,
+    0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x04, 0x83, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x04, 0x83, 0x01, 0x29, 0x35, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x04, 0x83, 0x01, 0x38, 0x39, 0x0a, 0x21, 0x0a, 0x04, 0x04,
+    0x04, 0x02, 0x02, 0x12, 0x04, 0x86, 0x01, 0x02, 0x3e, 0x1a, 0x13, 0x20, 0x42, 0x72, 0x69, 0x64,
+    0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x68, 0x61, 0x73, 0x68, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x06, 0x12, 0x04, 0x86, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x04, 0x86, 0x01, 0x29, 0x39, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x04, 0x86, 0x01, 0x3c, 0x3d, 0x0a, 0x4c, 0x0a, 0x04, 0x04,
+    0x04, 0x02, 0x03, 0x12, 0x04, 0x89, 0x01, 0x02, 0x17, 0x1a, 0x3e, 0x20, 0x4c, 0x6f, 0x67, 0x20,
+    0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68,
+    0x20, 0x61, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x68,
+    0x61, 0x70, 0x70, 0x65, 0x6e, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x03, 0x05, 0x12, 0x04, 0x89, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03,
+    0x01, 0x12, 0x04, 0x89, 0x01, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x03,
+    0x12, 0x04, 0x89, 0x01, 0x15, 0x16, 0x0a, 0x2f, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x06, 0x8d, 0x01,
+    0x00, 0x96, 0x01, 0x01, 0x1a, 0x21, 0x20, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x20,
+    0x47, 0x45, 0x52, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e,
+    0x75, 0x6d, 0x62, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x04,
+    0x8d, 0x01, 0x08, 0x28, 0x0a, 0x2c, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x04, 0x8f, 0x01,
     0x02, 0x1a, 0x1a, 0x1e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e,
     0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x65, 0x72,
-    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x67, 0x02, 0x08,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x67, 0x09, 0x15, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x67, 0x18, 0x19, 0x0a, 0x1e, 0x0a, 0x04,
-    0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x6a, 0x02, 0x2c, 0x1a, 0x11, 0x20, 0x54, 0x68, 0x65, 0x20,
-    0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x20, 0x47, 0x45, 0x52, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x01, 0x06, 0x12, 0x03, 0x6a, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x6a, 0x14, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x6a, 0x2a, 0x2b, 0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03,
-    0x6d, 0x02, 0x17, 0x1a, 0x2f, 0x20, 0x54, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20,
-    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x6a, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20,
-    0x47, 0x45, 0x52, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x62, 0x6c, 0x6f,
-    0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x6d,
-    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x6d, 0x09, 0x12,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6d, 0x15, 0x16, 0x0a, 0x22,
-    0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x71, 0x00, 0x77, 0x01, 0x1a, 0x16, 0x20, 0x50, 0x72, 0x6f,
-    0x76, 0x65, 0x6e, 0x20, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x20, 0x47, 0x45, 0x52,
-    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x71, 0x08, 0x19, 0x0a, 0x27,
-    0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x73, 0x02, 0x3d, 0x1a, 0x1a, 0x20, 0x50, 0x72,
-    0x6f, 0x6f, 0x66, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x47, 0x45, 0x52, 0x20, 0x74, 0x6f, 0x20,
-    0x4c, 0x31, 0x52, 0x6f, 0x6f, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06,
-    0x12, 0x03, 0x73, 0x02, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x73, 0x28, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x73, 0x3b,
-    0x3c, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x76, 0x02, 0x42, 0x1a, 0x11,
-    0x20, 0x4c, 0x31, 0x49, 0x6e, 0x66, 0x6f, 0x54, 0x72, 0x65, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x66,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x06, 0x12, 0x03, 0x76, 0x02, 0x35, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x76, 0x36, 0x3d, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x76, 0x40, 0x41, 0x0a, 0x3d, 0x0a, 0x02, 0x04,
-    0x07, 0x12, 0x05, 0x7a, 0x00, 0x83, 0x01, 0x01, 0x1a, 0x30, 0x20, 0x52, 0x65, 0x70, 0x72, 0x65,
-    0x73, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65,
-    0x64, 0x20, 0x47, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x20, 0x45, 0x78, 0x69, 0x74, 0x20, 0x52, 0x6f,
-    0x6f, 0x74, 0x20, 0x28, 0x47, 0x45, 0x52, 0x29, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07,
-    0x01, 0x12, 0x03, 0x7a, 0x08, 0x12, 0x0a, 0x24, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03,
-    0x7c, 0x04, 0x40, 0x1a, 0x17, 0x20, 0x54, 0x68, 0x65, 0x20, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c,
-    0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x07, 0x02, 0x00, 0x06, 0x12, 0x03, 0x7c, 0x04, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x7c, 0x2b, 0x3b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00,
-    0x03, 0x12, 0x03, 0x7c, 0x3e, 0x3f, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03,
-    0x7f, 0x04, 0x1c, 0x1a, 0x26, 0x20, 0x54, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20,
-    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65,
-    0x6d, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x47, 0x45, 0x52, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x7f, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x7f, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x7f, 0x1a, 0x1b, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x04, 0x82,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8f, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x09, 0x15,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8f, 0x01, 0x18, 0x19, 0x0a,
+    0x1f, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x04, 0x92, 0x01, 0x02, 0x2c, 0x1a, 0x11, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x20, 0x47, 0x45, 0x52, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x06, 0x12, 0x04, 0x92, 0x01, 0x02, 0x13, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x04, 0x92, 0x01, 0x14, 0x27, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x04, 0x92, 0x01, 0x2a, 0x2b, 0x0a, 0x3d, 0x0a,
+    0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x04, 0x95, 0x01, 0x02, 0x17, 0x1a, 0x2f, 0x20, 0x54, 0x68,
+    0x65, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69,
+    0x6e, 0x6a, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x47, 0x45, 0x52, 0x20, 0x65, 0x76, 0x65, 0x6e,
+    0x74, 0x20, 0x69, 0x6e, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x04, 0x95, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x02, 0x01, 0x12, 0x04, 0x95, 0x01, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0x95, 0x01, 0x15, 0x16, 0x0a, 0x24, 0x0a, 0x02, 0x04, 0x06, 0x12,
+    0x06, 0x99, 0x01, 0x00, 0x9f, 0x01, 0x01, 0x1a, 0x16, 0x20, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e,
+    0x20, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x20, 0x47, 0x45, 0x52, 0x2e, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x04, 0x99, 0x01, 0x08, 0x19, 0x0a, 0x28, 0x0a, 0x04,
+    0x04, 0x06, 0x02, 0x00, 0x12, 0x04, 0x9b, 0x01, 0x02, 0x3d, 0x1a, 0x1a, 0x20, 0x50, 0x72, 0x6f,
+    0x6f, 0x66, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x47, 0x45, 0x52, 0x20, 0x74, 0x6f, 0x20, 0x4c,
+    0x31, 0x52, 0x6f, 0x6f, 0x74, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06, 0x12,
+    0x04, 0x9b, 0x01, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0x9b, 0x01, 0x28, 0x38, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9b,
+    0x01, 0x3b, 0x3c, 0x0a, 0x1f, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x04, 0x9e, 0x01, 0x02,
+    0x42, 0x1a, 0x11, 0x20, 0x4c, 0x31, 0x49, 0x6e, 0x66, 0x6f, 0x54, 0x72, 0x65, 0x65, 0x20, 0x6c,
+    0x65, 0x61, 0x66, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x06, 0x12, 0x04, 0x9e,
+    0x01, 0x02, 0x35, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9e, 0x01,
+    0x36, 0x3d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9e, 0x01, 0x40,
+    0x41, 0x0a, 0x3e, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x06, 0xa2, 0x01, 0x00, 0xab, 0x01, 0x01, 0x1a,
+    0x30, 0x20, 0x52, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x6f, 0x6e, 0x65,
+    0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x47, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x20,
+    0x45, 0x78, 0x69, 0x74, 0x20, 0x52, 0x6f, 0x6f, 0x74, 0x20, 0x28, 0x47, 0x45, 0x52, 0x29, 0x2e,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x04, 0xa2, 0x01, 0x08, 0x12, 0x0a, 0x25,
+    0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x04, 0xa4, 0x01, 0x04, 0x40, 0x1a, 0x17, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x72,
+    0x6f, 0x6f, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x06, 0x12, 0x04,
+    0xa4, 0x01, 0x04, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa4,
+    0x01, 0x2b, 0x3b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa4, 0x01,
+    0x3e, 0x3f, 0x0a, 0x34, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x04, 0xa7, 0x01, 0x04, 0x1c,
+    0x1a, 0x26, 0x20, 0x54, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d,
+    0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76,
+    0x65, 0x64, 0x20, 0x47, 0x45, 0x52, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0xa7, 0x01, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0xa7, 0x01, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0xa7, 0x01, 0x1a, 0x1b, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x04, 0xaa,
     0x01, 0x04, 0x19, 0x1a, 0x33, 0x20, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x77, 0x69, 0x74, 0x68,
     0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x77, 0x68, 0x65,
     0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x47, 0x45, 0x52, 0x20, 0x67, 0x6f, 0x74, 0x20, 0x72,
     0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02,
-    0x05, 0x12, 0x04, 0x82, 0x01, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01,
-    0x12, 0x04, 0x82, 0x01, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12,
-    0x04, 0x82, 0x01, 0x17, 0x18, 0x0a, 0x38, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x06, 0x86, 0x01, 0x00,
-    0x8f, 0x01, 0x01, 0x1a, 0x2a, 0x20, 0x52, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x73,
+    0x05, 0x12, 0x04, 0xaa, 0x01, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01,
+    0x12, 0x04, 0xaa, 0x01, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12,
+    0x04, 0xaa, 0x01, 0x17, 0x18, 0x0a, 0x38, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x06, 0xae, 0x01, 0x00,
+    0xb7, 0x01, 0x01, 0x1a, 0x2a, 0x20, 0x52, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x73,
     0x20, 0x6f, 0x6e, 0x65, 0x20, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
     0x67, 0x6f, 0x74, 0x20, 0x75, 0x6e, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x65, 0x64, 0x2e, 0x0a, 0x0a,
-    0x0b, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x04, 0x86, 0x01, 0x08, 0x0f, 0x0a, 0x30, 0x0a, 0x04,
-    0x04, 0x08, 0x02, 0x00, 0x12, 0x04, 0x88, 0x01, 0x04, 0x3c, 0x1a, 0x22, 0x20, 0x47, 0x6c, 0x6f,
+    0x0b, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x04, 0xae, 0x01, 0x08, 0x0f, 0x0a, 0x30, 0x0a, 0x04,
+    0x04, 0x08, 0x02, 0x00, 0x12, 0x04, 0xb0, 0x01, 0x04, 0x3c, 0x1a, 0x22, 0x20, 0x47, 0x6c, 0x6f,
     0x62, 0x61, 0x6c, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x67,
     0x6f, 0x74, 0x20, 0x75, 0x6e, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x06, 0x12, 0x04, 0x88, 0x01, 0x04, 0x2a, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x04, 0x88, 0x01, 0x2b, 0x37, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x04, 0x88, 0x01, 0x3a, 0x3b, 0x0a, 0x2f, 0x0a, 0x04, 0x04,
-    0x08, 0x02, 0x01, 0x12, 0x04, 0x8b, 0x01, 0x04, 0x1c, 0x1a, 0x21, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x06, 0x12, 0x04, 0xb0, 0x01, 0x04, 0x2a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb0, 0x01, 0x2b, 0x37, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb0, 0x01, 0x3a, 0x3b, 0x0a, 0x2f, 0x0a, 0x04, 0x04,
+    0x08, 0x02, 0x01, 0x12, 0x04, 0xb3, 0x01, 0x04, 0x1c, 0x1a, 0x21, 0x20, 0x54, 0x68, 0x65, 0x20,
     0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20,
     0x61, 0x6e, 0x20, 0x75, 0x6e, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8b, 0x01, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x08, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8b, 0x01, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x01, 0x03, 0x12, 0x04, 0x8b, 0x01, 0x1a, 0x1b, 0x0a, 0x47, 0x0a, 0x04, 0x04, 0x08, 0x02,
-    0x02, 0x12, 0x04, 0x8e, 0x01, 0x04, 0x19, 0x1a, 0x39, 0x20, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x20,
+    0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb3, 0x01, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb3, 0x01, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xb3, 0x01, 0x1a, 0x1b, 0x0a, 0x47, 0x0a, 0x04, 0x04, 0x08, 0x02,
+    0x02, 0x12, 0x04, 0xb6, 0x01, 0x04, 0x19, 0x1a, 0x39, 0x20, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x20,
     0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x62, 0x6c, 0x6f, 0x63,
     0x6b, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x61, 0x20, 0x63, 0x6c, 0x61,
     0x69, 0x6d, 0x20, 0x67, 0x6f, 0x74, 0x20, 0x75, 0x6e, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x65, 0x64,
-    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x04, 0x8e, 0x01, 0x04,
-    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x0b, 0x14,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x04, 0x8e, 0x01, 0x17, 0x18, 0x62,
-    0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x04, 0xb6, 0x01, 0x04,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x04, 0xb6, 0x01, 0x0b, 0x14,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x04, 0xb6, 0x01, 0x17, 0x18, 0x0a,
+    0x3e, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x06, 0xba, 0x01, 0x00, 0xcc, 0x01, 0x01, 0x1a, 0x30, 0x20,
+    0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x20, 0x63, 0x6f,
+    0x6d, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61,
+    0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x04, 0xba, 0x01, 0x08, 0x21, 0x0a, 0x3e, 0x0a, 0x04,
+    0x04, 0x09, 0x02, 0x00, 0x12, 0x04, 0xbc, 0x01, 0x02, 0x42, 0x1a, 0x30, 0x20, 0x4c, 0x6f, 0x63,
+    0x61, 0x6c, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x66,
+    0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x04, 0xbc, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x00, 0x01, 0x12, 0x04, 0xbc, 0x01, 0x29, 0x3d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xbc, 0x01, 0x40, 0x41, 0x0a, 0x3d, 0x0a, 0x04, 0x04, 0x09, 0x02,
+    0x01, 0x12, 0x04, 0xbf, 0x01, 0x02, 0x41, 0x1a, 0x2f, 0x20, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x20,
+    0x65, 0x78, 0x69, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01,
+    0x06, 0x12, 0x04, 0xbf, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0xbf, 0x01, 0x29, 0x3c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0xbf, 0x01, 0x3f, 0x40, 0x0a, 0x3b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x04, 0xc2,
+    0x01, 0x02, 0x3a, 0x1a, 0x2d, 0x20, 0x4c, 0x31, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x20, 0x74, 0x72,
+    0x65, 0x65, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f,
+    0x66, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x63, 0x68, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x74, 0x6f,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x06, 0x12, 0x04, 0xc2, 0x01, 0x02,
+    0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc2, 0x01, 0x29, 0x35,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x04, 0xc2, 0x01, 0x38, 0x39, 0x0a,
+    0x2b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x1c, 0x1a, 0x1d, 0x20,
+    0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x20, 0x69, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x03, 0x05, 0x12, 0x04, 0xc5, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc5, 0x01, 0x09, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x03, 0x03, 0x12, 0x04, 0xc5, 0x01, 0x1a, 0x1b, 0x0a, 0x53, 0x0a, 0x04, 0x04, 0x09, 0x02,
+    0x04, 0x12, 0x04, 0xc8, 0x01, 0x02, 0x4a, 0x1a, 0x45, 0x20, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74,
+    0x6d, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6d, 0x70, 0x6f,
+    0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74,
+    0x73, 0x20, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x06, 0x12, 0x04, 0xc8, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x04, 0x01, 0x12, 0x04, 0xc8, 0x01, 0x29, 0x45, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x04, 0x03, 0x12, 0x04, 0xc8, 0x01, 0x48, 0x49, 0x0a, 0x48, 0x0a, 0x04, 0x04,
+    0x09, 0x02, 0x05, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x3d, 0x1a, 0x3a, 0x20, 0x41, 0x67, 0x67, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x2c, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x41, 0x67,
+    0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x56, 0x61, 0x6c, 0x75,
+    0x65, 0x73, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x06, 0x12, 0x04,
+    0xcb, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x01, 0x12, 0x04, 0xcb,
+    0x01, 0x29, 0x38, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x03, 0x12, 0x04, 0xcb, 0x01,
+    0x3b, 0x3c, 0x0a, 0x37, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x06, 0xcf, 0x01, 0x00, 0xe7, 0x01, 0x01,
+    0x1a, 0x29, 0x20, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x20, 0x68, 0x61, 0x73, 0x68, 0x65, 0x64,
+    0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61,
+    0x69, 0x6e, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x0a, 0x01, 0x12, 0x04, 0xcf, 0x01, 0x08, 0x1c, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00,
+    0x12, 0x04, 0xd1, 0x01, 0x02, 0x39, 0x1a, 0x2a, 0x20, 0x4c, 0x32, 0x20, 0x6f, 0x75, 0x74, 0x70,
+    0x75, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c,
+    0x61, 0x73, 0x74, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x06, 0x12, 0x04, 0xd1, 0x01, 0x02,
+    0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd1, 0x01, 0x29, 0x34,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd1, 0x01, 0x37, 0x38, 0x0a,
+    0x30, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x04, 0xd4, 0x01, 0x02, 0x38, 0x1a, 0x22, 0x20,
+    0x4c, 0x32, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x61,
+    0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x06, 0x12, 0x04, 0xd4, 0x01, 0x02, 0x28,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd4, 0x01, 0x29, 0x33, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd4, 0x01, 0x36, 0x37, 0x0a, 0x37,
+    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x04, 0xd7, 0x01, 0x02, 0x3d, 0x1a, 0x29, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x61, 0x73,
+    0x20, 0x61, 0x20, 0x62, 0x69, 0x67, 0x2d, 0x65, 0x6e, 0x64, 0x69, 0x61, 0x6e, 0x20, 0x75, 0x69,
+    0x6e, 0x74, 0x32, 0x35, 0x36, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x06,
+    0x12, 0x04, 0xd7, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0xd7, 0x01, 0x29, 0x38, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0xd7, 0x01, 0x3b, 0x3c, 0x0a, 0x2d, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x03, 0x12, 0x04, 0xda, 0x01,
+    0x02, 0x40, 0x1a, 0x1f, 0x20, 0x48, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x4c, 0x32, 0x20, 0x72, 0x6f, 0x6c, 0x6c, 0x75, 0x70, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69,
+    0x67, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x06, 0x12, 0x04, 0xda, 0x01,
+    0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x04, 0xda, 0x01, 0x29,
+    0x3b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x04, 0xda, 0x01, 0x3e, 0x3f,
+    0x0a, 0x43, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x04, 0x12, 0x04, 0xdd, 0x01, 0x02, 0x1b, 0x1a, 0x35,
+    0x20, 0x57, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x20, 0x77, 0x61, 0x73, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64,
+    0x20, 0x69, 0x6e, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x6d,
+    0x6f, 0x64, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04,
+    0xdd, 0x01, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0xdd,
+    0x01, 0x07, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0xdd, 0x01,
+    0x19, 0x1a, 0x0a, 0x31, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x05, 0x12, 0x04, 0xe0, 0x01, 0x02, 0x3f,
+    0x1a, 0x23, 0x20, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x72, 0x75, 0x73, 0x74, 0x65, 0x64, 0x20, 0x73, 0x65, 0x71, 0x75, 0x65, 0x6e,
+    0x63, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x06, 0x12, 0x04,
+    0xe0, 0x01, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04, 0xe0,
+    0x01, 0x29, 0x3a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x04, 0xe0, 0x01,
+    0x3d, 0x3e, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x06, 0x12, 0x04, 0xe3, 0x01, 0x02, 0x43,
+    0x1a, 0x31, 0x20, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x67, 0x72,
+    0x61, 0x6d, 0x20, 0x76, 0x65, 0x72, 0x69, 0x66, 0x79, 0x69, 0x6e, 0x67, 0x20, 0x6b, 0x65, 0x79,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x06, 0x12, 0x04, 0xe3, 0x01,
+    0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x01, 0x12, 0x04, 0xe3, 0x01, 0x29,
+    0x3e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x03, 0x12, 0x04, 0xe3, 0x01, 0x41, 0x42,
+    0x0a, 0x3e, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x07, 0x12, 0x04, 0xe6, 0x01, 0x02, 0x43, 0x1a, 0x30,
+    0x20, 0x48, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67,
+    0x72, 0x65, 0x67, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d,
+    0x20, 0x76, 0x65, 0x72, 0x69, 0x66, 0x79, 0x69, 0x6e, 0x67, 0x20, 0x6b, 0x65, 0x79, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x06, 0x12, 0x04, 0xe6, 0x01, 0x02, 0x28, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x01, 0x12, 0x04, 0xe6, 0x01, 0x29, 0x3e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x03, 0x12, 0x04, 0xe6, 0x01, 0x41, 0x42, 0x62, 0x06, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
 include!("aggkit.prover.v1.serde.rs");
 include!("aggkit.prover.v1.tonic.rs");
@@ -1,4 +1,406 @@
 // @generated
+impl serde::Serialize for AggchainParamsValues {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.l2_pre_root.is_some() {
+            len += 1;
+        }
+        if self.claim_root.is_some() {
+            len += 1;
+        }
+        if self.claim_block_num.is_some() {
+            len += 1;
+        }
+        if self.rollup_config_hash.is_some() {
+            len += 1;
+        }
+        if self.optimistic_mode {
+            len += 1;
+        }
+        if self.trusted_sequencer.is_some() {
+            len += 1;
+        }
+        if self.range_vkey_commitment.is_some() {
+            len += 1;
+        }
+        if self.aggregation_vkey_hash.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v1.AggchainParamsValues", len)?;
+        if let Some(v) = self.l2_pre_root.as_ref() {
+            struct_ser.serialize_field("l2PreRoot", v)?;
+        }
+        if let Some(v) = self.claim_root.as_ref() {
+            struct_ser.serialize_field("claimRoot", v)?;
+        }
+        if let Some(v) = self.claim_block_num.as_ref() {
+            struct_ser.serialize_field("claimBlockNum", v)?;
+        }
+        if let Some(v) = self.rollup_config_hash.as_ref() {
+            struct_ser.serialize_field("rollupConfigHash", v)?;
+        }
+        if self.optimistic_mode {
+            struct_ser.serialize_field("optimisticMode", &self.optimistic_mode)?;
+        }
+        if let Some(v) = self.trusted_sequencer.as_ref() {
+            struct_ser.serialize_field("trustedSequencer", v)?;
+        }
+        if let Some(v) = self.range_vkey_commitment.as_ref() {
+            struct_ser.serialize_field("rangeVkeyCommitment", v)?;
+        }
+        if let Some(v) = self.aggregation_vkey_hash.as_ref() {
+            struct_ser.serialize_field("aggregationVkeyHash", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AggchainParamsValues {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "l2_pre_root",
+            "l2PreRoot",
+            "claim_root",
+            "claimRoot",
+            "claim_block_num",
+            "claimBlockNum",
+            "rollup_config_hash",
+            "rollupConfigHash",
+            "optimistic_mode",
+            "optimisticMode",
+            "trusted_sequencer",
+            "trustedSequencer",
+            "range_vkey_commitment",
+            "rangeVkeyCommitment",
+            "aggregation_vkey_hash",
+            "aggregationVkeyHash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            L2PreRoot,
+            ClaimRoot,
+            ClaimBlockNum,
+            RollupConfigHash,
+            OptimisticMode,
+            TrustedSequencer,
+            RangeVkeyCommitment,
+            AggregationVkeyHash,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "l2PreRoot" | "l2_pre_root" => Ok(GeneratedField::L2PreRoot),
+                            "claimRoot" | "claim_root" => Ok(GeneratedField::ClaimRoot),
+                            "claimBlockNum" | "claim_block_num" => Ok(GeneratedField::ClaimBlockNum),
+                            "rollupConfigHash" | "rollup_config_hash" => Ok(GeneratedField::RollupConfigHash),
+                            "optimisticMode" | "optimistic_mode" => Ok(GeneratedField::OptimisticMode),
+                            "trustedSequencer" | "trusted_sequencer" => Ok(GeneratedField::TrustedSequencer),
+                            "rangeVkeyCommitment" | "range_vkey_commitment" => Ok(GeneratedField::RangeVkeyCommitment),
+                            "aggregationVkeyHash" | "aggregation_vkey_hash" => Ok(GeneratedField::AggregationVkeyHash),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AggchainParamsValues;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v1.AggchainParamsValues")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AggchainParamsValues, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut l2_pre_root__ = None;
+                let mut claim_root__ = None;
+                let mut claim_block_num__ = None;
+                let mut rollup_config_hash__ = None;
+                let mut optimistic_mode__ = None;
+                let mut trusted_sequencer__ = None;
+                let mut range_vkey_commitment__ = None;
+                let mut aggregation_vkey_hash__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::L2PreRoot => {
+                            if l2_pre_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("l2PreRoot"));
+                            }
+                            l2_pre_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::ClaimRoot => {
+                            if claim_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimRoot"));
+                            }
+                            claim_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::ClaimBlockNum => {
+                            if claim_block_num__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimBlockNum"));
+                            }
+                            claim_block_num__ = map_.next_value()?;
+                        }
+                        GeneratedField::RollupConfigHash => {
+                            if rollup_config_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupConfigHash"));
+                            }
+                            rollup_config_hash__ = map_.next_value()?;
+                        }
+                        GeneratedField::OptimisticMode => {
+                            if optimistic_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("optimisticMode"));
+                            }
+                            optimistic_mode__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::TrustedSequencer => {
+                            if trusted_sequencer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trustedSequencer"));
+                            }
+                            trusted_sequencer__ = map_.next_value()?;
+                        }
+                        GeneratedField::RangeVkeyCommitment => {
+                            if range_vkey_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rangeVkeyCommitment"));
+                            }
+                            range_vkey_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::AggregationVkeyHash => {
+                            if aggregation_vkey_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("aggregationVkeyHash"));
+                            }
+                            aggregation_vkey_hash__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(AggchainParamsValues {
+                    l2_pre_root: l2_pre_root__,
+                    claim_root: claim_root__,
+                    claim_block_num: claim_block_num__,
+                    rollup_config_hash: rollup_config_hash__,
+                    optimistic_mode: optimistic_mode__.unwrap_or_default(),
+                    trusted_sequencer: trusted_sequencer__,
+                    range_vkey_commitment: range_vkey_commitment__,
+                    aggregation_vkey_hash: aggregation_vkey_hash__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v1.AggchainParamsValues", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AggchainProofPublicValues {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.prev_local_exit_root.is_some() {
+            len += 1;
+        }
+        if self.new_local_exit_root.is_some() {
+            len += 1;
+        }
+        if self.l1_info_root.is_some() {
+            len += 1;
+        }
+        if self.origin_network != 0 {
+            len += 1;
+        }
+        if self.commit_imported_bridge_exits.is_some() {
+            len += 1;
+        }
+        if self.aggchain_params.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v1.AggchainProofPublicValues", len)?;
+        if let Some(v) = self.prev_local_exit_root.as_ref() {
+            struct_ser.serialize_field("prevLocalExitRoot", v)?;
+        }
+        if let Some(v) = self.new_local_exit_root.as_ref() {
+            struct_ser.serialize_field("newLocalExitRoot", v)?;
+        }
+        if let Some(v) = self.l1_info_root.as_ref() {
+            struct_ser.serialize_field("l1InfoRoot", v)?;
+        }
+        if self.origin_network != 0 {
+            struct_ser.serialize_field("originNetwork", &self.origin_network)?;
+        }
+        if let Some(v) = self.commit_imported_bridge_exits.as_ref() {
+            struct_ser.serialize_field("commitImportedBridgeExits", v)?;
+        }
+        if let Some(v) = self.aggchain_params.as_ref() {
+            struct_ser.serialize_field("aggchainParams", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AggchainProofPublicValues {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "prev_local_exit_root",
+            "prevLocalExitRoot",
+            "new_local_exit_root",
+            "newLocalExitRoot",
+            "l1_info_root",
+            "l1InfoRoot",
+            "origin_network",
+            "originNetwork",
+            "commit_imported_bridge_exits",
+            "commitImportedBridgeExits",
+            "aggchain_params",
+            "aggchainParams",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PrevLocalExitRoot,
+            NewLocalExitRoot,
+            L1InfoRoot,
+            OriginNetwork,
+            CommitImportedBridgeExits,
+            AggchainParams,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "prevLocalExitRoot" | "prev_local_exit_root" => Ok(GeneratedField::PrevLocalExitRoot),
+                            "newLocalExitRoot" | "new_local_exit_root" => Ok(GeneratedField::NewLocalExitRoot),
+                            "l1InfoRoot" | "l1_info_root" => Ok(GeneratedField::L1InfoRoot),
+                            "originNetwork" | "origin_network" => Ok(GeneratedField::OriginNetwork),
+                            "commitImportedBridgeExits" | "commit_imported_bridge_exits" => Ok(GeneratedField::CommitImportedBridgeExits),
+                            "aggchainParams" | "aggchain_params" => Ok(GeneratedField::AggchainParams),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AggchainProofPublicValues;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v1.AggchainProofPublicValues")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AggchainProofPublicValues, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut prev_local_exit_root__ = None;
+                let mut new_local_exit_root__ = None;
+                let mut l1_info_root__ = None;
+                let mut origin_network__ = None;
+                let mut commit_imported_bridge_exits__ = None;
+                let mut aggchain_params__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PrevLocalExitRoot => {
+                            if prev_local_exit_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevLocalExitRoot"));
+                            }
+                            prev_local_exit_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewLocalExitRoot => {
+                            if new_local_exit_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newLocalExitRoot"));
+                            }
+                            new_local_exit_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::L1InfoRoot => {
+                            if l1_info_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("l1InfoRoot"));
+                            }
+                            l1_info_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::OriginNetwork => {
+                            if origin_network__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("originNetwork"));
+                            }
+                            origin_network__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CommitImportedBridgeExits => {
+                            if commit_imported_bridge_exits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitImportedBridgeExits"));
+                            }
+                            commit_imported_bridge_exits__ = map_.next_value()?;
+                        }
+                        GeneratedField::AggchainParams => {
+                            if aggchain_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("aggchainParams"));
+                            }
+                            aggchain_params__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(AggchainProofPublicValues {
+                    prev_local_exit_root: prev_local_exit_root__,
+                    new_local_exit_root: new_local_exit_root__,
+                    l1_info_root: l1_info_root__,
+                    origin_network: origin_network__.unwrap_or_default(),
+                    commit_imported_bridge_exits: commit_imported_bridge_exits__,
+                    aggchain_params: aggchain_params__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v1.AggchainProofPublicValues", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GenerateAggchainProofRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -267,6 +669,18 @@ impl serde::Serialize for GenerateAggchainProofResponse {
         if !self.custom_chain_data.is_empty() {
             len += 1;
         }
+        if self.public_values.is_some() {
+            len += 1;
+        }
+        if self.output_root.is_some() {
+            len += 1;
+        }
+        if self.aggchain_params_values.is_some() {
+            len += 1;
+        }
+        if self.network_request_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("aggkit.prover.v1.GenerateAggchainProofResponse", len)?;
         if let Some(v) = self.aggchain_proof.as_ref() {
             struct_ser.serialize_field("aggchainProof", v)?;
@@ -289,6 +703,18 @@ impl serde::Serialize for GenerateAggchainProofResponse {
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("customChainData", pbjson::private::base64::encode(&self.custom_chain_data).as_str())?;
         }
+        if let Some(v) = self.public_values.as_ref() {
+            struct_ser.serialize_field("publicValues", v)?;
+        }
+        if let Some(v) = self.output_root.as_ref() {
+            struct_ser.serialize_field("outputRoot", v)?;
+        }
+        if let Some(v) = self.aggchain_params_values.as_ref() {
+            struct_ser.serialize_field("aggchainParamsValues", v)?;
+        }
+        if let Some(v) = self.network_request_id.as_ref() {
+            struct_ser.serialize_field("networkRequestId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -309,6 +735,14 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
             "localExitRootHash",
             "custom_chain_data",
             "customChainData",
+            "public_values",
+            "publicValues",
+            "output_root",
+            "outputRoot",
+            "aggchain_params_values",
+            "aggchainParamsValues",
+            "network_request_id",
+            "networkRequestId",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -318,6 +752,10 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
             EndBlock,
             LocalExitRootHash,
             CustomChainData,
+            PublicValues,
+            OutputRoot,
+            AggchainParamsValues,
+            NetworkRequestId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -344,6 +782,10 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
                             "endBlock" | "end_block" => Ok(GeneratedField::EndBlock),
                             "localExitRootHash" | "local_exit_root_hash" => Ok(GeneratedField::LocalExitRootHash),
                             "customChainData" | "custom_chain_data" => Ok(GeneratedField::CustomChainData),
+                            "publicValues" | "public_values" => Ok(GeneratedField::PublicValues),
+                            "outputRoot" | "output_root" => Ok(GeneratedField::OutputRoot),
+                            "aggchainParamsValues" | "aggchain_params_values" => Ok(GeneratedField::AggchainParamsValues),
+                            "networkRequestId" | "network_request_id" => Ok(GeneratedField::NetworkRequestId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -368,6 +810,10 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
                 let mut end_block__ = None;
                 let mut local_exit_root_hash__ = None;
                 let mut custom_chain_data__ = None;
+                let mut public_values__ = None;
+                let mut output_root__ = None;
+                let mut aggchain_params_values__ = None;
+                let mut network_request_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::AggchainProof => {
@@ -406,6 +852,30 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::PublicValues => {
+                            if public_values__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicValues"));
+                            }
+                            public_values__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputRoot => {
+                            if output_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputRoot"));
+                            }
+                            output_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::AggchainParamsValues => {
+                            if aggchain_params_values__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("aggchainParamsValues"));
+                            }
+                            aggchain_params_values__ = map_.next_value()?;
+                        }
+                        GeneratedField::NetworkRequestId => {
+                            if network_request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkRequestId"));
+                            }
+                            network_request_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(GenerateAggchainProofResponse {
@@ -414,6 +884,10 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
                     end_block: end_block__.unwrap_or_default(),
                     local_exit_root_hash: local_exit_root_hash__,
                     custom_chain_data: custom_chain_data__.unwrap_or_default(),
+                    public_values: public_values__,
+                    output_root: output_root__,
+                    aggchain_params_values: aggchain_params_values__,
+                    network_request_id: network_request_id__,
                 })
             }
         }
@@ -547,6 +1021,24 @@ impl serde::Serialize for GenerateOptimisticAggchainProofResponse {
         if !self.custom_chain_data.is_empty() {
             len += 1;
         }
+        if self.last_proven_block != 0 {
+            len += 1;
+        }
+        if self.end_block != 0 {
+            len += 1;
+        }
+        if self.public_values.is_some() {
+            len += 1;
+        }
+        if self.output_root.is_some() {
+            len += 1;
+        }
+        if self.aggchain_params_values.is_some() {
+            len += 1;
+        }
+        if self.network_request_id.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("aggkit.prover.v1.GenerateOptimisticAggchainProofResponse", len)?;
         if let Some(v) = self.aggchain_proof.as_ref() {
             struct_ser.serialize_field("aggchainProof", v)?;
@@ -559,6 +1051,28 @@ impl serde::Serialize for GenerateOptimisticAggchainProofResponse {
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("customChainData", pbjson::private::base64::encode(&self.custom_chain_data).as_str())?;
         }
+        if self.last_proven_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("lastProvenBlock", ToString::to_string(&self.last_proven_block).as_str())?;
+        }
+        if self.end_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("endBlock", ToString::to_string(&self.end_block).as_str())?;
+        }
+        if let Some(v) = self.public_values.as_ref() {
+            struct_ser.serialize_field("publicValues", v)?;
+        }
+        if let Some(v) = self.output_root.as_ref() {
+            struct_ser.serialize_field("outputRoot", v)?;
+        }
+        if let Some(v) = self.aggchain_params_values.as_ref() {
+            struct_ser.serialize_field("aggchainParamsValues", v)?;
+        }
+        if let Some(v) = self.network_request_id.as_ref() {
+            struct_ser.serialize_field("networkRequestId", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -575,6 +1089,18 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofResponse {
             "localExitRootHash",
             "custom_chain_data",
             "customChainData",
+            "last_proven_block",
+            "lastProvenBlock",
+            "end_block",
+            "endBlock",
+            "public_values",
+            "publicValues",
+            "output_root",
+            "outputRoot",
+            "aggchain_params_values",
+            "aggchainParamsValues",
+            "network_request_id",
+            "networkRequestId",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -582,6 +1108,12 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofResponse {
             AggchainProof,
             LocalExitRootHash,
             CustomChainData,
+            LastProvenBlock,
+            EndBlock,
+            PublicValues,
+            OutputRoot,
+            AggchainParamsValues,
+            NetworkRequestId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -606,6 +1138,12 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofResponse {
                             "aggchainProof" | "aggchain_proof" => Ok(GeneratedField::AggchainProof),
                             "localExitRootHash" | "local_exit_root_hash" => Ok(GeneratedField::LocalExitRootHash),
                             "customChainData" | "custom_chain_data" => Ok(GeneratedField::CustomChainData),
+                            "lastProvenBlock" | "last_proven_block" => Ok(GeneratedField::LastProvenBlock),
+                            "endBlock" | "end_block" => Ok(GeneratedField::EndBlock),
+                            "publicValues" | "public_values" => Ok(GeneratedField::PublicValues),
+                            "outputRoot" | "output_root" => Ok(GeneratedField::OutputRoot),
+                            "aggchainParamsValues" | "aggchain_params_values" => Ok(GeneratedField::AggchainParamsValues),
+                            "networkRequestId" | "network_request_id" => Ok(GeneratedField::NetworkRequestId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -628,6 +1166,12 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofResponse {
                 let mut aggchain_proof__ = None;
                 let mut local_exit_root_hash__ = None;
                 let mut custom_chain_data__ = None;
+                let mut last_proven_block__ = None;
+                let mut end_block__ = None;
+                let mut public_values__ = None;
+                let mut output_root__ = None;
+                let mut aggchain_params_values__ = None;
+                let mut network_request_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::AggchainProof => {
@@ -650,12 +1194,58 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofResponse {
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::LastProvenBlock => {
+                            if last_proven_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastProvenBlock"));
+                            }
+                            last_proven_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndBlock => {
+                            if end_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endBlock"));
+                            }
+                            end_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PublicValues => {
+                            if public_values__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicValues"));
+                            }
+                            public_values__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputRoot => {
+                            if output_root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputRoot"));
+                            }
+                            output_root__ = map_.next_value()?;
+                        }
+                        GeneratedField::AggchainParamsValues => {
+                            if aggchain_params_values__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("aggchainParamsValues"));
+                            }
+                            aggchain_params_values__ = map_.next_value()?;
+                        }
+                        GeneratedField::NetworkRequestId => {
+                            if network_request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkRequestId"));
+                            }
+                            network_request_id__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(GenerateOptimisticAggchainProofResponse {
                     aggchain_proof: aggchain_proof__,
                     local_exit_root_hash: local_exit_root_hash__,
                     custom_chain_data: custom_chain_data__.unwrap_or_default(),
+                    last_proven_block: last_proven_block__.unwrap_or_default(),
+                    end_block: end_block__.unwrap_or_default(),
+                    public_values: public_values__,
+                    output_root: output_root__,
+                    aggchain_params_values: aggchain_params_values__,
+                    network_request_id: network_request_id__,
                 })
             }
         }
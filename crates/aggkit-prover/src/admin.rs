@@ -0,0 +1,304 @@
+//! Operator endpoints served by the admin HTTP server.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use aggchain_proof_service::AGGCHAIN_VKEY_SELECTOR;
+use aggkit_prover_config::ProverConfig;
+use axum::{extract::State, http::StatusCode, routing::get, Json, Router};
+use prover_logger::filter::{LogFilterError, LogFilterHandle, LogFilterStatus};
+use prover_utils::with::HumanDuration;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::serde_as;
+use sp1_sdk::HashableKey as _;
+use url::Url;
+
+use crate::{
+    jobs::{Job, JobKind, JobStage},
+    rpc::GrpcService,
+};
+
+/// Replacement of the redacted configuration values.
+const REDACTED: &str = "<redacted>";
+
+/// Fragments of the configuration keys holding secrets.
+const SECRET_KEYS: &[&str] = &[
+    "private-key",
+    "secret",
+    "password",
+    "token",
+    "api-key",
+    "apikey",
+    "mnemonic",
+];
+
+/// Routes controlling the log filters at runtime:
+///
+/// - `GET /log-level` returns the filters in effect.
+/// - `PUT /log-level` overrides the filters with a `RUST_LOG` directive, for
+///   instance `{"directive": "info,aggkit_prover=debug", "ttl": "15m"}`. The
+///   configured filters are restored once the optional TTL expires.
+/// - `DELETE /log-level` restores the configured filters.
+pub fn log_level_routes(log_filter: LogFilterHandle) -> Router {
+    Router::new()
+        .route(
+            "/log-level",
+            get(get_log_level)
+                .put(set_log_level)
+                .delete(reset_log_level),
+        )
+        .with_state(log_filter)
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct LogLevelResponse {
+    pub directives: Vec<String>,
+    pub overridden: bool,
+    #[serde_as(as = "Option<HumanDuration>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revert_in: Option<Duration>,
+}
+
+impl From<LogFilterStatus> for LogLevelResponse {
+    fn from(status: LogFilterStatus) -> Self {
+        Self {
+            directives: status.directives,
+            overridden: status.overridden,
+            revert_in: status.revert_in.map(whole_seconds),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct SetLogLevelRequest {
+    pub directive: String,
+    #[serde_as(as = "Option<HumanDuration>")]
+    #[serde(default)]
+    pub ttl: Option<Duration>,
+}
+
+async fn get_log_level(State(log_filter): State<LogFilterHandle>) -> Json<LogLevelResponse> {
+    Json(log_filter.status().into())
+}
+
+async fn set_log_level(
+    State(log_filter): State<LogFilterHandle>,
+    Json(request): Json<SetLogLevelRequest>,
+) -> Result<Json<LogLevelResponse>, (StatusCode, String)> {
+    log_filter
+        .set(&request.directive, request.ttl)
+        .map_err(log_filter_error)?;
+
+    Ok(Json(log_filter.status().into()))
+}
+
+async fn reset_log_level(
+    State(log_filter): State<LogFilterHandle>,
+) -> Result<Json<LogLevelResponse>, (StatusCode, String)> {
+    log_filter.reset().map_err(log_filter_error)?;
+
+    Ok(Json(log_filter.status().into()))
+}
+
+fn log_filter_error(error: LogFilterError) -> (StatusCode, String) {
+    let status = match error {
+        LogFilterError::InvalidDirective(_) => StatusCode::BAD_REQUEST,
+        LogFilterError::Reload(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    let message = match std::error::Error::source(&error) {
+        Some(source) => format!("{error}: {source}"),
+        None => error.to_string(),
+    };
+
+    (status, message)
+}
+
+/// Route reporting the state of the prover:
+///
+/// - `GET /status` returns the version, the uptime, whether the aggchain proof
+///   service is initialized and the proof requests in flight with their stage.
+pub fn status_routes(version: String, grpc_service: GrpcService) -> Router {
+    let state = Arc::new(StatusState {
+        version,
+        started_at: Instant::now(),
+        grpc_service,
+    });
+
+    Router::new()
+        .route("/status", get(get_status))
+        .with_state(state)
+}
+
+struct StatusState {
+    version: String,
+    started_at: Instant,
+    grpc_service: GrpcService,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct StatusResponse {
+    pub version: String,
+    #[serde_as(as = "HumanDuration")]
+    pub uptime: Duration,
+    pub initialized: bool,
+    pub jobs: Vec<JobStatus>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct JobStatus {
+    pub id: u64,
+    pub kind: JobKind,
+    pub last_proven_block: u64,
+    pub requested_end_block: u64,
+    pub stage: JobStage,
+    #[serde_as(as = "HumanDuration")]
+    pub elapsed: Duration,
+}
+
+impl From<Job> for JobStatus {
+    fn from(job: Job) -> Self {
+        Self {
+            id: job.id,
+            kind: job.kind,
+            last_proven_block: job.last_proven_block,
+            requested_end_block: job.requested_end_block,
+            stage: job.stage,
+            elapsed: whole_seconds(job.elapsed),
+        }
+    }
+}
+
+async fn get_status(State(state): State<Arc<StatusState>>) -> Json<StatusResponse> {
+    Json(StatusResponse {
+        version: state.version.clone(),
+        uptime: whole_seconds(state.started_at.elapsed()),
+        initialized: state.grpc_service.is_initialized(),
+        jobs: state
+            .grpc_service
+            .jobs()
+            .jobs()
+            .into_iter()
+            .map(JobStatus::from)
+            .collect(),
+    })
+}
+
+/// Route exposing the effective configuration:
+///
+/// - `GET /config` returns the loaded configuration as JSON. The values of the
+///   keys naming a secret are replaced with `<redacted>`, and so are the
+///   credentials, path, query and fragment of the URLs, which commonly embed
+///   API keys.
+pub fn config_routes(config: &ProverConfig) -> eyre::Result<Router> {
+    let mut config = serde_json::to_value(config)?;
+    redact_secrets(&mut config);
+
+    Ok(Router::new()
+        .route("/config", get(get_config))
+        .with_state(Arc::new(config)))
+}
+
+async fn get_config(State(config): State<Arc<Value>>) -> Json<Value> {
+    Json(Value::clone(&config))
+}
+
+/// Replaces the secrets of a configuration with [`REDACTED`].
+pub fn redact_secrets(value: &mut Value) {
+    match value {
+        Value::Object(entries) => {
+            for (key, value) in entries.iter_mut() {
+                let key = key.to_lowercase();
+                if SECRET_KEYS.iter().any(|secret| key.contains(secret)) {
+                    if !value.is_null() {
+                        *value = Value::String(REDACTED.to_owned());
+                    }
+                } else {
+                    redact_secrets(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_secrets),
+        Value::String(value) => {
+            if let Some(redacted) = redact_url(value) {
+                *value = redacted;
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+/// Keeps only the scheme, host and port of a URL carrying anything else.
+fn redact_url(value: &str) -> Option<String> {
+    let url = Url::parse(value).ok()?;
+    let host = url.host_str()?;
+
+    let sensitive = !url.username().is_empty()
+        || url.password().is_some()
+        || !matches!(url.path(), "" | "/")
+        || url.query().is_some()
+        || url.fragment().is_some();
+    if !sensitive {
+        return None;
+    }
+
+    let port = url
+        .port()
+        .map(|port| format!(":{port}"))
+        .unwrap_or_default();
+
+    Some(format!("{}://{host}{port}/{REDACTED}", url.scheme()))
+}
+
+/// Route exposing the verification keys in effect:
+///
+/// - `GET /vkeys` returns the hash of the aggchain proof vkey, known once the
+///   aggchain proof service is initialized, its selector, and the op-succinct
+///   aggregation vkey hash and range vkey commitment. The latter two follow the
+///   `op-succinct` overrides of the aggchain proof service configuration once
+///   the service is initialized, and are the values embedded at build time
+///   until then.
+pub fn vkeys_routes(grpc_service: GrpcService) -> Router {
+    Router::new()
+        .route("/vkeys", get(get_vkeys))
+        .with_state(grpc_service)
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct VkeysResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggchain_vkey_hash: Option<String>,
+    pub aggchain_vkey_selector: String,
+    pub aggregation_vkey_hash: String,
+    pub range_vkey_commitment: String,
+}
+
+async fn get_vkeys(State(grpc_service): State<GrpcService>) -> Json<VkeysResponse> {
+    Json(VkeysResponse {
+        aggchain_vkey_hash: grpc_service
+            .aggchain_vkey()
+            .map(|vkey| format!("0x{}", hex::encode(vkey.hash_bytes()))),
+        aggchain_vkey_selector: format!("0x{}", hex::encode(AGGCHAIN_VKEY_SELECTOR.to_be_bytes())),
+        aggregation_vkey_hash: format!(
+            "0x{}",
+            hex::encode(proposer_elfs::aggregation::vkey().bytes32_raw())
+        ),
+        range_vkey_commitment: format!("0x{}", hex::encode(proposer_elfs::range::commitment())),
+    })
+}
+
+/// Drops the sub-second part of a duration, for readability.
+fn whole_seconds(duration: Duration) -> Duration {
+    Duration::from_secs(duration.as_secs())
+}
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use aggchain_proof_service::config::AggchainProofServiceConfig;
+use aggkit_prover_config::InitializationConfig;
+use opentelemetry::{global, KeyValue};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::rpc::GrpcService;
+
+/// Initializes the aggchain proof service behind `service`, retrying with an
+/// exponential backoff until it succeeds or the cancellation token is
+/// triggered.
+///
+/// Progress is reported through the `aggkit_prover_initialization_attempts`
+/// counter and the `aggkit_prover_initialized` gauge.
+pub async fn initialize_with_backoff(
+    service: GrpcService,
+    config: &AggchainProofServiceConfig,
+    backoff: &InitializationConfig,
+    cancellation_token: CancellationToken,
+) {
+    let meter = global::meter("aggkit_prover");
+    let attempts = meter
+        .u64_counter("aggkit_prover_initialization_attempts")
+        .with_description("Number of attempts at initializing the aggchain proof service")
+        .build();
+    let initialized = meter
+        .u64_gauge("aggkit_prover_initialized")
+        .with_description("Whether the aggchain proof service is initialized")
+        .build();
+    initialized.record(0, &[]);
+
+    let mut delay = backoff.initial_backoff;
+
+    for attempt in 1.. {
+        info!(attempt, "Initializing the aggchain proof service");
+
+        match service.try_initialize(config).await {
+            Ok(()) => {
+                attempts.add(1, &[KeyValue::new("outcome", "success")]);
+                initialized.record(1, &[]);
+                info!(attempt, "Aggchain proof service initialized");
+
+                return;
+            }
+            Err(error) => {
+                attempts.add(1, &[KeyValue::new("outcome", "failure")]);
+                warn!(
+                    attempt,
+                    retry_in = ?delay,
+                    "Unable to initialize the aggchain proof service: {error:?}"
+                );
+            }
+        }
+
+        tokio::select! {
+            _ = cancellation_token.cancelled() => {
+                info!("Aggchain proof service initialization cancelled");
+
+                return;
+            }
+            _ = tokio::time::sleep(delay) => {}
+        }
+
+        delay = next_delay(delay, backoff.max_backoff);
+    }
+}
+
+pub(crate) fn next_delay(delay: Duration, max_backoff: Duration) -> Duration {
+    delay.saturating_mul(2).min(max_backoff)
+}
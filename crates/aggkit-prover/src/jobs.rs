@@ -0,0 +1,155 @@
+//! Bookkeeping of the proof requests in flight, reported by the `/status`
+//! admin endpoint.
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use aggchain_proof_types::{ProofProgress, ProofStage};
+use serde::{Deserialize, Serialize};
+
+/// Kind of proof requested.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum JobKind {
+    AggchainProof,
+    OptimisticAggchainProof,
+}
+
+/// Stage of a proof request.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum JobStage {
+    /// Waiting for the aggchain proof service to accept the request.
+    Queued,
+    /// Waiting for the L2 end block to reach the configured finality.
+    WaitingForL2Finality,
+    /// Waiting for the proposer to deliver the aggregation proof of the range.
+    WaitingForAggregationProof,
+    /// Fetching the bridge events and the chain data from the L1 and L2 nodes.
+    FetchingChainData,
+    /// Generating the aggchain proof.
+    Proving,
+}
+
+impl From<ProofStage> for JobStage {
+    fn from(stage: ProofStage) -> Self {
+        match stage {
+            ProofStage::WaitingForL2Finality => JobStage::WaitingForL2Finality,
+            ProofStage::WaitingForAggregationProof => JobStage::WaitingForAggregationProof,
+            ProofStage::FetchingChainData => JobStage::FetchingChainData,
+            ProofStage::Proving => JobStage::Proving,
+        }
+    }
+}
+
+/// Snapshot of a proof request in flight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub id: u64,
+    pub kind: JobKind,
+    pub last_proven_block: u64,
+    pub requested_end_block: u64,
+    pub stage: JobStage,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+struct TrackedJob {
+    kind: JobKind,
+    last_proven_block: u64,
+    requested_end_block: u64,
+    stage: JobStage,
+    started_at: Instant,
+}
+
+/// Registry of the proof requests in flight.
+#[derive(Clone, Debug, Default)]
+pub struct JobTracker {
+    next_id: Arc<AtomicU64>,
+    jobs: Arc<Mutex<BTreeMap<u64, TrackedJob>>>,
+}
+
+impl JobTracker {
+    /// Registers a new request, tracked until the returned guard is dropped.
+    pub fn start(
+        &self,
+        kind: JobKind,
+        last_proven_block: u64,
+        requested_end_block: u64,
+    ) -> JobGuard {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.lock().insert(
+            id,
+            TrackedJob {
+                kind,
+                last_proven_block,
+                requested_end_block,
+                stage: JobStage::Queued,
+                started_at: Instant::now(),
+            },
+        );
+
+        JobGuard {
+            id,
+            tracker: self.clone(),
+        }
+    }
+
+    /// Returns the requests in flight, oldest first.
+    pub fn jobs(&self) -> Vec<Job> {
+        self.lock()
+            .iter()
+            .map(|(id, job)| Job {
+                id: *id,
+                kind: job.kind,
+                last_proven_block: job.last_proven_block,
+                requested_end_block: job.requested_end_block,
+                stage: job.stage,
+                elapsed: job.started_at.elapsed(),
+            })
+            .collect()
+    }
+
+    fn set_stage(&self, id: u64, stage: JobStage) {
+        if let Some(job) = self.lock().get_mut(&id) {
+            job.stage = stage;
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<u64, TrackedJob>> {
+        // The map is left consistent by every critical section, so a poisoned
+        // lock is safe to recover.
+        self.jobs
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Keeps a request registered in the [`JobTracker`] while alive.
+#[derive(Debug)]
+pub struct JobGuard {
+    id: u64,
+    tracker: JobTracker,
+}
+
+impl JobGuard {
+    /// Observer moving the request through the stages reported by the
+    /// aggchain proof service.
+    pub fn progress(&self) -> ProofProgress {
+        let tracker = self.tracker.clone();
+        let id = self.id;
+        ProofProgress::new(move |stage| tracker.set_stage(id, stage.into()))
+    }
+}
+
+impl Drop for JobGuard {
+    fn drop(&mut self) {
+        self.tracker.lock().remove(&self.id);
+    }
+}
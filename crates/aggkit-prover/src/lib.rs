@@ -1,12 +1,21 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{future::Future, path::PathBuf, sync::Arc};
 
+use aggchain_proof_service::service::AggchainProofService;
+use aggkit_prover_config::ProverConfig;
 use aggkit_prover_types::v1::aggchain_proof_service_server::AggchainProofServiceServer;
+use futures::{future::BoxFuture, FutureExt as _};
+use initialization::initialize_with_backoff;
 use prover_engine::ProverEngine;
-use rpc::GrpcService;
+use prover_logger::filter::LogFilterHandle;
+use rpc::{aggchain_proof_server, GrpcService};
 use tokio_util::sync::CancellationToken;
-use tracing::info;
+use tonic::server::NamedService;
+use tracing::{error, info};
 
+pub mod admin;
 pub mod cli;
+pub mod initialization;
+pub mod jobs;
 pub mod rpc;
 
 #[cfg(test)]
@@ -15,42 +24,188 @@ mod tests;
 pub fn runtime(cfg: PathBuf, version: &str) -> eyre::Result<()> {
     let config = Arc::new(aggkit_prover_config::ProverConfig::try_load(&cfg)?);
 
-    // Initialize the logger
-    prover_logger::tracing(&config.log);
+    // Initialize the logger, the pending traces are flushed on return.
+    let tracing_guard = prover_logger::tracing(&config.log);
 
-    let global_cancellation_token = CancellationToken::new();
+    ProverRuntime::new(config, version)
+        .set_log_filter(tracing_guard.log_filter())
+        .start()
+}
+
+/// Future resolving to an aggchain proof service built by the caller.
+type AggchainProofServiceFuture =
+    BoxFuture<'static, Result<AggchainProofService, aggchain_proof_service::Error>>;
+
+/// The aggkit prover servers and background tasks, run on the calling thread
+/// until a shutdown signal is received or the cancellation token is
+/// triggered.
+///
+/// The tracing subscriber is global to the process, so it is installed by the
+/// caller, which hands over its log filter for the admin routes.
+pub struct ProverRuntime {
+    config: Arc<ProverConfig>,
+    version: String,
+    grpc_service: GrpcService,
+    log_filter: Option<LogFilterHandle>,
+    aggchain_proof_service: Option<AggchainProofServiceFuture>,
+    grpc_listener: Option<std::net::TcpListener>,
+    cancellation_token: CancellationToken,
+}
+
+impl ProverRuntime {
+    pub fn new(config: Arc<ProverConfig>, version: &str) -> Self {
+        Self {
+            config,
+            version: version.to_owned(),
+            grpc_service: GrpcService::uninitialized(),
+            log_filter: None,
+            aggchain_proof_service: None,
+            grpc_listener: None,
+            cancellation_token: CancellationToken::new(),
+        }
+    }
+
+    /// gRPC front of the aggchain proof service, e.g. to wait for its
+    /// initialization.
+    pub fn grpc_service(&self) -> GrpcService {
+        self.grpc_service.clone()
+    }
+
+    /// Serves the log level admin routes over the filters of the installed
+    /// tracing subscriber.
+    pub fn set_log_filter(mut self, log_filter: LogFilterHandle) -> Self {
+        self.log_filter = Some(log_filter);
+        self
+    }
+
+    /// Serves the aggchain proof service resolved by `service` instead of
+    /// building it from the configuration, e.g. over fake chain clients.
+    pub fn set_aggchain_proof_service(
+        mut self,
+        service: impl Future<Output = Result<AggchainProofService, aggchain_proof_service::Error>>
+            + Send
+            + 'static,
+    ) -> Self {
+        self.aggchain_proof_service = Some(service.boxed());
+        self
+    }
+
+    /// Serves the gRPC API on `grpc_listener` instead of binding the
+    /// configured endpoint.
+    pub fn set_grpc_listener(mut self, grpc_listener: std::net::TcpListener) -> Self {
+        self.grpc_listener = Some(grpc_listener);
+        self
+    }
 
-    info!("Starting AggKit Prover version info: {}", version);
+    /// Sets the token shutting the prover down once cancelled.
+    pub fn set_cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.cancellation_token = cancellation_token;
+        self
+    }
 
-    let prover_runtime = tokio::runtime::Builder::new_multi_thread()
-        .thread_name("aggkit-prover-runtime")
-        .enable_all()
-        .build()?;
+    pub fn start(self) -> eyre::Result<()> {
+        let Self {
+            config,
+            version,
+            grpc_service,
+            log_filter,
+            aggchain_proof_service: built_service,
+            grpc_listener,
+            cancellation_token: global_cancellation_token,
+        } = self;
 
-    let metrics_runtime = tokio::runtime::Builder::new_multi_thread()
-        .thread_name("metrics-runtime")
-        .worker_threads(2)
-        .enable_all()
-        .build()?;
+        info!("Starting AggKit Prover version info: {}", version);
 
-    let aggchain_proof_service = prover_runtime.block_on(async {
-        let grpc_service = GrpcService::new(&config.aggchain_proof_service).await?;
-        Ok::<AggchainProofServiceServer<GrpcService>, aggchain_proof_service::Error>(
-            AggchainProofServiceServer::new(grpc_service),
+        let prover_runtime = tokio::runtime::Builder::new_multi_thread()
+            .thread_name("aggkit-prover-runtime")
+            .enable_all()
+            .build()?;
+
+        let metrics_runtime = tokio::runtime::Builder::new_multi_thread()
+            .thread_name("metrics-runtime")
+            .worker_threads(2)
+            .enable_all()
+            .build()?;
+
+        // The aggchain proof service is initialized in the background, so that the
+        // health and metrics servers are up while its dependencies are not reachable.
+        let aggchain_proof_service = aggchain_proof_server(grpc_service.clone(), &config.grpc);
+
+        let initialization = match built_service {
+            Some(built_service) => {
+                let grpc_service = grpc_service.clone();
+                async move {
+                    match built_service.await {
+                        Ok(service) => {
+                            grpc_service.initialize_with(service);
+                            info!("Aggchain proof service initialized");
+                        }
+                        Err(error) => {
+                            error!("Unable to initialize the aggchain proof service: {error:?}")
+                        }
+                    }
+                }
+                .boxed()
+            }
+            None => {
+                let grpc_service = grpc_service.clone();
+                let config = config.clone();
+                let cancellation_token = global_cancellation_token.clone();
+                async move {
+                    initialize_with_backoff(
+                        grpc_service,
+                        &config.aggchain_proof_service,
+                        &config.initialization,
+                        cancellation_token,
+                    )
+                    .await
+                }
+                .boxed()
+            }
+        };
+
+        let mut prover_engine = ProverEngine::new(
+            config.grpc_endpoint,
+            config.telemetry.addr,
+            config.shutdown.runtime_timeout,
+        )
+        .set_drain_timeout(config.shutdown.drain_timeout)
+        .set_health_check_interval(config.health.check_interval)
+        .set_health_check_timeout(config.health.check_timeout)
+        .add_health_check(
+            <AggchainProofServiceServer<GrpcService> as NamedService>::NAME,
+            {
+                let grpc_service = grpc_service.clone();
+                move || {
+                    let grpc_service = grpc_service.clone();
+                    async move { grpc_service.check_health().await }
+                }
+            },
         )
-    })?;
-
-    ProverEngine::new(
-        config.grpc_endpoint,
-        config.telemetry.addr,
-        config.shutdown.runtime_timeout,
-    )
-    .add_rpc_service(aggchain_proof_service)
-    .add_reflection_service(aggkit_prover_types::v1::FILE_DESCRIPTOR_SET)
-    .set_rpc_runtime(prover_runtime)
-    .set_metrics_runtime(metrics_runtime)
-    .set_cancellation_token(global_cancellation_token)
-    .start()
+        .add_admin_routes(admin::status_routes(version, grpc_service.clone()))
+        .add_admin_routes(admin::vkeys_routes(grpc_service))
+        .add_admin_routes(admin::config_routes(&config)?)
+        .add_background_task(initialization)
+        .add_rpc_service(aggchain_proof_service)
+        .add_reflection_service(aggkit_prover_types::v1::FILE_DESCRIPTOR_SET)
+        .set_rpc_runtime(prover_runtime)
+        .set_metrics_runtime(metrics_runtime)
+        .set_cancellation_token(global_cancellation_token);
+
+        if let Some(log_filter) = log_filter {
+            prover_engine = prover_engine.add_admin_routes(admin::log_level_routes(log_filter));
+        }
+
+        if let Some(grpc_listener) = grpc_listener {
+            prover_engine = prover_engine.set_rpc_listener(grpc_listener);
+        }
+
+        if let Some(admin_addr) = config.telemetry.admin_addr {
+            prover_engine = prover_engine.set_admin_socket_addr(admin_addr);
+        }
+
+        prover_engine.start()
+    }
 }
 
 /// Common version information about the executed agglayer binary.
@@ -1,57 +1,173 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, OnceLock},
+};
+
 use aggchain_proof_service::{
     config::AggchainProofServiceConfig,
-    service::{AggchainProofService, AggchainProofServiceRequest},
+    health::AggchainProofServiceHealth,
+    service::{AggchainProofService, AggchainProofServiceRequest, AggchainProofServiceResponse},
 };
 use aggchain_proof_types::{AggchainProofInputs, OptimisticAggchainProofInputs};
+use aggkit_prover_config::{CompressionEncoding, GrpcConfig};
 use aggkit_prover_types::{
     conversion::v1::context::Contextualize as _,
     error::AggchainProofRequestError,
     v1::{
-        aggchain_proof_service_server::AggchainProofService as AggchainProofGrpcService,
+        aggchain_proof_service_server::{
+            AggchainProofService as AggchainProofGrpcService, AggchainProofServiceServer,
+        },
         GenerateAggchainProofRequest, GenerateAggchainProofResponse,
         GenerateOptimisticAggchainProofRequest, GenerateOptimisticAggchainProofResponse,
     },
 };
 use agglayer_interop::{
     grpc::v1::{AggchainProof, Sp1StarkProof},
-    types::bincode,
+    types::{bincode, Digest},
 };
 use prost::bytes::Bytes;
 use prover_executor::sp1_fast;
-use sp1_sdk::SP1_CIRCUIT_VERSION;
+use prover_logger::propagation::set_parent_from_headers;
+use sp1_sdk::{SP1VerifyingKey, SP1_CIRCUIT_VERSION};
 use tonic::{Request, Response, Status};
 use tonic_types::{ErrorDetails, StatusExt};
 use tower::{buffer::Buffer, Service, ServiceExt};
-use tracing::{error, info, instrument};
+use tracing::{error, info, info_span, Instrument as _};
+
+use crate::jobs::{JobKind, JobTracker};
 
 const MAX_CONCURRENT_REQUESTS: usize = 100;
 
-#[derive(Clone)]
+/// gRPC front of the [`AggchainProofService`].
+///
+/// The service can be created before the aggchain proof service is
+/// initialized, in which case every request is refused as `Unavailable` until
+/// [`GrpcService::try_initialize`] succeeds.
+#[derive(Clone, Default)]
 pub struct GrpcService {
+    inner: Arc<OnceLock<InitializedService>>,
+    jobs: JobTracker,
+}
+
+struct InitializedService {
     service: Buffer<AggchainProofService, AggchainProofServiceRequest>,
+    health: AggchainProofServiceHealth,
+    aggchain_vkey: Arc<SP1VerifyingKey>,
 }
 
 impl GrpcService {
     pub async fn new(
         config: &AggchainProofServiceConfig,
     ) -> Result<Self, aggchain_proof_service::Error> {
-        Ok(GrpcService {
+        let service = Self::uninitialized();
+        service.try_initialize(config).await?;
+
+        Ok(service)
+    }
+
+    /// Creates a service refusing every request until initialized.
+    pub fn uninitialized() -> Self {
+        Self::default()
+    }
+
+    /// Makes one attempt at initializing the aggchain proof service.
+    pub async fn try_initialize(
+        &self,
+        config: &AggchainProofServiceConfig,
+    ) -> Result<(), aggchain_proof_service::Error> {
+        let service = AggchainProofService::new(config).await?;
+        self.initialize_with(service);
+
+        Ok(())
+    }
+
+    /// Initializes the service with an already built aggchain proof service.
+    /// Does nothing if the service is already initialized.
+    pub fn initialize_with(&self, service: AggchainProofService) {
+        let health = service.health();
+        let aggchain_vkey = service.aggchain_vkey();
+
+        _ = self.inner.set(InitializedService {
             service: tower::ServiceBuilder::new()
                 .buffer(MAX_CONCURRENT_REQUESTS)
-                .service(AggchainProofService::new(config).await?),
+                .service(service),
+            health,
+            aggchain_vkey,
+        });
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.inner.get().is_some()
+    }
+
+    /// Verification key of the aggchain proof program, known once the service
+    /// is initialized.
+    pub fn aggchain_vkey(&self) -> Option<Arc<SP1VerifyingKey>> {
+        self.inner
+            .get()
+            .map(|initialized| initialized.aggchain_vkey.clone())
+    }
+
+    /// Proof requests in flight.
+    pub fn jobs(&self) -> &JobTracker {
+        &self.jobs
+    }
+
+    /// Checks the dependencies of the aggchain proof service, failing while
+    /// it is not initialized.
+    pub async fn check_health(&self) -> eyre::Result<()> {
+        let Some(initialized) = self.inner.get() else {
+            eyre::bail!("The aggchain proof service is not initialized yet");
+        };
+
+        Ok(initialized.health.check().await?)
+    }
+
+    fn service(&self) -> Result<Buffer<AggchainProofService, AggchainProofServiceRequest>, Status> {
+        self.inner
+            .get()
+            .map(|initialized| initialized.service.clone())
+            .ok_or_else(|| Status::unavailable("The aggchain proof service is initializing"))
+    }
+}
+
+/// Wraps an aggchain proof gRPC service into a server applying the message
+/// size limits and compression encodings from the `[grpc]` configuration.
+pub fn aggchain_proof_server<S>(service: S, config: &GrpcConfig) -> AggchainProofServiceServer<S>
+where
+    S: AggchainProofGrpcService,
+{
+    let server = AggchainProofServiceServer::new(service)
+        .max_decoding_message_size(config.max_decoding_message_size)
+        .max_encoding_message_size(config.max_encoding_message_size);
+
+    let server = config
+        .accept_compressed
+        .iter()
+        .fold(server, |server, encoding| {
+            server.accept_compressed(tonic_compression_encoding(*encoding))
+        });
+
+    config
+        .send_compressed
+        .iter()
+        .fold(server, |server, encoding| {
+            server.send_compressed(tonic_compression_encoding(*encoding))
         })
+}
+
+fn tonic_compression_encoding(encoding: CompressionEncoding) -> tonic::codec::CompressionEncoding {
+    match encoding {
+        CompressionEncoding::Gzip => tonic::codec::CompressionEncoding::Gzip,
+        CompressionEncoding::Zstd => tonic::codec::CompressionEncoding::Zstd,
     }
 }
 
-#[tonic::async_trait]
-impl AggchainProofGrpcService for GrpcService {
-    #[instrument(skip(self, request))]
-    async fn generate_aggchain_proof(
+impl GrpcService {
+    async fn handle_aggchain_proof_request(
         &self,
-        request: Request<GenerateAggchainProofRequest>,
+        request: GenerateAggchainProofRequest,
     ) -> Result<Response<GenerateAggchainProofResponse>, Status> {
-        let request = request.into_inner();
-
         let last_proven_block = request.last_proven_block;
         let requested_end_block = request.requested_end_block;
 
@@ -99,11 +215,20 @@ impl AggchainProofGrpcService for GrpcService {
                     )
                 })?;
 
+        let job = self.jobs.start(
+            JobKind::AggchainProof,
+            last_proven_block,
+            requested_end_block,
+        );
+
         let mut context = aggchain_proof_inputs.context();
 
-        let proof_request = AggchainProofServiceRequest::Normal(aggchain_proof_inputs);
+        let proof_request = AggchainProofServiceRequest::Normal {
+            inputs: aggchain_proof_inputs,
+            progress: job.progress(),
+        };
 
-        let mut service = self.service.clone();
+        let mut service = self.service()?;
 
         let service = service
             .ready()
@@ -117,6 +242,8 @@ impl AggchainProofGrpcService for GrpcService {
                     "customchaindata: {}",
                     hex::encode(&response.custom_chain_data)
                 );
+                // The context entries below duplicate the typed response fields and are only
+                // kept for backwards compatibility.
                 context.insert(
                     "public_values".to_owned(),
                     Bytes::from(
@@ -135,41 +262,23 @@ impl AggchainProofGrpcService for GrpcService {
                 );
                 info!(last_proven_block = %response.last_proven_block,
                     end_block = %response.end_block,
+                    network_request_id = ?response.network_request_id,
                     "GenerateAggchainProof request executed successfully");
-                Ok(Response::new(GenerateAggchainProofResponse {
-                    aggchain_proof: Some(AggchainProof {
-                        aggchain_params: Some(response.aggchain_params.into()),
-                        // Signature is handled by the initiator
-                        signature: None,
-                        context,
-                        proof: Some(agglayer_interop::grpc::v1::aggchain_proof::Proof::Sp1Stark(
-                            Sp1StarkProof {
-                                version: SP1_CIRCUIT_VERSION.to_string(),
-                                proof: response.proof.into(),
-                                vkey: response.vkey.into(),
-                            },
-                        )),
-                    }),
-                    last_proven_block: response.last_proven_block,
-                    end_block: response.end_block,
-                    local_exit_root_hash: Some(response.local_exit_root_hash.into()),
-                    custom_chain_data: response.custom_chain_data.into(),
-                }))
+                Ok(Response::new(generate_aggchain_proof_response(
+                    response, context,
+                )))
             }
             Err(error) => {
                 error!(%last_proven_block, %requested_end_block, ?error, "Unable to execute GenerateAggchainProof request");
-                Err(Status::internal(error.to_string()))
+                Err(proof_generation_error_status(error.as_ref()))
             }
         }
     }
 
-    #[instrument(skip(self, request))]
-    async fn generate_optimistic_aggchain_proof(
+    async fn handle_optimistic_aggchain_proof_request(
         &self,
-        request: Request<GenerateOptimisticAggchainProofRequest>,
+        request: GenerateOptimisticAggchainProofRequest,
     ) -> Result<Response<GenerateOptimisticAggchainProofResponse>, Status> {
-        let request = request.into_inner();
-
         let aggchain_proof_inputs: OptimisticAggchainProofInputs =
             request
                 .try_into()
@@ -226,11 +335,20 @@ impl AggchainProofGrpcService for GrpcService {
             ));
         }
 
+        let job = self.jobs.start(
+            JobKind::OptimisticAggchainProof,
+            last_proven_block,
+            requested_end_block,
+        );
+
         let mut context = aggchain_proof_inputs.context();
 
-        let proof_request = AggchainProofServiceRequest::Optimistic(aggchain_proof_inputs);
+        let proof_request = AggchainProofServiceRequest::Optimistic {
+            inputs: aggchain_proof_inputs,
+            progress: job.progress(),
+        };
 
-        let mut service = self.service.clone();
+        let mut service = self.service()?;
 
         let service = service
             .ready()
@@ -240,6 +358,8 @@ impl AggchainProofGrpcService for GrpcService {
 
         match service.call(proof_request).await {
             Ok(response) => {
+                // The context entries below duplicate the typed response fields and are only
+                // kept for backwards compatibility.
                 context.insert(
                     "public_values".to_owned(),
                     Bytes::from(
@@ -258,29 +378,166 @@ impl AggchainProofGrpcService for GrpcService {
                 );
                 info!(last_proven_block = %response.last_proven_block,
                     end_block = %response.end_block,
+                    network_request_id = ?response.network_request_id,
                     "Generate optimistic aggchain proof request executed successfully");
-                Ok(Response::new(GenerateOptimisticAggchainProofResponse {
-                    aggchain_proof: Some(AggchainProof {
-                        aggchain_params: Some(response.aggchain_params.into()),
-                        // Signature is handled by the initiator
-                        signature: None,
-                        context,
-                        proof: Some(agglayer_interop::grpc::v1::aggchain_proof::Proof::Sp1Stark(
-                            Sp1StarkProof {
-                                version: SP1_CIRCUIT_VERSION.to_string(),
-                                proof: response.proof.into(),
-                                vkey: response.vkey.into(),
-                            },
-                        )),
-                    }),
-                    local_exit_root_hash: Some(response.local_exit_root_hash.into()),
-                    custom_chain_data: response.custom_chain_data.into(),
-                }))
+                Ok(Response::new(generate_optimistic_aggchain_proof_response(
+                    response, context,
+                )))
             }
             Err(error) => {
                 error!(%last_proven_block, %requested_end_block, ?error, "Unable to execute GenerateOptimisticAggchainProof request");
-                Err(Status::internal(error.to_string()))
+                Err(proof_generation_error_status(error.as_ref()))
             }
         }
     }
 }
+
+/// Response to a `GenerateAggchainProof` request, whose aggchain proof
+/// carries the request `context`.
+pub(crate) fn generate_aggchain_proof_response(
+    response: AggchainProofServiceResponse,
+    context: HashMap<String, Bytes>,
+) -> GenerateAggchainProofResponse {
+    GenerateAggchainProofResponse {
+        aggchain_proof: Some(aggchain_proof(
+            response.aggchain_params,
+            response.proof,
+            response.vkey,
+            context,
+        )),
+        last_proven_block: response.last_proven_block,
+        end_block: response.end_block,
+        local_exit_root_hash: Some(response.local_exit_root_hash.into()),
+        custom_chain_data: response.custom_chain_data.into(),
+        public_values: Some(response.public_values.into()),
+        output_root: Some(response.output_root.into()),
+        aggchain_params_values: Some(response.aggchain_params_values.into()),
+        network_request_id: response
+            .network_request_id
+            .map(|network_request_id| Digest(network_request_id.0).into()),
+    }
+}
+
+/// Response to a `GenerateOptimisticAggchainProof` request, whose aggchain
+/// proof carries the request `context`.
+pub(crate) fn generate_optimistic_aggchain_proof_response(
+    response: AggchainProofServiceResponse,
+    context: HashMap<String, Bytes>,
+) -> GenerateOptimisticAggchainProofResponse {
+    GenerateOptimisticAggchainProofResponse {
+        aggchain_proof: Some(aggchain_proof(
+            response.aggchain_params,
+            response.proof,
+            response.vkey,
+            context,
+        )),
+        local_exit_root_hash: Some(response.local_exit_root_hash.into()),
+        custom_chain_data: response.custom_chain_data.into(),
+        last_proven_block: response.last_proven_block,
+        end_block: response.end_block,
+        public_values: Some(response.public_values.into()),
+        output_root: Some(response.output_root.into()),
+        aggchain_params_values: Some(response.aggchain_params_values.into()),
+        network_request_id: response
+            .network_request_id
+            .map(|network_request_id| Digest(network_request_id.0).into()),
+    }
+}
+
+fn aggchain_proof(
+    aggchain_params: Digest,
+    proof: Vec<u8>,
+    vkey: Vec<u8>,
+    context: HashMap<String, Bytes>,
+) -> AggchainProof {
+    AggchainProof {
+        aggchain_params: Some(aggchain_params.into()),
+        // Signature is handled by the initiator
+        signature: None,
+        context,
+        proof: Some(agglayer_interop::grpc::v1::aggchain_proof::Proof::Sp1Stark(
+            Sp1StarkProof {
+                version: SP1_CIRCUIT_VERSION.to_string(),
+                proof: proof.into(),
+                vkey: vkey.into(),
+            },
+        )),
+    }
+}
+
+/// Maps a failed proof generation to a gRPC status.
+///
+/// A request for L2 blocks beyond the configured finality is rejected as
+/// `FailedPrecondition`. A mismatch between the bridge events of the request
+/// and the L2 logs is the caller's fault, every difference is reported as a
+/// bad request violation of the related field. Any other failure is
+/// `Internal`, with the chain of its causes reported as debug info.
+pub(crate) fn proof_generation_error_status(error: &(dyn std::error::Error + 'static)) -> Status {
+    let mut diff = None;
+    for error in std::iter::successors(Some(error), |error| error.source()) {
+        if let Some(error @ aggchain_proof_service::Error::L2BlockNotFinal { .. }) =
+            error.downcast_ref::<aggchain_proof_service::Error>()
+        {
+            return Status::failed_precondition(error.to_string());
+        }
+
+        if let Some(aggchain_proof_builder::Error::BridgeEventsMismatch(mismatch)) =
+            error.downcast_ref::<aggchain_proof_builder::Error>()
+        {
+            diff = Some(mismatch);
+            break;
+        }
+    }
+
+    let Some(diff) = diff else {
+        let causes = std::iter::successors(error.source(), |error| error.source())
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        return Status::with_error_details(
+            tonic::Code::Internal,
+            error.to_string(),
+            ErrorDetails::with_debug_info(causes, ""),
+        );
+    };
+
+    let mut error_details = ErrorDetails::new();
+    for (field, description) in diff.violations() {
+        error_details.add_bad_request_violation(field, description);
+    }
+
+    Status::with_error_details(
+        tonic::Code::InvalidArgument,
+        "Bridge events supplied in the request do not match the l2 logs",
+        error_details,
+    )
+}
+
+// The request spans are created before being entered, so that they continue
+// the trace of the caller.
+#[tonic::async_trait]
+impl AggchainProofGrpcService for GrpcService {
+    async fn generate_aggchain_proof(
+        &self,
+        request: Request<GenerateAggchainProofRequest>,
+    ) -> Result<Response<GenerateAggchainProofResponse>, Status> {
+        let span = info_span!("generate_aggchain_proof");
+        set_parent_from_headers(&span, &request.metadata().clone().into_headers());
+
+        self.handle_aggchain_proof_request(request.into_inner())
+            .instrument(span)
+            .await
+    }
+
+    async fn generate_optimistic_aggchain_proof(
+        &self,
+        request: Request<GenerateOptimisticAggchainProofRequest>,
+    ) -> Result<Response<GenerateOptimisticAggchainProofResponse>, Status> {
+        let span = info_span!("generate_optimistic_aggchain_proof");
+        set_parent_from_headers(&span, &request.metadata().clone().into_headers());
+
+        self.handle_optimistic_aggchain_proof_request(request.into_inner())
+            .instrument(span)
+            .await
+    }
+}
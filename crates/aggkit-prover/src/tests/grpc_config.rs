@@ -0,0 +1,195 @@
+use aggkit_prover_config::{CompressionEncoding, GrpcConfig};
+use aggkit_prover_types::v1::{
+    aggchain_proof_service_client::AggchainProofServiceClient,
+    aggchain_proof_service_server::AggchainProofService as AggchainProofGrpcService,
+    GenerateAggchainProofRequest, GenerateAggchainProofResponse,
+    GenerateOptimisticAggchainProofRequest, GenerateOptimisticAggchainProofResponse,
+    ImportedBridgeExitWithBlockNumber,
+};
+use agglayer_interop::grpc::v1::FixedBytes32;
+use http::Uri;
+use hyper_util::rt::TokioIo;
+use prost::bytes::Bytes;
+use tonic::{
+    transport::{Channel, Endpoint, Server},
+    Request, Response, Status,
+};
+use tower::service_fn;
+
+use crate::rpc::aggchain_proof_server;
+
+const MIB: usize = 1024 * 1024;
+
+/// Test service answering with a `custom_chain_data` payload of
+/// `requested_end_block` bytes, so that both directions of the message size
+/// limits can be exercised without any proving backend.
+struct SizedResponseService;
+
+#[tonic::async_trait]
+impl AggchainProofGrpcService for SizedResponseService {
+    async fn generate_aggchain_proof(
+        &self,
+        request: Request<GenerateAggchainProofRequest>,
+    ) -> Result<Response<GenerateAggchainProofResponse>, Status> {
+        let request = request.into_inner();
+
+        Ok(Response::new(GenerateAggchainProofResponse {
+            last_proven_block: request.imported_bridge_exits.len() as u64,
+            custom_chain_data: Bytes::from(vec![0xaa; request.requested_end_block as usize]),
+            ..Default::default()
+        }))
+    }
+
+    async fn generate_optimistic_aggchain_proof(
+        &self,
+        _request: Request<GenerateOptimisticAggchainProofRequest>,
+    ) -> Result<Response<GenerateOptimisticAggchainProofResponse>, Status> {
+        Err(Status::unimplemented("not used in tests"))
+    }
+}
+
+async fn serve(config: &GrpcConfig) -> AggchainProofServiceClient<Channel> {
+    let (client, server) = tokio::io::duplex(64 * 1024);
+    let service = aggchain_proof_server(SizedResponseService, config);
+
+    tokio::spawn(async move {
+        Server::builder()
+            .add_service(service)
+            .serve_with_incoming(tokio_stream::once(Ok::<_, std::io::Error>(server)))
+            .await
+    });
+
+    let mut client = Some(client);
+    let channel = Endpoint::try_from("http://[::]:50051")
+        .expect("valid endpoint")
+        .connect_with_connector(service_fn(move |_: Uri| {
+            let client = client.take();
+
+            async move {
+                if let Some(client) = client {
+                    Ok(TokioIo::new(client))
+                } else {
+                    Err(std::io::Error::other("Client already taken"))
+                }
+            }
+        }))
+        .await
+        .unwrap();
+
+    AggchainProofServiceClient::new(channel)
+        .max_decoding_message_size(usize::MAX)
+        .max_encoding_message_size(usize::MAX)
+}
+
+/// Builds a request carrying `imported_bridge_exits` entries and asking for a
+/// response of `response_size` bytes.
+fn request(imported_bridge_exits: usize, response_size: usize) -> GenerateAggchainProofRequest {
+    GenerateAggchainProofRequest {
+        last_proven_block: 0,
+        requested_end_block: response_size as u64,
+        imported_bridge_exits: (0..imported_bridge_exits)
+            .map(|i| ImportedBridgeExitWithBlockNumber {
+                block_number: i as u64,
+                global_index: Some(FixedBytes32 {
+                    value: Bytes::from(vec![1; 32]),
+                }),
+                bridge_exit_hash: Some(FixedBytes32 {
+                    value: Bytes::from(vec![2; 32]),
+                }),
+                log_index: i as u64,
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn config(max_message_size: usize) -> GrpcConfig {
+    GrpcConfig {
+        max_decoding_message_size: max_message_size,
+        max_encoding_message_size: max_message_size,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn oversized_request_is_rejected() {
+    let mut client = serve(&config(MIB)).await;
+
+    let error = client
+        .generate_aggchain_proof(request(20_000, 0))
+        .await
+        .unwrap_err();
+
+    assert!(
+        error.message().contains("message length too large"),
+        "unexpected error: {error:?}"
+    );
+}
+
+#[tokio::test]
+async fn oversized_response_is_rejected() {
+    let mut client = serve(&config(MIB)).await;
+
+    let error = client
+        .generate_aggchain_proof(request(0, 2 * MIB))
+        .await
+        .unwrap_err();
+
+    assert!(
+        error.message().contains("message length too large"),
+        "unexpected error: {error:?}"
+    );
+}
+
+#[tokio::test]
+async fn large_payloads_pass_with_raised_limits() {
+    let mut client = serve(&config(64 * MIB)).await;
+
+    let response = client
+        .generate_aggchain_proof(request(20_000, 8 * MIB))
+        .await
+        .expect("large payloads within the configured limits")
+        .into_inner();
+
+    assert_eq!(response.last_proven_block, 20_000);
+    assert_eq!(response.custom_chain_data.len(), 8 * MIB);
+}
+
+#[tokio::test]
+async fn compressed_payloads_are_negotiated() {
+    for encoding in [
+        tonic::codec::CompressionEncoding::Gzip,
+        tonic::codec::CompressionEncoding::Zstd,
+    ] {
+        let mut client = serve(&config(64 * MIB))
+            .await
+            .send_compressed(encoding)
+            .accept_compressed(encoding);
+
+        let response = client
+            .generate_aggchain_proof(request(20_000, 8 * MIB))
+            .await
+            .expect("compressed payloads are accepted by default")
+            .into_inner();
+
+        assert_eq!(response.last_proven_block, 20_000);
+        assert_eq!(response.custom_chain_data.len(), 8 * MIB);
+    }
+}
+
+#[tokio::test]
+async fn disabled_compression_is_refused() {
+    let mut client = serve(&GrpcConfig {
+        accept_compressed: vec![CompressionEncoding::Gzip],
+        ..Default::default()
+    })
+    .await
+    .send_compressed(tonic::codec::CompressionEncoding::Zstd);
+
+    let error = client
+        .generate_aggchain_proof(request(1, 0))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.code(), tonic::Code::Unimplemented);
+}
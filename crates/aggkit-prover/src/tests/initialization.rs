@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use crate::{initialization::next_delay, rpc::GrpcService};
+
+#[test]
+fn backoff_doubles_up_to_the_maximum() {
+    let max = Duration::from_secs(60);
+
+    let delays: Vec<_> = std::iter::successors(Some(Duration::from_secs(1)), |delay| {
+        Some(next_delay(*delay, max))
+    })
+    .take(8)
+    .map(|delay| delay.as_secs())
+    .collect();
+
+    assert_eq!(delays, vec![1, 2, 4, 8, 16, 32, 60, 60]);
+}
+
+#[tokio::test]
+async fn uninitialized_service_is_unhealthy() {
+    let service = GrpcService::uninitialized();
+
+    assert!(!service.is_initialized());
+    assert!(service.check_health().await.is_err());
+}
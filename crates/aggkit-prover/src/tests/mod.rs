@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
+use aggchain_proof_core::full_execution_proof::AggchainParamsValues;
 use aggchain_proof_service::{
-    config::AggchainProofServiceConfig,
-    service::{AggchainProofService, AggchainProofServiceRequest},
+    config::{AggchainProofServiceConfig, L2FinalityPolicy},
+    service::{AggchainProofService, AggchainProofServiceRequest, AggchainProofServiceResponse},
 };
 use aggchain_proof_types::AggchainProofInputs;
 use aggkit_prover_types::{
@@ -12,14 +13,119 @@ use aggkit_prover_types::{
     },
     Digest,
 };
-use agglayer_interop::types::{L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof};
+use agglayer_interop::{
+    grpc::v1::FixedBytes32,
+    types::{L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof},
+};
+use alloy_primitives::{Address, B256, U256};
 use http::Uri;
 use hyper_util::rt::TokioIo;
 use tonic::transport::{Endpoint, Server};
 use tonic_types::StatusExt;
 use tower::{service_fn, Service};
+use unified_bridge::AggchainProofPublicValues;
+
+use crate::rpc::{
+    generate_aggchain_proof_response, generate_optimistic_aggchain_proof_response,
+    proof_generation_error_status, GrpcService,
+};
+
+mod admin;
+mod grpc_config;
+mod initialization;
+
+#[test]
+fn wrapped_l2_block_not_final_is_a_failed_precondition() {
+    let error = eyre::Report::new(aggchain_proof_service::Error::L2BlockNotFinal {
+        block_number: 20,
+        policy: L2FinalityPolicy::Safe,
+        head: 10,
+    })
+    .wrap_err("Aggchain proof request failed");
+
+    let status = proof_generation_error_status(error.as_ref());
+
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+}
+
+#[test]
+fn internal_error_reports_its_causes() {
+    let error = eyre::eyre!("Prover failed")
+        .wrap_err("Aggchain proof builder request failed")
+        .wrap_err("Aggchain proof request failed");
+
+    let status = proof_generation_error_status(error.as_ref());
+
+    assert_eq!(status.code(), tonic::Code::Internal);
+    assert_eq!(status.message(), "Aggchain proof request failed");
+    assert_eq!(
+        status
+            .get_error_details()
+            .debug_info()
+            .unwrap()
+            .stack_entries,
+        ["Aggchain proof builder request failed", "Prover failed"]
+    );
+}
+
+fn aggchain_proof_service_response(
+    network_request_id: Option<B256>,
+) -> AggchainProofServiceResponse {
+    AggchainProofServiceResponse {
+        proof: vec![1, 2, 3],
+        aggchain_params: Digest([8; 32]),
+        vkey: vec![4, 5, 6],
+        last_proven_block: 100,
+        end_block: 110,
+        local_exit_root_hash: Digest([7; 32]),
+        custom_chain_data: vec![9],
+        public_values: AggchainProofPublicValues {
+            prev_local_exit_root: Digest([1; 32]),
+            new_local_exit_root: Digest([7; 32]),
+            l1_info_root: Digest([2; 32]),
+            origin_network: 1u32.into(),
+            commit_imported_bridge_exits: Digest([3; 32]),
+            aggchain_params: Digest([8; 32]),
+        },
+        output_root: Digest([4; 32]),
+        aggchain_params_values: AggchainParamsValues {
+            l2_pre_root: B256::repeat_byte(0x11),
+            claim_root: B256::repeat_byte(0x04),
+            claim_block_num: U256::from(110),
+            rollup_config_hash: B256::repeat_byte(0x12),
+            optimistic_mode: false,
+            trusted_sequencer: Address::repeat_byte(0x13),
+            range_vkey_commitment: B256::repeat_byte(0x14),
+            aggregation_vkey_hash: B256::repeat_byte(0x15),
+        },
+        network_request_id,
+    }
+}
+
+#[test]
+fn network_request_id_is_reported_in_the_responses() {
+    let network_request_id: Option<FixedBytes32> = Some(Digest([0x42; 32]).into());
+
+    let response = generate_aggchain_proof_response(
+        aggchain_proof_service_response(Some(B256::repeat_byte(0x42))),
+        HashMap::new(),
+    );
+    assert_eq!(response.network_request_id, network_request_id);
 
-use crate::rpc::GrpcService;
+    let response = generate_optimistic_aggchain_proof_response(
+        aggchain_proof_service_response(Some(B256::repeat_byte(0x42))),
+        HashMap::new(),
+    );
+    assert_eq!(response.network_request_id, network_request_id);
+}
+
+#[test]
+fn locally_proven_responses_have_no_network_request_id() {
+    let response =
+        generate_aggchain_proof_response(aggchain_proof_service_response(None), HashMap::new());
+
+    assert_eq!(response.network_request_id, None);
+}
 
 #[tokio::test]
 #[ignore]
@@ -31,26 +137,29 @@ async fn service_can_be_called() {
     let mut service = AggchainProofService::new(&AggchainProofServiceConfig::default())
         .await
         .expect("create aggchain proof service");
-    let request = AggchainProofServiceRequest::Normal(AggchainProofInputs {
-        last_proven_block: 0,
-        requested_end_block: 100,
-        l1_info_tree_root_hash: Default::default(),
-        l1_info_tree_leaf: L1InfoTreeLeaf {
-            l1_info_tree_index: 1,
-            rer: Default::default(),
-            mer: Default::default(),
-            inner: L1InfoTreeLeafInner {
-                global_exit_root: Default::default(),
-                block_hash: Default::default(),
-                timestamp: 0u64,
+    let request = AggchainProofServiceRequest::Normal {
+        inputs: AggchainProofInputs {
+            last_proven_block: 0,
+            requested_end_block: 100,
+            l1_info_tree_root_hash: Default::default(),
+            l1_info_tree_leaf: L1InfoTreeLeaf {
+                l1_info_tree_index: 1,
+                rer: Default::default(),
+                mer: Default::default(),
+                inner: L1InfoTreeLeafInner {
+                    global_exit_root: Default::default(),
+                    block_hash: Default::default(),
+                    timestamp: 0u64,
+                },
             },
+            l1_info_tree_merkle_proof: MerkleProof::new(Digest::default(), [Digest::default(); 32]),
+            ger_leaves: Default::default(),
+            imported_bridge_exits: Default::default(),
+            removed_gers: Default::default(),
+            unclaims: Default::default(),
         },
-        l1_info_tree_merkle_proof: MerkleProof::new(Digest::default(), [Digest::default(); 32]),
-        ger_leaves: Default::default(),
-        imported_bridge_exits: Default::default(),
-        removed_gers: Default::default(),
-        unclaims: Default::default(),
-    });
+        progress: Default::default(),
+    };
     let response = service.call(request).await;
     assert!(response.is_ok());
 }
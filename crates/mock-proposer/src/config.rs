@@ -0,0 +1,78 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::{Path, PathBuf},
+};
+
+use aggchain_proof_contracts::config::AggchainProofContractsConfig;
+use prover_logger::log::Log;
+use serde::{Deserialize, Serialize};
+
+/// Port of the op-succinct proposer gRPC service.
+const DEFAULT_GRPC_PORT: u16 = 3000;
+
+/// The mock proposer configuration.
+///
+/// The public values of the aggregation proofs are computed from the L1 and L2
+/// nodes of the `contracts` configuration, or from their recorded traffic when
+/// it sets an `rpc-fixture` to replay.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MockProposerConfig {
+    /// The gRPC endpoint of the `Proofs` service.
+    #[serde(default = "default_grpc_endpoint")]
+    pub grpc_endpoint: SocketAddr,
+
+    /// The log configuration.
+    #[serde(default)]
+    pub log: Log,
+
+    /// Network id of the l2 chain the proofs are aggregated for.
+    #[serde(default)]
+    pub network_id: u32,
+
+    #[serde(default)]
+    pub contracts: AggchainProofContractsConfig,
+}
+
+impl Default for MockProposerConfig {
+    fn default() -> Self {
+        Self {
+            grpc_endpoint: default_grpc_endpoint(),
+            log: Log::default(),
+            network_id: 0,
+            contracts: AggchainProofContractsConfig::default(),
+        }
+    }
+}
+
+impl MockProposerConfig {
+    pub fn try_load(path: &Path) -> Result<Self, ConfigurationError> {
+        let reader = std::fs::read_to_string(path).map_err(|source| {
+            ConfigurationError::UnableToReadConfigFile {
+                path: path.to_path_buf(),
+                source,
+            }
+        })?;
+
+        let deserializer = toml::de::Deserializer::new(&reader);
+        serde::Deserialize::deserialize(deserializer)
+            .map_err(ConfigurationError::DeserializationError)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigurationError {
+    #[error("Unable to read the configuration file: {source}")]
+    UnableToReadConfigFile {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to deserialize the configuration: {0}")]
+    DeserializationError(#[from] toml::de::Error),
+}
+
+fn default_grpc_endpoint() -> SocketAddr {
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), DEFAULT_GRPC_PORT)
+}
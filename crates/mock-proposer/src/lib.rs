@@ -0,0 +1,250 @@
+//! Mock op-succinct proposer, serving mock aggregation proofs over the
+//! `Proofs` gRPC service of the proposer for the local and devnet setups.
+
+use std::{
+    collections::HashMap, future::Future, panic::AssertUnwindSafe, str::FromStr as _, sync::Mutex,
+};
+
+use aggchain_proof_core::full_execution_proof::AggregationProofPublicValues;
+use alloy_primitives::B256;
+use alloy_sol_types::SolType as _;
+use eyre::eyre;
+use proposer_client::rpc::grpc::{
+    proofs_server::{Proofs, ProofsServer},
+    AggProofRequest, AggProofResponse, CancelAggProofRequest, CancelAggProofResponse,
+    GetMockProofRequest, GetMockProofResponse,
+};
+use prover_executor::sp1_async;
+use sp1_sdk::{
+    Prover as _, ProvingKey as _, SP1ProofMode, SP1ProofWithPublicValues, SP1PublicValues,
+    SP1VerifyingKey, SP1_CIRCUIT_VERSION,
+};
+use tokio::net::TcpListener;
+use tonic::{transport::server::TcpIncoming, Request, Response, Status};
+use tracing::{info, warn};
+
+pub use crate::outputs::{AggregationOutputs, ContractsOutputs};
+
+pub mod config;
+mod outputs;
+
+#[cfg(test)]
+mod tests;
+
+/// Mock proposer answering the aggregation proof requests with mock proofs of
+/// the aggregation program, committing to the public values of its
+/// [`AggregationOutputs`].
+pub struct MockProposer<Outputs> {
+    outputs: Outputs,
+
+    /// Verification key of the aggregation program, in mock mode.
+    aggregation_vkey: SP1VerifyingKey,
+
+    /// Serialized mock proofs, by proof id.
+    proofs: Mutex<Vec<Vec<u8>>>,
+
+    /// Proof ids of the pending aggregation proof requests.
+    requests: Mutex<HashMap<RequestKey, i64>>,
+}
+
+/// Last proven block, requested end block and l1 block hash of an
+/// aggregation proof request.
+type RequestKey = (u64, u64, String);
+
+impl<Outputs: AggregationOutputs> MockProposer<Outputs> {
+    pub async fn new(outputs: Outputs) -> eyre::Result<Self> {
+        let prover = sp1_sdk::ProverClient::builder().mock().build().await;
+        let proving_key = sp1_async(AssertUnwindSafe(async {
+            prover.setup(proposer_elfs::aggregation::ELF.into()).await
+        }))
+        .await?
+        .map_err(|error| eyre!(error.to_string()))?;
+
+        Ok(Self {
+            outputs,
+            aggregation_vkey: proving_key.verifying_key().clone(),
+            proofs: Mutex::default(),
+            requests: Mutex::default(),
+        })
+    }
+
+    /// Serves the `Proofs` gRPC service on `listener` until `shutdown`
+    /// completes.
+    pub async fn serve(
+        self,
+        listener: TcpListener,
+        shutdown: impl Future<Output = ()>,
+    ) -> Result<(), tonic::transport::Error> {
+        tonic::transport::Server::builder()
+            .add_service(ProofsServer::new(self))
+            .serve_with_incoming_shutdown(TcpIncoming::from(listener), shutdown)
+            .await
+    }
+
+    fn store_proof(&self, proof: Vec<u8>) -> i64 {
+        let mut proofs = self
+            .proofs
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        proofs.push(proof);
+
+        (proofs.len() - 1) as i64
+    }
+
+    fn requests(&self) -> std::sync::MutexGuard<'_, HashMap<RequestKey, i64>> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Request id of the mock proof `proof_id`, as the proposer client decodes
+/// it.
+fn proof_request_id(proof_id: i64) -> Vec<u8> {
+    let mut request_id = [0; 32];
+    request_id[24..].copy_from_slice(&proof_id.to_be_bytes());
+
+    request_id.to_vec()
+}
+
+/// Mock proof id of the request id `proof_request_id`.
+fn proof_id(proof_request_id: &[u8]) -> Result<i64, Status> {
+    let request_id: [u8; 32] = proof_request_id.try_into().map_err(|_| {
+        Status::invalid_argument(format!(
+            "Invalid proof request id of {} bytes",
+            proof_request_id.len()
+        ))
+    })?;
+
+    Ok(i64::from_be_bytes(
+        request_id[24..].try_into().expect("8 bytes slice"),
+    ))
+}
+
+#[tonic::async_trait]
+impl<Outputs: AggregationOutputs> Proofs for MockProposer<Outputs> {
+    async fn request_agg_proof(
+        &self,
+        request: Request<AggProofRequest>,
+    ) -> Result<Response<AggProofResponse>, Status> {
+        let AggProofRequest {
+            last_proven_block,
+            requested_end_block,
+            l1_block_hash,
+            ..
+        } = request.into_inner();
+
+        if requested_end_block <= last_proven_block {
+            return Err(Status::invalid_argument(format!(
+                "Requested end block {requested_end_block} is not after the last proven block \
+                 {last_proven_block}"
+            )));
+        }
+
+        let l1_head = B256::from_str(&l1_block_hash).map_err(|error| {
+            Status::invalid_argument(format!("Invalid l1 block hash {l1_block_hash}: {error}"))
+        })?;
+
+        let public_values = self
+            .outputs
+            .aggregation_outputs(l1_head, last_proven_block, requested_end_block)
+            .await
+            .inspect_err(|error| warn!(?error, "Unable to compute the aggregation outputs"))
+            .map_err(|error| Status::unavailable(format!("{error:#}")))?;
+
+        let proof = SP1ProofWithPublicValues::create_mock_proof(
+            &self.aggregation_vkey,
+            SP1PublicValues::from(&AggregationProofPublicValues::abi_encode(&public_values)),
+            SP1ProofMode::Compressed,
+            SP1_CIRCUIT_VERSION,
+        );
+        let proof = agglayer_interop_types::bincode::default()
+            .serialize(&proof)
+            .map_err(|error| {
+                Status::internal(format!("Unable to serialize the mock proof: {error}"))
+            })?;
+
+        let proof_id = self.store_proof(proof);
+        self.requests().insert(
+            (last_proven_block, requested_end_block, l1_block_hash),
+            proof_id,
+        );
+        info!(%last_proven_block, end_block=%requested_end_block, %proof_id,
+            "Mock aggregation proof generated");
+
+        Ok(Response::new(AggProofResponse {
+            last_proven_block,
+            end_block: requested_end_block,
+            proof_request_id: proof_request_id(proof_id).into(),
+        }))
+    }
+
+    async fn get_agg_proof(
+        &self,
+        request: Request<AggProofRequest>,
+    ) -> Result<Response<AggProofResponse>, Status> {
+        let AggProofRequest {
+            last_proven_block,
+            requested_end_block,
+            l1_block_hash,
+            ..
+        } = request.into_inner();
+
+        let proof_id = self
+            .requests()
+            .get(&(last_proven_block, requested_end_block, l1_block_hash))
+            .copied()
+            .ok_or_else(|| {
+                Status::not_found(format!(
+                    "No aggregation proof request from {last_proven_block} to \
+                     {requested_end_block}"
+                ))
+            })?;
+
+        Ok(Response::new(AggProofResponse {
+            last_proven_block,
+            end_block: requested_end_block,
+            proof_request_id: proof_request_id(proof_id).into(),
+        }))
+    }
+
+    async fn cancel_agg_proof(
+        &self,
+        request: Request<CancelAggProofRequest>,
+    ) -> Result<Response<CancelAggProofResponse>, Status> {
+        let proof_id = proof_id(&request.into_inner().proof_request_id)?;
+
+        let mut requests = self.requests();
+        let len = requests.len();
+        requests.retain(|_, id| *id != proof_id);
+        if requests.len() == len {
+            return Err(Status::not_found(format!(
+                "Unknown aggregation proof request {proof_id}"
+            )));
+        }
+
+        info!(%proof_id, "Mock aggregation proof request cancelled");
+
+        Ok(Response::new(CancelAggProofResponse {}))
+    }
+
+    async fn get_mock_proof(
+        &self,
+        request: Request<GetMockProofRequest>,
+    ) -> Result<Response<GetMockProofResponse>, Status> {
+        let proof_id = request.into_inner().proof_id;
+
+        let proofs = self
+            .proofs
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let proof = usize::try_from(proof_id)
+            .ok()
+            .and_then(|index| proofs.get(index))
+            .ok_or_else(|| Status::not_found(format!("Unknown mock proof {proof_id}")))?;
+
+        Ok(Response::new(GetMockProofResponse {
+            proof: proof.clone().into(),
+        }))
+    }
+}
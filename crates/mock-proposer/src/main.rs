@@ -0,0 +1,52 @@
+use std::{path::PathBuf, sync::Arc};
+
+use aggchain_proof_contracts::AggchainContractsRpcClient;
+use clap::{Parser, ValueHint};
+use eyre::Context as _;
+use mock_proposer::{config::MockProposerConfig, ContractsOutputs, MockProposer};
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+/// Mock op-succinct proposer, serving mock aggregation proofs.
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Cli {
+    /// The path to the configuration file.
+    #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "mock-proposer.toml", env = "CONFIG_PATH")]
+    config_path: PathBuf,
+}
+
+#[tokio::main]
+pub async fn main() -> eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cli = Cli::parse();
+    let config = MockProposerConfig::try_load(&cli.config_path)?;
+
+    let _tracing_guard = prover_logger::tracing(&config.log);
+
+    let contracts_client = AggchainContractsRpcClient::new(config.network_id, &config.contracts)
+        .await
+        .context("Failed to create the contracts client")?;
+    let proposer = MockProposer::new(ContractsOutputs::new(Arc::new(contracts_client))).await?;
+
+    let listener = tokio::net::TcpListener::bind(config.grpc_endpoint)
+        .await
+        .with_context(|| format!("Failed to bind {}", config.grpc_endpoint))?;
+    info!("Mock proposer listening on {}", config.grpc_endpoint);
+
+    let cancellation_token = CancellationToken::new();
+    tokio::spawn({
+        let cancellation_token = cancellation_token.clone();
+        async move {
+            _ = tokio::signal::ctrl_c().await;
+            cancellation_token.cancel();
+        }
+    });
+
+    proposer
+        .serve(listener, cancellation_token.cancelled_owned())
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use aggchain_proof_contracts::contracts::{
+    GetTrustedSequencerAddress, L1OpSuccinctConfigFetcher, L2OutputAtBlock, L2OutputAtBlockFetcher,
+};
+use aggchain_proof_core::full_execution_proof::{
+    compute_output_root, AggregationProofPublicValues,
+};
+use alloy_primitives::B256;
+use eyre::Context as _;
+
+/// Source of the public values of the mock aggregation proofs.
+#[async_trait::async_trait]
+pub trait AggregationOutputs: Send + Sync + 'static {
+    /// Public values of the aggregation proof of the blocks after
+    /// `last_proven_block` up to `end_block`, anchored at the l1 block
+    /// `l1_head`.
+    async fn aggregation_outputs(
+        &self,
+        l1_head: B256,
+        last_proven_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<AggregationProofPublicValues>;
+}
+
+/// Public values read from the L1 and L2 chains, as the aggregation program
+/// commits to them.
+pub struct ContractsOutputs<ContractsClient> {
+    contracts_client: Arc<ContractsClient>,
+}
+
+impl<ContractsClient> ContractsOutputs<ContractsClient> {
+    pub fn new(contracts_client: Arc<ContractsClient>) -> Self {
+        Self { contracts_client }
+    }
+}
+
+#[async_trait::async_trait]
+impl<ContractsClient> AggregationOutputs for ContractsOutputs<ContractsClient>
+where
+    ContractsClient: L2OutputAtBlockFetcher
+        + L1OpSuccinctConfigFetcher
+        + GetTrustedSequencerAddress
+        + Send
+        + Sync
+        + 'static,
+{
+    async fn aggregation_outputs(
+        &self,
+        l1_head: B256,
+        last_proven_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<AggregationProofPublicValues> {
+        let output_root = |output: L2OutputAtBlock| {
+            compute_output_root(
+                output.state_root.0,
+                output.withdrawal_storage_root.0,
+                output.latest_block_hash.0,
+            )
+        };
+
+        let l2_pre_output = self
+            .contracts_client
+            .get_l2_output_at_block(last_proven_block)
+            .await
+            .with_context(|| format!("Retrieving the l2 output at block {last_proven_block}"))?;
+
+        let l2_post_output = self
+            .contracts_client
+            .get_l2_output_at_block(end_block)
+            .await
+            .with_context(|| format!("Retrieving the l2 output at block {end_block}"))?;
+
+        let op_succinct_config = self
+            .contracts_client
+            .get_op_succinct_config()
+            .await
+            .context("Retrieving the op succinct config")?;
+
+        let trusted_sequencer = self
+            .contracts_client
+            .get_trusted_sequencer_address()
+            .await
+            .context("Retrieving the trusted sequencer address")?;
+
+        Ok(AggregationProofPublicValues {
+            l1_head,
+            l2_pre_root: output_root(l2_pre_output).into(),
+            l2_post_root: output_root(l2_post_output).into(),
+            l2_block_number: end_block,
+            rollup_config_hash: op_succinct_config.rollup_config_hash.0.into(),
+            multi_block_vkey: proposer_elfs::range::commitment().into(),
+            prover_address: trusted_sequencer.into(),
+        })
+    }
+}
@@ -0,0 +1,188 @@
+use std::{sync::Arc, time::Duration};
+
+use aggchain_proof_contracts::{
+    contracts::{L2OutputAtBlock, OpSuccinctConfig},
+    testutils::{FakeAggchainContractsClient, FakeL2Block},
+};
+use aggchain_proof_core::full_execution_proof::compute_output_root;
+use agglayer_evm_client::MockRpc;
+use agglayer_primitives::{address, Digest};
+use alloy_primitives::B256;
+use proposer_client::{
+    config::ProposerClientConfig,
+    rpc::{AggregationProofProposer as _, AggregationProofProposerRequest, ProposerRpcClient},
+    FepProposerRequest, GrpcUri,
+};
+use proposer_service::{config::ProposerServiceConfig, ProposerService};
+use tokio_util::sync::CancellationToken;
+use tower::Service as _;
+
+use crate::{ContractsOutputs, MockProposer};
+
+const LAST_PROVEN_BLOCK: u64 = 10;
+const END_BLOCK: u64 = 20;
+
+fn l2_output(byte: u8) -> L2OutputAtBlock {
+    L2OutputAtBlock {
+        state_root: Digest([byte; 32]),
+        withdrawal_storage_root: Digest([byte + 1; 32]),
+        ..Default::default()
+    }
+}
+
+fn fake_chain() -> FakeAggchainContractsClient {
+    FakeAggchainContractsClient::new()
+        .set_block(
+            LAST_PROVEN_BLOCK,
+            FakeL2Block::new(LAST_PROVEN_BLOCK).set_output(l2_output(1)),
+        )
+        .set_block(
+            END_BLOCK,
+            FakeL2Block::new(END_BLOCK).set_output(l2_output(3)),
+        )
+        .set_op_succinct_config(OpSuccinctConfig {
+            rollup_config_hash: Digest([5; 32]),
+            ..Default::default()
+        })
+        .set_trusted_sequencer(address!("0x0000000000000000000000000000000000000007"))
+}
+
+/// Serves a mock proposer over the `fake_chain`, until the returned guard is
+/// dropped.
+async fn serve_mock_proposer() -> (GrpcUri, tokio_util::sync::DropGuard) {
+    let proposer = MockProposer::new(ContractsOutputs::new(Arc::new(fake_chain())))
+        .await
+        .unwrap();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let uri = format!("http://{}", listener.local_addr().unwrap())
+        .parse()
+        .unwrap();
+
+    let cancellation_token = CancellationToken::new();
+    tokio::spawn(proposer.serve(listener, cancellation_token.clone().cancelled_owned()));
+
+    (uri, cancellation_token.drop_guard())
+}
+
+#[tokio::test]
+async fn proposer_service_gets_mock_aggregation_proofs() {
+    let (proposer_endpoint, _server) = serve_mock_proposer().await;
+
+    let mut l1_rpc = MockRpc::new();
+    l1_rpc.expect_get_block_number().returning(|_| Ok(100));
+
+    let config = ProposerServiceConfig {
+        mock: true,
+        client: ProposerClientConfig {
+            proposer_endpoint,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut proposer_service = ProposerService::new_mock(&config, Arc::new(l1_rpc))
+        .await
+        .unwrap();
+
+    let l1_block_hash = B256::repeat_byte(9);
+    let response = proposer_service
+        .call(FepProposerRequest {
+            last_proven_block: LAST_PROVEN_BLOCK,
+            requested_end_block: END_BLOCK,
+            l1_block_hash,
+        })
+        .await
+        .unwrap();
+
+    let public_values = response.public_values;
+    assert_eq!(response.end_block, END_BLOCK);
+    assert_eq!(public_values.l1_head, l1_block_hash);
+    assert_eq!(
+        public_values.l2_pre_root,
+        B256::from(compute_output_root(
+            [1; 32],
+            [2; 32],
+            FakeL2Block::new(LAST_PROVEN_BLOCK).hash.0
+        ))
+    );
+    assert_eq!(
+        public_values.l2_post_root,
+        B256::from(compute_output_root(
+            [3; 32],
+            [4; 32],
+            FakeL2Block::new(END_BLOCK).hash.0
+        ))
+    );
+    assert_eq!(public_values.l2_block_number, END_BLOCK);
+    assert_eq!(public_values.rollup_config_hash, B256::repeat_byte(5));
+    assert_eq!(
+        public_values.multi_block_vkey,
+        B256::from(proposer_elfs::range::commitment())
+    );
+}
+
+#[tokio::test]
+async fn empty_block_ranges_are_rejected() {
+    let (proposer_endpoint, _server) = serve_mock_proposer().await;
+
+    let client = ProposerRpcClient::new(proposer_endpoint, Duration::from_secs(5)).unwrap();
+
+    let result = client
+        .request_agg_proof(AggregationProofProposerRequest {
+            last_proven_block: END_BLOCK,
+            requested_end_block: END_BLOCK,
+            l1_block_number: 100,
+            l1_block_hash: B256::ZERO,
+        })
+        .await;
+
+    assert!(result.is_err());
+}
+
+fn agg_proof_request(l1_block_hash: B256) -> AggregationProofProposerRequest {
+    AggregationProofProposerRequest {
+        last_proven_block: LAST_PROVEN_BLOCK,
+        requested_end_block: END_BLOCK,
+        l1_block_number: 100,
+        l1_block_hash,
+    }
+}
+
+#[tokio::test]
+async fn pending_requests_are_found() {
+    let (proposer_endpoint, _server) = serve_mock_proposer().await;
+
+    let client = ProposerRpcClient::new(proposer_endpoint, Duration::from_secs(5)).unwrap();
+
+    let request = agg_proof_request(B256::repeat_byte(9));
+    let response = client.request_agg_proof(request.clone()).await.unwrap();
+
+    let found = client
+        .find_agg_proof(&request)
+        .await
+        .unwrap()
+        .expect("pending request");
+    assert_eq!(found.request_id, response.request_id);
+    assert_eq!(found.last_proven_block, LAST_PROVEN_BLOCK);
+    assert_eq!(found.end_block, END_BLOCK);
+
+    let unknown = client
+        .find_agg_proof(&agg_proof_request(B256::repeat_byte(8)))
+        .await
+        .unwrap();
+    assert!(unknown.is_none());
+}
+
+#[tokio::test]
+async fn cancelled_requests_are_forgotten() {
+    let (proposer_endpoint, _server) = serve_mock_proposer().await;
+
+    let client = ProposerRpcClient::new(proposer_endpoint, Duration::from_secs(5)).unwrap();
+
+    let request = agg_proof_request(B256::repeat_byte(9));
+    let response = client.request_agg_proof(request.clone()).await.unwrap();
+
+    assert!(client.cancel_agg_proof(&response).await.unwrap());
+    assert!(client.find_agg_proof(&request).await.unwrap().is_none());
+    assert!(!client.cancel_agg_proof(&response).await.unwrap());
+}
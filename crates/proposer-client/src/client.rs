@@ -1,10 +1,19 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
+use alloy_primitives::B256;
 use educe::Educe;
+use prover_config::CancellationPolicy;
 use sp1_sdk::{SP1ProofWithPublicValues, SP1VerifyingKey};
+use tokio::sync::OnceCell;
+use tracing::{info, warn};
 
 use crate::{
     aggregation_prover::AggregationProver,
+    config::default_request_reuse_timeout,
     error,
     rpc::{
         AggregationProofProposer, AggregationProofProposerRequest, AggregationProofProposerResponse,
@@ -21,12 +30,53 @@ use crate::{
 /// request creation of the AggregationProof (getting the proof ID in return),
 /// and directly communicates with the SP1 cluster using NetworkProver
 /// to retrieve the generated proof.
+///
+/// The requests submitted to the proposer are remembered for the
+/// `request_reuse_timeout`, so that a retry of the same request waits for the
+/// proof already being generated instead of starting a new aggregation. The
+/// concurrent calls for the same request wait for the first one to submit it.
+/// A request is forgotten as soon as its proof fails, or once abandoned under
+/// the [`CancellationPolicy::Cancel`] policy.
 #[derive(Educe)]
 #[educe(Clone(bound()))]
 pub struct Client<Proposer, Prover> {
     proposer_rpc: Arc<Proposer>,
     prover_rpc: Arc<Prover>,
     proving_timeout: Option<Duration>,
+    request_reuse_timeout: Duration,
+    cancellation_policy: CancellationPolicy,
+    submitted_requests: Arc<Mutex<HashMap<RequestKey, SubmittedRequest>>>,
+}
+
+/// Aggregation proof request, as identified for its reuse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct RequestKey {
+    last_proven_block: u64,
+    requested_end_block: u64,
+    l1_block_hash: B256,
+}
+
+impl From<&AggregationProofProposerRequest> for RequestKey {
+    fn from(request: &AggregationProofProposerRequest) -> Self {
+        Self {
+            last_proven_block: request.last_proven_block,
+            requested_end_block: request.requested_end_block,
+            l1_block_hash: request.l1_block_hash,
+        }
+    }
+}
+
+/// Request submitted to the proposer, or being submitted while its response
+/// is not set.
+struct SubmittedRequest {
+    response: Arc<OnceCell<AggregationProofProposerResponse>>,
+    submitted_at: Instant,
+}
+
+impl SubmittedRequest {
+    fn request_id(&self) -> Option<&RequestId> {
+        self.response.get().map(|response| &response.request_id)
+    }
 }
 
 impl<Proposer, Prover> Client<Proposer, Prover> {
@@ -40,21 +90,114 @@ impl<Proposer, Prover> Client<Proposer, Prover> {
             proposer_rpc: proposer,
             prover_rpc: Arc::new(prover),
             proving_timeout,
+            request_reuse_timeout: default_request_reuse_timeout(),
+            cancellation_policy: CancellationPolicy::default(),
+            submitted_requests: Arc::default(),
         })
     }
+
+    /// Sets how long a submitted request is reused, zero disables the reuse.
+    pub fn set_request_reuse_timeout(mut self, request_reuse_timeout: Duration) -> Self {
+        self.request_reuse_timeout = request_reuse_timeout;
+        self
+    }
+
+    /// Sets what happens to the requests abandoned by their callers.
+    pub fn set_cancellation_policy(mut self, cancellation_policy: CancellationPolicy) -> Self {
+        self.cancellation_policy = cancellation_policy;
+        self
+    }
+
+    fn submitted_requests(
+        &self,
+    ) -> std::sync::MutexGuard<'_, HashMap<RequestKey, SubmittedRequest>> {
+        self.submitted_requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Response of the still valid request submitted for `key`, set once
+    /// submitted. A new entry is made when there is none.
+    fn submitted_request(
+        &self,
+        key: RequestKey,
+    ) -> Arc<OnceCell<AggregationProofProposerResponse>> {
+        let mut submitted_requests = self.submitted_requests();
+        submitted_requests.retain(|_, request| {
+            !request.response.initialized()
+                || request.submitted_at.elapsed() < self.request_reuse_timeout
+        });
+
+        submitted_requests
+            .entry(key)
+            .or_insert_with(|| SubmittedRequest {
+                response: Arc::default(),
+                submitted_at: Instant::now(),
+            })
+            .response
+            .clone()
+    }
+
+    /// Forgets the entry of `key` once its submission failed, unless another
+    /// call submitted the request since or a new entry took its place.
+    fn forget_failed(
+        &self,
+        key: &RequestKey,
+        response: &Arc<OnceCell<AggregationProofProposerResponse>>,
+    ) {
+        let mut submitted_requests = self.submitted_requests();
+        if submitted_requests.get(key).is_some_and(|request| {
+            Arc::ptr_eq(&request.response, response) && !response.initialized()
+        }) {
+            submitted_requests.remove(key);
+        }
+    }
+
+    fn forget(&self, request_id: &RequestId) {
+        self.submitted_requests()
+            .retain(|_, request| request.request_id() != Some(request_id));
+    }
 }
 
 #[async_trait::async_trait]
 impl<Proposer, Prover> ProposerClient for Client<Proposer, Prover>
 where
-    Proposer: AggregationProofProposer + Sync + Send,
+    Proposer: AggregationProofProposer + Sync + Send + 'static,
     Prover: AggregationProver + Sync + Send,
 {
     async fn request_agg_proof(
         &self,
         request: AggregationProofProposerRequest,
     ) -> Result<AggregationProofProposerResponse, Error> {
-        self.proposer_rpc.request_agg_proof(request).await
+        if self.request_reuse_timeout.is_zero() {
+            return self.proposer_rpc.request_agg_proof(request).await;
+        }
+
+        let key = RequestKey::from(&request);
+        let submitted_request = self.submitted_request(key);
+        if let Some(response) = submitted_request.get() {
+            info!(%response, "Reusing the submitted agg proof request");
+            return Ok(response.clone());
+        }
+
+        let response = submitted_request
+            .get_or_try_init(|| async move {
+                match self.proposer_rpc.find_agg_proof(&request).await {
+                    Ok(Some(response)) => Ok(response),
+                    Ok(None) => self.proposer_rpc.request_agg_proof(request).await,
+                    Err(error) => {
+                        warn!(
+                            ?error,
+                            "Unable to look up the agg proof requests of the proposer"
+                        );
+                        self.proposer_rpc.request_agg_proof(request).await
+                    }
+                }
+            })
+            .await
+            .inspect_err(|_| self.forget_failed(&key, &submitted_request))?;
+
+        Ok(response.clone())
     }
 
     async fn wait_for_proof(
@@ -64,6 +207,7 @@ where
         self.prover_rpc
             .wait_for_proof(request_id.0, self.proving_timeout)
             .await
+            .inspect_err(|_| self.forget(&request_id))
             .map_err(|e| Error::Proving(request_id, e.to_string()))
     }
 
@@ -75,6 +219,45 @@ where
     ) -> Result<(), Error> {
         self.prover_rpc
             .verify_aggregated_proof(proof, vkey)
+            .inspect_err(|_| self.forget(&request_id))
             .map_err(|source| Error::Verification { request_id, source })
     }
+
+    async fn check_connectivity(&self) -> Result<(), Error> {
+        self.proposer_rpc.check_connectivity().await
+    }
+
+    fn discard_request(&self, request_id: &RequestId) {
+        self.forget(request_id);
+    }
+
+    fn abandon_request(&self, response: &AggregationProofProposerResponse) {
+        if self.cancellation_policy == CancellationPolicy::FinishAndCache {
+            info!(%response, "Agg proof request abandoned, left to finish for its next request");
+            return;
+        }
+
+        self.cancel_request(response);
+    }
+
+    fn cancel_request(&self, response: &AggregationProofProposerResponse) {
+        self.forget(&response.request_id);
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            warn!(%response, "Agg proof request cancelled outside of a runtime, not sent");
+            return;
+        };
+
+        let proposer_rpc = self.proposer_rpc.clone();
+        let response = response.clone();
+        runtime.spawn(async move {
+            match proposer_rpc.cancel_agg_proof(&response).await {
+                Ok(true) => {}
+                Ok(false) => warn!(
+                    %response,
+                    "Agg proof request not cancelled, the proposer cannot cancel it"
+                ),
+                Err(error) => warn!(%response, ?error, "Unable to cancel the agg proof request"),
+            }
+        });
+    }
 }
@@ -1,8 +1,9 @@
-use std::{str::FromStr, time::Duration};
+use std::{path::PathBuf, str::FromStr, time::Duration};
 
+use prover_config::{CancellationPolicy, NetworkKeyConfig};
 use prover_utils::from_env_or_default;
 use serde::{Deserialize, Serialize};
-use serde_with::{serde_as, DisplayFromStr, DurationSeconds};
+use serde_with::{serde_as, DisplayFromStr, DurationMilliSeconds, DurationSeconds};
 use url::Url;
 
 use crate::GrpcUri;
@@ -22,10 +23,21 @@ pub struct ProposerClientConfig {
     #[serde_as(as = "DisplayFromStr")]
     pub proposer_endpoint: GrpcUri,
 
+    /// The proposer endpoints failed over to, in order, when the proposer
+    /// endpoint is unavailable.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub fallback_proposer_endpoints: Vec<GrpcUri>,
+
     /// The sp1 proving cluster endpoint.
     #[serde(default = "default_sp1_cluster_endpoint")]
     pub sp1_cluster_endpoint: Url,
 
+    /// Where the key signing the requests to the sp1 proving cluster is read
+    /// from.
+    #[serde(default, skip_serializing_if = "NetworkKeyConfig::is_default")]
+    pub private_key: NetworkKeyConfig,
+
     /// Proposer request timeout in seconds.
     #[serde(default = "default_request_timeout")]
     #[serde_as(as = "DurationSeconds<u64>")]
@@ -35,15 +47,141 @@ pub struct ProposerClientConfig {
     #[serde(default = "default_proving_timeout")]
     #[serde_as(as = "DurationSeconds<u64>")]
     pub proving_timeout: Duration,
+
+    /// How long a proof request submitted to the proposer is reused for the
+    /// requests of the same range and l1 block, in seconds. Zero disables the
+    /// reuse.
+    #[serde(default = "default_request_reuse_timeout")]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub request_reuse_timeout: Duration,
+
+    /// What happens to an aggregation proof request once its caller gives up
+    /// on it. Left to finish, it is reused for the `request-reuse-timeout`.
+    #[serde(default, skip_serializing_if = "CancellationPolicy::is_default")]
+    pub cancellation_policy: CancellationPolicy,
+
+    /// Timeout of the connection to a proposer endpoint, in seconds.
+    #[serde(
+        default = "default_connect_timeout",
+        skip_serializing_if = "same_as_default_connect_timeout"
+    )]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub connect_timeout: Duration,
+
+    /// Interval of the HTTP/2 keepalive pings sent to the proposer, in
+    /// seconds. Zero disables the pings.
+    #[serde(
+        default = "default_keepalive_interval",
+        skip_serializing_if = "same_as_default_keepalive_interval"
+    )]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub keepalive_interval: Duration,
+
+    /// How long a keepalive ping waits for its acknowledgement before the
+    /// connection is closed, in seconds.
+    #[serde(
+        default = "default_keepalive_timeout",
+        skip_serializing_if = "same_as_default_keepalive_timeout"
+    )]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub keepalive_timeout: Duration,
+
+    /// Maximum size of a message received from the proposer, in bytes.
+    #[serde(
+        default = "default_max_decoding_message_size",
+        skip_serializing_if = "same_as_default_max_decoding_message_size"
+    )]
+    pub max_decoding_message_size: usize,
+
+    /// Maximum size of a message sent to the proposer, in bytes.
+    #[serde(
+        default = "default_max_encoding_message_size",
+        skip_serializing_if = "same_as_default_max_encoding_message_size"
+    )]
+    pub max_encoding_message_size: usize,
+
+    /// Number of retries of an aggregation proof request refused as
+    /// unavailable or resource exhausted.
+    #[serde(
+        default = "default_max_retries",
+        skip_serializing_if = "same_as_default_max_retries"
+    )]
+    pub max_retries: usize,
+
+    /// Delay before the first retry, in milliseconds. The delay is doubled
+    /// after each failed attempt.
+    #[serde(
+        default = "default_initial_backoff",
+        skip_serializing_if = "same_as_default_initial_backoff"
+    )]
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub initial_backoff: Duration,
+
+    /// Upper bound of the delay between two attempts, in milliseconds.
+    #[serde(
+        default = "default_max_backoff",
+        skip_serializing_if = "same_as_default_max_backoff"
+    )]
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub max_backoff: Duration,
+
+    /// TLS settings of the connections to the proposer. The https endpoints
+    /// trust the native roots if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<ProposerTlsConfig>,
+}
+
+/// TLS settings of the connections to the proposer.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProposerTlsConfig {
+    /// PEM file of the certificate authority of the proposer. The native
+    /// roots are trusted if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_certificate: Option<PathBuf>,
+
+    /// PEM file of the client certificate, presented for mutual TLS along
+    /// with the client key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_certificate: Option<PathBuf>,
+
+    /// PEM file of the client key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key: Option<PathBuf>,
+
+    /// Name checked against the proposer certificate, the host of the
+    /// endpoint if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<String>,
+}
+
+impl ProposerClientConfig {
+    /// The proposer endpoints, in order of preference.
+    pub fn proposer_endpoints(&self) -> impl Iterator<Item = &GrpcUri> {
+        std::iter::once(&self.proposer_endpoint).chain(&self.fallback_proposer_endpoints)
+    }
 }
 
 impl Default for ProposerClientConfig {
     fn default() -> Self {
         Self {
             proposer_endpoint: default_proposer_service_endpoint(),
+            fallback_proposer_endpoints: Vec::new(),
             sp1_cluster_endpoint: default_sp1_cluster_endpoint(),
+            private_key: NetworkKeyConfig::default(),
             request_timeout: default_request_timeout(),
             proving_timeout: default_proving_timeout(),
+            request_reuse_timeout: default_request_reuse_timeout(),
+            cancellation_policy: CancellationPolicy::default(),
+            connect_timeout: default_connect_timeout(),
+            keepalive_interval: default_keepalive_interval(),
+            keepalive_timeout: default_keepalive_timeout(),
+            max_decoding_message_size: default_max_decoding_message_size(),
+            max_encoding_message_size: default_max_encoding_message_size(),
+            max_retries: default_max_retries(),
+            initial_backoff: default_initial_backoff(),
+            max_backoff: default_max_backoff(),
+            tls: None,
         }
     }
 }
@@ -69,3 +207,71 @@ pub fn default_request_timeout() -> Duration {
 pub fn default_proving_timeout() -> Duration {
     Duration::from_secs(3600)
 }
+
+pub fn default_request_reuse_timeout() -> Duration {
+    Duration::from_secs(3600)
+}
+
+const fn default_connect_timeout() -> Duration {
+    Duration::from_secs(10)
+}
+
+fn same_as_default_connect_timeout(value: &Duration) -> bool {
+    *value == default_connect_timeout()
+}
+
+const fn default_keepalive_interval() -> Duration {
+    Duration::from_secs(30)
+}
+
+fn same_as_default_keepalive_interval(value: &Duration) -> bool {
+    *value == default_keepalive_interval()
+}
+
+const fn default_keepalive_timeout() -> Duration {
+    Duration::from_secs(10)
+}
+
+fn same_as_default_keepalive_timeout(value: &Duration) -> bool {
+    *value == default_keepalive_timeout()
+}
+
+const fn default_max_decoding_message_size() -> usize {
+    64 * 1024 * 1024
+}
+
+fn same_as_default_max_decoding_message_size(value: &usize) -> bool {
+    *value == default_max_decoding_message_size()
+}
+
+const fn default_max_encoding_message_size() -> usize {
+    4 * 1024 * 1024
+}
+
+fn same_as_default_max_encoding_message_size(value: &usize) -> bool {
+    *value == default_max_encoding_message_size()
+}
+
+const fn default_max_retries() -> usize {
+    3
+}
+
+fn same_as_default_max_retries(value: &usize) -> bool {
+    *value == default_max_retries()
+}
+
+const fn default_initial_backoff() -> Duration {
+    Duration::from_secs(1)
+}
+
+fn same_as_default_initial_backoff(value: &Duration) -> bool {
+    *value == default_initial_backoff()
+}
+
+const fn default_max_backoff() -> Duration {
+    Duration::from_secs(30)
+}
+
+fn same_as_default_max_backoff(value: &Duration) -> bool {
+    *value == default_max_backoff()
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::RequestId;
 
 #[derive(Debug, thiserror::Error)]
@@ -16,6 +18,15 @@ pub enum Error {
 
     #[error("Error initializing grpc connection")]
     Connect(#[source] tonic::transport::Error),
+
+    #[error("Unable to read the TLS file {}", path.display())]
+    TlsFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Invalid TLS config: {0}")]
+    TlsConfig(&'static str),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -36,9 +36,20 @@ pub struct GetMockProofResponse {
     #[prost(bytes="bytes", tag="1")]
     pub proof: ::prost::bytes::Bytes,
 }
+/// Request message for CancelAggProof
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelAggProofRequest {
+    /// Id of the aggregation proof request to cancel
+    #[prost(bytes="bytes", tag="1")]
+    pub proof_request_id: ::prost::bytes::Bytes,
+}
+/// Response message for CancelAggProof
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CancelAggProofResponse {
+}
 /// Encoded file descriptor set for the `proofs` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xe9, 0x0d, 0x0a, 0x0c, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+    0x0a, 0xa1, 0x13, 0x0a, 0x0c, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74,
     0x6f, 0x12, 0x06, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x22, 0xb9, 0x01, 0x0a, 0x0f, 0x41, 0x67,
     0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x2a, 0x0a,
     0x11, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x62, 0x6c, 0x6f,
@@ -65,25 +76,40 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x18, 0x01, 0x20, 0x01, 0x28, 0x03, 0x52, 0x07, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x49, 0x64, 0x22,
     0x2c, 0x0a, 0x14, 0x47, 0x65, 0x74, 0x4d, 0x6f, 0x63, 0x6b, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52,
     0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x70, 0x72, 0x6f, 0x6f, 0x66,
-    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x32, 0x99, 0x01,
-    0x0a, 0x06, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x12, 0x44, 0x0a, 0x0f, 0x52, 0x65, 0x71, 0x75,
-    0x65, 0x73, 0x74, 0x41, 0x67, 0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x17, 0x2e, 0x70, 0x72,
-    0x6f, 0x6f, 0x66, 0x73, 0x2e, 0x41, 0x67, 0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71,
-    0x75, 0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x2e, 0x41, 0x67,
-    0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x49,
-    0x0a, 0x0c, 0x47, 0x65, 0x74, 0x4d, 0x6f, 0x63, 0x6b, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x1b,
-    0x2e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x2e, 0x47, 0x65, 0x74, 0x4d, 0x6f, 0x63, 0x6b, 0x50,
-    0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x22, 0x41, 0x0a,
+    0x15, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x67, 0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x28, 0x0a, 0x10, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c,
+    0x52, 0x0e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49, 0x64,
+    0x22, 0x18, 0x0a, 0x16, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x67, 0x67, 0x50, 0x72, 0x6f,
+    0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x32, 0xac, 0x02, 0x0a, 0x06, 0x50,
+    0x72, 0x6f, 0x6f, 0x66, 0x73, 0x12, 0x44, 0x0a, 0x0f, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x41, 0x67, 0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x6f, 0x66,
+    0x73, 0x2e, 0x41, 0x67, 0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x1a, 0x18, 0x2e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x2e, 0x41, 0x67, 0x67, 0x50, 0x72,
+    0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x49, 0x0a, 0x0c, 0x47,
+    0x65, 0x74, 0x4d, 0x6f, 0x63, 0x6b, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x1b, 0x2e, 0x70, 0x72,
     0x6f, 0x6f, 0x66, 0x73, 0x2e, 0x47, 0x65, 0x74, 0x4d, 0x6f, 0x63, 0x6b, 0x50, 0x72, 0x6f, 0x6f,
+    0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x70, 0x72, 0x6f, 0x6f, 0x66,
+    0x73, 0x2e, 0x47, 0x65, 0x74, 0x4d, 0x6f, 0x63, 0x6b, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x40, 0x0a, 0x0b, 0x47, 0x65, 0x74, 0x41, 0x67, 0x67,
+    0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x17, 0x2e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x2e, 0x41,
+    0x67, 0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x18,
+    0x2e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x2e, 0x41, 0x67, 0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4f, 0x0a, 0x0e, 0x43, 0x61, 0x6e, 0x63,
+    0x65, 0x6c, 0x41, 0x67, 0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x1d, 0x2e, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x73, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x67, 0x67, 0x50, 0x72, 0x6f,
+    0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1e, 0x2e, 0x70, 0x72, 0x6f, 0x6f,
+    0x66, 0x73, 0x2e, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x67, 0x67, 0x50, 0x72, 0x6f, 0x6f,
     0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x51, 0x0a, 0x0a, 0x63, 0x6f, 0x6d,
     0x2e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x42, 0x0b, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x50,
     0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0xa2, 0x02, 0x03, 0x50, 0x58, 0x58, 0xaa, 0x02, 0x06, 0x50,
     0x72, 0x6f, 0x6f, 0x66, 0x73, 0xca, 0x02, 0x06, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0xe2, 0x02,
     0x12, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64,
-    0x61, 0x74, 0x61, 0xea, 0x02, 0x06, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x4a, 0xb5, 0x08, 0x0a,
-    0x06, 0x12, 0x04, 0x00, 0x00, 0x23, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00,
+    0x61, 0x74, 0x61, 0xea, 0x02, 0x06, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x73, 0x4a, 0xfd, 0x0b, 0x0a,
+    0x06, 0x12, 0x04, 0x00, 0x00, 0x32, 0x21, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00,
     0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x01, 0x00, 0x0f, 0x0a, 0x28, 0x0a, 0x02, 0x06,
-    0x00, 0x12, 0x04, 0x04, 0x00, 0x0a, 0x01, 0x1a, 0x1c, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63,
+    0x00, 0x12, 0x04, 0x04, 0x00, 0x10, 0x01, 0x1a, 0x1c, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63,
     0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x71, 0x75,
     0x65, 0x73, 0x74, 0x73, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08,
     0x0e, 0x0a, 0x2b, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x06, 0x02, 0x42, 0x1a, 0x1e,
@@ -97,58 +123,87 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x62, 0x79, 0x20, 0x69, 0x74, 0x73, 0x20, 0x49, 0x44,
     0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x09, 0x06, 0x12, 0x0a,
     0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x09, 0x13, 0x26, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x09, 0x31, 0x45, 0x0a, 0x2e, 0x0a, 0x02, 0x04,
-    0x00, 0x12, 0x04, 0x0d, 0x00, 0x12, 0x01, 0x1a, 0x22, 0x20, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-    0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
-    0x00, 0x01, 0x12, 0x03, 0x0d, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12,
-    0x03, 0x0e, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0e,
-    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x09, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0e, 0x1d, 0x1e, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x0f, 0x09, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x0f, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x10,
-    0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x10, 0x02, 0x08,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x10, 0x09, 0x18, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x10, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x11, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x03, 0x05, 0x12, 0x03, 0x11, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01,
-    0x12, 0x03, 0x11, 0x09, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03,
-    0x11, 0x19, 0x1a, 0x0a, 0x38, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x15, 0x00, 0x19, 0x01, 0x1a,
-    0x2c, 0x20, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20,
-    0x61, 0x6e, 0x20, 0x61, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x70,
-    0x72, 0x6f, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x15, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
-    0x00, 0x12, 0x03, 0x16, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x16, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x16,
-    0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x1d, 0x1e,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x17, 0x02, 0x17, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x17, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x17, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12,
-    0x03, 0x18, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x18,
-    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x18, 0x08, 0x18,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x18, 0x1b, 0x1c, 0x0a, 0x2e,
-    0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x1c, 0x00, 0x1e, 0x01, 0x1a, 0x22, 0x20, 0x52, 0x65, 0x71,
-    0x75, 0x65, 0x73, 0x74, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x66, 0x6f, 0x72,
+    0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x09, 0x31, 0x45, 0x0a, 0x5a, 0x0a, 0x04, 0x06,
+    0x00, 0x02, 0x02, 0x12, 0x03, 0x0c, 0x02, 0x3e, 0x1a, 0x4d, 0x20, 0x4c, 0x6f, 0x6f, 0x6b, 0x20,
+    0x75, 0x70, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x20, 0x6d, 0x61, 0x64, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61,
+    0x6d, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x6c, 0x31,
+    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x0c, 0x06, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03,
+    0x0c, 0x12, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0c, 0x2c,
+    0x3c, 0x0a, 0x32, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x03, 0x12, 0x03, 0x0f, 0x02, 0x4d, 0x1a, 0x25,
+    0x20, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x67, 0x67, 0x72, 0x65,
+    0x67, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x0f, 0x06, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x0f, 0x15,
+    0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x0f, 0x35, 0x4b, 0x0a,
+    0x2e, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x13, 0x00, 0x18, 0x01, 0x1a, 0x22, 0x20, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x67, 0x67,
+    0x72, 0x65, 0x67, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x0a, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x13, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x00, 0x12, 0x03, 0x14, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x14, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x14, 0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x14,
+    0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x15, 0x02, 0x21, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x15, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x15, 0x09, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x15, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x02, 0x12, 0x03, 0x16, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x16, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x16,
+    0x09, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x16, 0x1b, 0x1c,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x17, 0x02, 0x1b, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x17, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x17, 0x09, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x03, 0x03, 0x12, 0x03, 0x17, 0x19, 0x1a, 0x0a, 0x38, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x1b,
+    0x00, 0x1f, 0x01, 0x1a, 0x2c, 0x20, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x66,
+    0x72, 0x6f, 0x6d, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x1b, 0x08, 0x18, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1c, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x1c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x1c, 0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x1c, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x1d, 0x02,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x1d, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x1d, 0x09, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x1d, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x01, 0x02, 0x02, 0x12, 0x03, 0x1e, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
+    0x05, 0x12, 0x03, 0x1e, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x1e, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1e,
+    0x1b, 0x1c, 0x0a, 0x2e, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x22, 0x00, 0x24, 0x01, 0x1a, 0x22,
+    0x20, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x47, 0x65, 0x74, 0x4d, 0x6f, 0x63, 0x6b, 0x50, 0x72, 0x6f, 0x6f,
+    0x66, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x22, 0x08, 0x1b, 0x0a, 0x33,
+    0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x23, 0x02, 0x15, 0x22, 0x26, 0x20, 0x54, 0x68,
+    0x65, 0x20, 0x49, 0x44, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x6f, 0x63, 0x6b,
+    0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x72, 0x69, 0x65,
+    0x76, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x23, 0x02,
+    0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x23, 0x08, 0x10, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x23, 0x13, 0x14, 0x0a, 0x2f, 0x0a,
+    0x02, 0x04, 0x03, 0x12, 0x04, 0x27, 0x00, 0x29, 0x01, 0x1a, 0x23, 0x20, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x66, 0x6f, 0x72,
     0x20, 0x47, 0x65, 0x74, 0x4d, 0x6f, 0x63, 0x6b, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x0a, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1c, 0x08, 0x1b, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x02,
-    0x02, 0x00, 0x12, 0x03, 0x1d, 0x02, 0x15, 0x22, 0x26, 0x20, 0x54, 0x68, 0x65, 0x20, 0x49, 0x44,
-    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x6f, 0x63, 0x6b, 0x20, 0x70, 0x72, 0x6f,
-    0x6f, 0x66, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x72, 0x69, 0x65, 0x76, 0x65, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1d, 0x02, 0x07, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1d, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1d, 0x13, 0x14, 0x0a, 0x2f, 0x0a, 0x02, 0x04, 0x03, 0x12,
-    0x04, 0x21, 0x00, 0x23, 0x01, 0x1a, 0x23, 0x20, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
-    0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x47, 0x65, 0x74,
-    0x4d, 0x6f, 0x63, 0x6b, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03,
-    0x01, 0x12, 0x03, 0x21, 0x08, 0x1c, 0x0a, 0x22, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03,
-    0x22, 0x02, 0x12, 0x22, 0x15, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6d, 0x6f, 0x63, 0x6b, 0x20, 0x70,
-    0x72, 0x6f, 0x6f, 0x66, 0x20, 0x64, 0x61, 0x74, 0x61, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x22, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x22, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x22, 0x10, 0x11, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x27, 0x08, 0x1c, 0x0a, 0x22, 0x0a, 0x04, 0x04, 0x03,
+    0x02, 0x00, 0x12, 0x03, 0x28, 0x02, 0x12, 0x22, 0x15, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6d, 0x6f,
+    0x63, 0x6b, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x64, 0x61, 0x74, 0x61, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x28, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x28, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x28, 0x10, 0x11, 0x0a, 0x30, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04,
+    0x2c, 0x00, 0x2f, 0x01, 0x1a, 0x24, 0x20, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x6d,
+    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x43, 0x61, 0x6e, 0x63, 0x65,
+    0x6c, 0x41, 0x67, 0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04,
+    0x01, 0x12, 0x03, 0x2c, 0x08, 0x1d, 0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03,
+    0x2e, 0x02, 0x1d, 0x1a, 0x2f, 0x20, 0x49, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x61, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f,
+    0x66, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x63, 0x61, 0x6e,
+    0x63, 0x65, 0x6c, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2e,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2e, 0x08, 0x18,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2e, 0x1b, 0x1c, 0x0a, 0x30,
+    0x0a, 0x02, 0x04, 0x05, 0x12, 0x03, 0x32, 0x00, 0x21, 0x1a, 0x25, 0x20, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x41, 0x67, 0x67, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x0a,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x32, 0x08, 0x1e, 0x62, 0x06, 0x70, 0x72,
+    0x6f, 0x74, 0x6f, 0x33,
 ];
 include!("proofs.serde.rs");
 include!("proofs.tonic.rs");
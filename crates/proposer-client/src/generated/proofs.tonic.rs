@@ -138,6 +138,54 @@ pub mod proofs_client {
                 .insert(GrpcMethod::new("proofs.Proofs", "GetMockProof"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_agg_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AggProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AggProofResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proofs.Proofs/GetAggProof",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("proofs.Proofs", "GetAggProof"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn cancel_agg_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CancelAggProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CancelAggProofResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/proofs.Proofs/CancelAggProof",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("proofs.Proofs", "CancelAggProof"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -167,6 +215,20 @@ pub mod proofs_server {
             tonic::Response<super::GetMockProofResponse>,
             tonic::Status,
         >;
+        async fn get_agg_proof(
+            &self,
+            request: tonic::Request<super::AggProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AggProofResponse>,
+            tonic::Status,
+        >;
+        async fn cancel_agg_proof(
+            &self,
+            request: tonic::Request<super::CancelAggProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CancelAggProofResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct ProofsServer<T> {
@@ -332,6 +394,94 @@ pub mod proofs_server {
                     };
                     Box::pin(fut)
                 }
+                "/proofs.Proofs/GetAggProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAggProofSvc<T: Proofs>(pub Arc<T>);
+                    impl<T: Proofs> tonic::server::UnaryService<super::AggProofRequest>
+                    for GetAggProofSvc<T> {
+                        type Response = super::AggProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AggProofRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Proofs>::get_agg_proof(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetAggProofSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/proofs.Proofs/CancelAggProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct CancelAggProofSvc<T: Proofs>(pub Arc<T>);
+                    impl<
+                        T: Proofs,
+                    > tonic::server::UnaryService<super::CancelAggProofRequest>
+                    for CancelAggProofSvc<T> {
+                        type Response = super::CancelAggProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CancelAggProofRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Proofs>::cancel_agg_proof(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CancelAggProofSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -41,6 +41,24 @@ pub trait ProposerClient {
         proof: &SP1ProofWithPublicValues,
         vkey: &SP1VerifyingKey,
     ) -> Result<(), Error>;
+
+    /// Checks that the proposer can be reached.
+    async fn check_connectivity(&self) -> Result<(), Error>;
+
+    /// Discards the aggregation proof request `request_id`, so that it is not
+    /// reused by the next request of the same range.
+    fn discard_request(&self, request_id: &RequestId);
+
+    /// Cancels the aggregation proof request `response` on the proposer
+    /// whatever the cancellation policy, once its proof is known to be of no
+    /// use. The request is discarded as well.
+    fn cancel_request(&self, response: &AggregationProofProposerResponse);
+
+    /// Gives up on the aggregation proof request `response`, whose proof is
+    /// no longer waited for. Depending on the cancellation policy, the request
+    /// is cancelled on the proposer, or left to finish for the next request
+    /// of the same range.
+    fn abandon_request(&self, response: &AggregationProofProposerResponse);
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -2,7 +2,7 @@ use std::{panic::AssertUnwindSafe, time::Duration};
 
 use alloy_primitives::B256;
 use eyre::{eyre, Context};
-use prover_executor::{sp1_async, sp1_fast};
+use prover_executor::{network_key::NetworkKeySource, sp1_async, sp1_fast};
 use sp1_sdk::{
     NetworkProver, Prover, ProvingKey, SP1ProofWithPublicValues, SP1ProvingKey, SP1VerifyingKey,
 };
@@ -49,21 +49,13 @@ impl AggregationProver for NetworkProver {
     }
 }
 
-pub async fn new_network_prover<T: AsRef<str>>(endpoint: T) -> eyre::Result<NetworkProver> {
-    let endpoint = endpoint.as_ref().to_string();
-    let private_key = std::env::var("NETWORK_PRIVATE_KEY").context(
-        "Failed to get NETWORK_PRIVATE_KEY, when building NetworkProver for proposer-client",
-    )?;
-
-    sp1_async(AssertUnwindSafe(async move {
-        Ok::<_, eyre::Report>(
-            sp1_sdk::ProverClient::builder()
-                .network()
-                .rpc_url(&endpoint)
-                .private_key(&private_key)
-                .build()
-                .await,
-        )
-    }))
-    .await?
+/// Builds the client of the sp1 proving cluster at `endpoint`, signing its
+/// requests with the key of `key_source`.
+pub async fn new_network_prover<T: AsRef<str>>(
+    endpoint: T,
+    key_source: &dyn NetworkKeySource,
+) -> eyre::Result<NetworkProver> {
+    prover_executor::network_key::new_network_prover(endpoint.as_ref(), key_source)
+        .await
+        .context("Failed to build the network prover for proposer-client")
 }
@@ -1,10 +1,23 @@
-use std::{fmt::Display, time::Duration};
+use std::{
+    fmt::Display,
+    future::Future,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    time::Duration,
+};
 
 use alloy_primitives::B256;
-use tracing::{error, info};
+use prover_logger::propagation::{TraceContextLayer, TraceContextService};
+use tonic::{
+    transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity},
+    Code, Status,
+};
+use tower::Layer as _;
+use tracing::{error, info, warn};
 
 pub use crate::generated::proofs as grpc;
 use crate::{
+    config::{ProposerClientConfig, ProposerTlsConfig},
     error::{self, Error, ProofRequestError},
     GrpcUri, MockProofId, RequestId,
 };
@@ -13,6 +26,10 @@ mod proofs_service_types;
 
 use grpc::proofs_client::ProofsClient;
 
+/// Status codes of the aggregation proof requests refused for a transient
+/// reason, which are retried.
+const RETRYABLE_CODES: &[Code] = &[Code::Unavailable, Code::ResourceExhausted];
+
 /// Proposer client that requests the generation
 /// of the aggregation proof from the proposer and gets
 /// request_id in response.
@@ -23,10 +40,33 @@ pub trait AggregationProofProposer {
         request: AggregationProofProposerRequest,
     ) -> Result<AggregationProofProposerResponse, Error>;
 
+    /// Looks up the aggregation proof request the proposer already has for
+    /// the same range and l1 block. Returns `None` when there is none, or when
+    /// the proposer cannot be queried for it.
+    async fn find_agg_proof(
+        &self,
+        _request: &AggregationProofProposerRequest,
+    ) -> Result<Option<AggregationProofProposerResponse>, Error> {
+        Ok(None)
+    }
+
+    /// Cancels the aggregation proof request `response` on the proposer.
+    /// Returns whether it was cancelled, which never happens with the
+    /// proposers that cannot cancel their requests.
+    async fn cancel_agg_proof(
+        &self,
+        _response: &AggregationProofProposerResponse,
+    ) -> Result<bool, Error> {
+        Ok(false)
+    }
+
     async fn get_mock_proof(
         &self,
         request: MockProofProposerRequest,
     ) -> Result<MockProofProposerResponse, Error>;
+
+    /// Checks that the proposer can be reached.
+    async fn check_connectivity(&self) -> Result<(), Error>;
 }
 
 /// Request format for the proposer `proofs_requestAggProof`
@@ -82,22 +122,230 @@ pub struct MockProofProposerResponse {
     pub proof: Vec<u8>,
 }
 
+/// Proposer endpoint, along with the client over its channel.
+#[derive(Clone)]
+struct ProposerEndpoint {
+    uri: GrpcUri,
+    endpoint: Endpoint,
+    client: ProofsClient<TraceContextService<Channel>>,
+}
+
+impl ProposerEndpoint {
+    /// Builds the channel to `uri`, connected on its first request and
+    /// reconnected whenever the connection is lost.
+    #[allow(clippy::result_large_err)]
+    fn new(uri: GrpcUri, config: &ProposerClientConfig) -> Result<Self, Error> {
+        let mut endpoint = Channel::builder(uri.clone())
+            .timeout(config.request_timeout)
+            .connect_timeout(config.connect_timeout)
+            .keep_alive_timeout(config.keepalive_timeout)
+            .keep_alive_while_idle(true);
+        if !config.keepalive_interval.is_zero() {
+            endpoint = endpoint.http2_keep_alive_interval(config.keepalive_interval);
+        }
+        if config.tls.is_some() || uri.scheme_str() == Some("https") {
+            let tls_config = client_tls_config(&config.tls.clone().unwrap_or_default())?;
+            endpoint = endpoint.tls_config(tls_config).map_err(Error::Connect)?;
+        }
+
+        let channel = TraceContextLayer.layer(endpoint.connect_lazy());
+
+        Ok(Self {
+            uri,
+            endpoint,
+            client: ProofsClient::new(channel)
+                .max_decoding_message_size(config.max_decoding_message_size)
+                .max_encoding_message_size(config.max_encoding_message_size),
+        })
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn client_tls_config(config: &ProposerTlsConfig) -> Result<ClientTlsConfig, Error> {
+    let read = |path: &PathBuf| {
+        std::fs::read(path).map_err(|source| Error::TlsFile {
+            path: path.clone(),
+            source,
+        })
+    };
+
+    let mut tls_config = ClientTlsConfig::new();
+    tls_config = match &config.ca_certificate {
+        Some(path) => tls_config.ca_certificate(Certificate::from_pem(read(path)?)),
+        None => tls_config.with_native_roots(),
+    };
+    match (&config.client_certificate, &config.client_key) {
+        (Some(certificate), Some(key)) => {
+            tls_config = tls_config.identity(Identity::from_pem(read(certificate)?, read(key)?));
+        }
+        (Some(_), None) => {
+            return Err(Error::TlsConfig(
+                "client certificate set without a client key",
+            ))
+        }
+        (None, Some(_)) => {
+            return Err(Error::TlsConfig(
+                "client key set without a client certificate",
+            ))
+        }
+        (None, None) => {}
+    }
+    if let Some(domain_name) = &config.domain_name {
+        tls_config = tls_config.domain_name(domain_name);
+    }
+
+    Ok(tls_config)
+}
+
+/// gRPC client of the proposer.
+///
+/// The requests are sent to the active endpoint, which fails over to the
+/// next configured endpoint when it is unavailable. The aggregation proof
+/// requests refused for a transient reason are retried with an exponential
+/// backoff.
 pub struct ProposerRpcClient {
-    client: ProofsClient<tonic::transport::Channel>,
+    /// Endpoints of the proposer, in order of preference.
+    endpoints: Vec<ProposerEndpoint>,
+
+    /// Index of the endpoint the requests are sent to.
+    active: AtomicUsize,
+
+    max_retries: usize,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+
+    /// Whether the proposer may serve the query of the existing aggregation
+    /// proof requests, until it answers it is not implemented.
+    query_supported: AtomicBool,
+
+    /// Whether the proposer may cancel its aggregation proof requests, until
+    /// it answers it is not implemented.
+    cancel_supported: AtomicBool,
 }
 
 impl ProposerRpcClient {
-    pub async fn new(rpc_endpoint: GrpcUri, timeout: Duration) -> Result<Self, Error> {
-        // TODO: Configure various other limits besides timeout on the channel.
-        let channel = tonic::transport::Channel::builder(rpc_endpoint)
-            .timeout(timeout)
-            .connect()
-            .await
-            .inspect_err(|e| error!("Error connecting to proposer gRPC: {e}"))
-            .map_err(Error::Connect)?;
+    /// Creates a client of the proposer at `rpc_endpoint`, with the default
+    /// settings besides the request `timeout`.
+    #[allow(clippy::result_large_err)]
+    pub fn new(rpc_endpoint: GrpcUri, timeout: Duration) -> Result<Self, Error> {
+        Self::from_config(&ProposerClientConfig {
+            proposer_endpoint: rpc_endpoint,
+            request_timeout: timeout,
+            ..Default::default()
+        })
+    }
+
+    /// Creates a client of the configured proposer endpoints. Nothing is
+    /// connected until the first request, so that the proposer may be down.
+    #[allow(clippy::result_large_err)]
+    pub fn from_config(config: &ProposerClientConfig) -> Result<Self, Error> {
+        let endpoints = config
+            .proposer_endpoints()
+            .map(|uri| ProposerEndpoint::new(uri.clone(), config))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            endpoints,
+            active: AtomicUsize::new(0),
+            max_retries: config.max_retries,
+            initial_backoff: config.initial_backoff,
+            max_backoff: config.max_backoff,
+            query_supported: AtomicBool::new(true),
+            cancel_supported: AtomicBool::new(true),
+        })
+    }
+
+    /// Sends a request through `call` to the active endpoint, failing over to
+    /// the next endpoint when it is unavailable. Every endpoint is tried once
+    /// per attempt, and up to `max_retries` attempts are made again when
+    /// refused for a transient reason.
+    async fn call<Res, F, Fut>(
+        &self,
+        method: &'static str,
+        max_retries: usize,
+        call: F,
+    ) -> Result<Res, Status>
+    where
+        F: Fn(ProposerEndpoint) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<Res>, Status>>,
+    {
+        let mut delay = self.initial_backoff;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+
+            let (uri, status) = match self.call_endpoints(&call).await {
+                Ok(response) => return Ok(response),
+                Err(failure) => failure,
+            };
+
+            if attempt > max_retries || !RETRYABLE_CODES.contains(&status.code()) {
+                return Err(status);
+            }
+
+            warn!(
+                method,
+                %uri,
+                attempt,
+                retry_in = ?delay,
+                "Proposer request failed: {status}"
+            );
+            tokio::time::sleep(delay).await;
+            delay = delay.saturating_mul(2).min(self.max_backoff);
+        }
+    }
+
+    /// Sends a request through `call` to the active endpoint, then to each
+    /// next endpoint while unavailable, until every endpoint was tried.
+    /// Returns the last endpoint tried along with its status on failure.
+    async fn call_endpoints<Res, F, Fut>(&self, call: &F) -> Result<Res, (GrpcUri, Status)>
+    where
+        F: Fn(ProposerEndpoint) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<Res>, Status>>,
+    {
+        let mut tried = 0;
+        loop {
+            tried += 1;
+
+            let index = self.active.load(Ordering::Relaxed);
+            let endpoint = self.endpoints[index].clone();
+            let uri = endpoint.uri.clone();
+            let status = match call(endpoint).await {
+                Ok(response) => return Ok(response.into_inner()),
+                Err(status) => status,
+            };
+
+            if status.code() == Code::Unavailable {
+                self.fail_over(index);
+                if tried < self.endpoints.len() {
+                    continue;
+                }
+            }
 
-        let client = ProofsClient::new(channel);
-        Ok(ProposerRpcClient { client })
+            return Err((uri, status));
+        }
+    }
+
+    /// Moves the active endpoint past the endpoint at `index`, unless another
+    /// request already did.
+    fn fail_over(&self, index: usize) {
+        if self.endpoints.len() < 2 {
+            return;
+        }
+
+        let next = (index + 1) % self.endpoints.len();
+        if self
+            .active
+            .compare_exchange(index, next, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+        {
+            warn!(
+                from = %self.endpoints[index].uri,
+                to = %self.endpoints[next].uri,
+                "Proposer endpoint unavailable, failing over"
+            );
+        }
     }
 }
 
@@ -109,15 +357,17 @@ impl AggregationProofProposer for ProposerRpcClient {
     ) -> Result<AggregationProofProposerResponse, Error> {
         let request = grpc::AggProofRequest::from(request);
 
-        let mut client = self.client.clone();
-        let grpc_response = client
-            .request_agg_proof(request)
+        let grpc_response = self
+            .call("request_agg_proof", self.max_retries, |endpoint| {
+                let mut client = endpoint.client;
+                let request = request.clone();
+                async move { client.request_agg_proof(request).await }
+            })
             .await
             .map_err(ProofRequestError::Grpc)
             .inspect_err(|e| error!("Aggregation proof request failed: {e:?}"))
             .map_err(|e| Error::Requesting(Box::new(e)))?;
         let response: AggregationProofProposerResponse = grpc_response
-            .into_inner()
             .try_into()
             .inspect_err(|e| error!("Aggregation proof request failed: {e:?}"))
             .map_err(|e| Error::Requesting(Box::new(e)))?;
@@ -130,27 +380,127 @@ impl AggregationProofProposer for ProposerRpcClient {
         Ok(response)
     }
 
+    async fn find_agg_proof(
+        &self,
+        request: &AggregationProofProposerRequest,
+    ) -> Result<Option<AggregationProofProposerResponse>, Error> {
+        if !self.query_supported.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+
+        let request = grpc::AggProofRequest::from(request.clone());
+        let result = self
+            .call("find_agg_proof", 0, |endpoint| {
+                let mut client = endpoint.client;
+                let request = request.clone();
+                async move { client.get_agg_proof(request).await }
+            })
+            .await;
+
+        let grpc_response = match result {
+            Ok(grpc_response) => grpc_response,
+            Err(status) if status.code() == Code::NotFound => return Ok(None),
+            Err(status) if status.code() == Code::Unimplemented => {
+                info!("Proposer does not serve the existing aggregation proof requests");
+                self.query_supported.store(false, Ordering::Relaxed);
+                return Ok(None);
+            }
+            Err(status) => {
+                return Err(Error::Requesting(Box::new(ProofRequestError::Grpc(status))))
+            }
+        };
+        let response: AggregationProofProposerResponse = grpc_response
+            .try_into()
+            .inspect_err(|e| error!("Aggregation proof query failed: {e:?}"))
+            .map_err(|e| Error::Requesting(Box::new(e)))?;
+
+        info!(
+            request_id = response.to_string(),
+            "agg proof request found on the proposer"
+        );
+
+        Ok(Some(response))
+    }
+
+    async fn cancel_agg_proof(
+        &self,
+        response: &AggregationProofProposerResponse,
+    ) -> Result<bool, Error> {
+        if !self.cancel_supported.load(Ordering::Relaxed) {
+            return Ok(false);
+        }
+
+        let request = grpc::CancelAggProofRequest::from(response);
+        let result = self
+            .call("cancel_agg_proof", 0, |endpoint| {
+                let mut client = endpoint.client;
+                let request = request.clone();
+                async move { client.cancel_agg_proof(request).await }
+            })
+            .await;
+
+        match result {
+            Ok(grpc::CancelAggProofResponse {}) => {
+                info!(
+                    request_id = response.to_string(),
+                    "agg proof request cancelled"
+                );
+                Ok(true)
+            }
+            // The request is already over.
+            Err(status) if status.code() == Code::NotFound => Ok(false),
+            Err(status) if status.code() == Code::Unimplemented => {
+                info!("Proposer does not cancel its aggregation proof requests");
+                self.cancel_supported.store(false, Ordering::Relaxed);
+                Ok(false)
+            }
+            Err(status) => Err(Error::Requesting(Box::new(ProofRequestError::Grpc(status)))),
+        }
+    }
+
     async fn get_mock_proof(
         &self,
         request: MockProofProposerRequest,
     ) -> Result<MockProofProposerResponse, Error> {
+        let proof_id = request.proof_id.0;
         let request = grpc::GetMockProofRequest::from(request);
 
-        let mut client = self.client.clone();
-        let grpc_response = client
-            .get_mock_proof(request)
+        let grpc_response = self
+            .call("get_mock_proof", 0, |endpoint| {
+                let mut client = endpoint.client;
+                async move { client.get_mock_proof(request).await }
+            })
             .await
             .map_err(ProofRequestError::Grpc)
             .inspect_err(|e| error!("Get mock proof request failed: {e:?}"))
             .map_err(|e| Error::Requesting(Box::new(e)))?;
         let response: MockProofProposerResponse = grpc_response
-            .into_inner()
             .try_into()
             .inspect_err(|e| error!("Get mock proof request failed: {e:?}"))
             .map_err(|e| Error::Requesting(Box::new(e)))?;
 
-        info!(proof_id = request.proof_id, "mock proof request fullfilled");
+        info!(proof_id, "mock proof request fullfilled");
 
         Ok(response)
     }
+
+    async fn check_connectivity(&self) -> Result<(), Error> {
+        // Dedicated connections are opened, so that the check does not depend on
+        // the state of the channels used for the proof requests. The proposer is
+        // reachable as long as one of its endpoints is.
+        let mut result = Ok(());
+        for endpoint in &self.endpoints {
+            result = endpoint
+                .endpoint
+                .connect()
+                .await
+                .map(|_| ())
+                .map_err(Error::Connect);
+            if result.is_ok() {
+                break;
+            }
+        }
+
+        result
+    }
 }
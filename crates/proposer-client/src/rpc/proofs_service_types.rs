@@ -46,6 +46,14 @@ impl TryFrom<grpc::AggProofResponse> for AggregationProofProposerResponse {
     }
 }
 
+impl From<&AggregationProofProposerResponse> for grpc::CancelAggProofRequest {
+    fn from(response: &AggregationProofProposerResponse) -> Self {
+        grpc::CancelAggProofRequest {
+            proof_request_id: response.request_id.0.to_vec().into(),
+        }
+    }
+}
+
 impl From<MockProofProposerRequest> for grpc::GetMockProofRequest {
     fn from(request: MockProofProposerRequest) -> Self {
         grpc::GetMockProofRequest {
@@ -0,0 +1,292 @@
+#![allow(clippy::result_large_err)]
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use alloy_primitives::B256;
+use eyre::eyre;
+use prover_config::CancellationPolicy;
+use sp1_sdk::{SP1ProofWithPublicValues, SP1ProvingKey, SP1VerifyingKey};
+
+use crate::{
+    aggregation_prover::AggregationProver,
+    client::Client,
+    rpc::{
+        AggregationProofProposer, AggregationProofProposerRequest,
+        AggregationProofProposerResponse, MockProofProposerRequest, MockProofProposerResponse,
+    },
+    Error, ProposerClient as _, RequestId,
+};
+
+/// Proposer answering every aggregation proof request with a new request id,
+/// after `delay`.
+#[derive(Default)]
+struct CountingProposer {
+    requests: AtomicUsize,
+    cancellations: AtomicUsize,
+    existing_request: Option<AggregationProofProposerResponse>,
+    delay: Duration,
+}
+
+#[tonic::async_trait]
+impl AggregationProofProposer for CountingProposer {
+    async fn request_agg_proof(
+        &self,
+        request: AggregationProofProposerRequest,
+    ) -> Result<AggregationProofProposerResponse, Error> {
+        let count = self.requests.fetch_add(1, Ordering::Relaxed) + 1;
+        tokio::time::sleep(self.delay).await;
+
+        Ok(AggregationProofProposerResponse {
+            request_id: RequestId(B256::with_last_byte(count as u8)),
+            last_proven_block: request.last_proven_block,
+            end_block: request.requested_end_block,
+        })
+    }
+
+    async fn find_agg_proof(
+        &self,
+        _request: &AggregationProofProposerRequest,
+    ) -> Result<Option<AggregationProofProposerResponse>, Error> {
+        Ok(self.existing_request.clone())
+    }
+
+    async fn cancel_agg_proof(
+        &self,
+        _response: &AggregationProofProposerResponse,
+    ) -> Result<bool, Error> {
+        self.cancellations.fetch_add(1, Ordering::Relaxed);
+
+        Ok(true)
+    }
+
+    async fn get_mock_proof(
+        &self,
+        _request: MockProofProposerRequest,
+    ) -> Result<MockProofProposerResponse, Error> {
+        unimplemented!()
+    }
+
+    async fn check_connectivity(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Prover failing every proof.
+struct FailingProver;
+
+#[tonic::async_trait]
+impl AggregationProver for FailingProver {
+    async fn compute_pkey_vkey(
+        &self,
+        _program: &[u8],
+    ) -> eyre::Result<(SP1ProvingKey, SP1VerifyingKey)> {
+        unimplemented!()
+    }
+
+    async fn wait_for_proof(
+        &self,
+        _request_id: B256,
+        _timeout: Option<Duration>,
+    ) -> eyre::Result<SP1ProofWithPublicValues> {
+        Err(eyre!("Proof unfulfillable"))
+    }
+
+    fn verify_aggregated_proof(
+        &self,
+        _proof: &SP1ProofWithPublicValues,
+        _vkey: &SP1VerifyingKey,
+    ) -> eyre::Result<()> {
+        unimplemented!()
+    }
+}
+
+fn agg_proof_request(l1_block_hash: u8) -> AggregationProofProposerRequest {
+    AggregationProofProposerRequest {
+        last_proven_block: 500,
+        requested_end_block: 550,
+        l1_block_number: 101,
+        l1_block_hash: B256::repeat_byte(l1_block_hash),
+    }
+}
+
+#[tokio::test]
+async fn same_request_is_submitted_once() {
+    let proposer = Arc::new(CountingProposer::default());
+    let client = Client::new(proposer.clone(), FailingProver, None).unwrap();
+
+    let first = client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+    let retry = client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+    let other = client
+        .request_agg_proof(agg_proof_request(2))
+        .await
+        .unwrap();
+
+    assert_eq!(first.request_id, retry.request_id);
+    assert_ne!(first.request_id, other.request_id);
+    assert_eq!(proposer.requests.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn concurrent_same_requests_are_submitted_once() {
+    let proposer = Arc::new(CountingProposer {
+        delay: Duration::from_millis(50),
+        ..Default::default()
+    });
+    let client = Client::new(proposer.clone(), FailingProver, None).unwrap();
+
+    let (first, second) = tokio::join!(
+        client.request_agg_proof(agg_proof_request(1)),
+        client.request_agg_proof(agg_proof_request(1)),
+    );
+
+    assert_eq!(first.unwrap().request_id, second.unwrap().request_id);
+    assert_eq!(proposer.requests.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn failed_request_is_submitted_again() {
+    let proposer = Arc::new(CountingProposer::default());
+    let client = Client::new(proposer.clone(), FailingProver, None).unwrap();
+
+    let first = client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+    client
+        .wait_for_proof(first.request_id.clone())
+        .await
+        .unwrap_err();
+    let retry = client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+
+    assert_ne!(first.request_id, retry.request_id);
+    assert_eq!(proposer.requests.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn request_known_to_the_proposer_is_reused() {
+    let existing_request = AggregationProofProposerResponse {
+        request_id: RequestId(B256::repeat_byte(0xee)),
+        last_proven_block: 500,
+        end_block: 550,
+    };
+    let proposer = Arc::new(CountingProposer {
+        existing_request: Some(existing_request.clone()),
+        ..Default::default()
+    });
+    let client = Client::new(proposer.clone(), FailingProver, None).unwrap();
+
+    let response = client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+
+    assert_eq!(response.request_id, existing_request.request_id);
+    assert_eq!(proposer.requests.load(Ordering::Relaxed), 0);
+}
+
+#[tokio::test]
+async fn reuse_can_be_disabled() {
+    let proposer = Arc::new(CountingProposer::default());
+    let client = Client::new(proposer.clone(), FailingProver, None)
+        .unwrap()
+        .set_request_reuse_timeout(Duration::ZERO);
+
+    client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+    client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+
+    assert_eq!(proposer.requests.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn abandoned_request_is_reused() {
+    let proposer = Arc::new(CountingProposer::default());
+    let client = Client::new(proposer.clone(), FailingProver, None).unwrap();
+
+    let first = client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+    client.abandon_request(&first);
+    let retry = client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+
+    assert_eq!(first.request_id, retry.request_id);
+    assert_eq!(proposer.cancellations.load(Ordering::Relaxed), 0);
+}
+
+#[tokio::test]
+async fn abandoned_request_is_cancelled_under_the_cancel_policy() {
+    let proposer = Arc::new(CountingProposer::default());
+    let client = Client::new(proposer.clone(), FailingProver, None)
+        .unwrap()
+        .set_cancellation_policy(CancellationPolicy::Cancel);
+
+    let first = client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+    client.abandon_request(&first);
+    let retry = client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+
+    // The cancellation is sent in the background.
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while proposer.cancellations.load(Ordering::Relaxed) == 0 {
+            tokio::task::yield_now().await;
+        }
+    })
+    .await
+    .unwrap();
+    assert_ne!(first.request_id, retry.request_id);
+}
+
+#[tokio::test]
+async fn cancelled_request_is_cancelled_whatever_the_policy() {
+    let proposer = Arc::new(CountingProposer::default());
+    let client = Client::new(proposer.clone(), FailingProver, None).unwrap();
+
+    let first = client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+    client.cancel_request(&first);
+    let retry = client
+        .request_agg_proof(agg_proof_request(1))
+        .await
+        .unwrap();
+
+    // The cancellation is sent in the background.
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while proposer.cancellations.load(Ordering::Relaxed) == 0 {
+            tokio::task::yield_now().await;
+        }
+    })
+    .await
+    .unwrap();
+    assert_ne!(first.request_id, retry.request_id);
+}
@@ -23,6 +23,16 @@ mockall::mock! {
             request: tonic::Request<grpc::AggProofRequest>,
         ) -> Result<tonic::Response<grpc::AggProofResponse>, tonic::Status>;
 
+        async fn get_agg_proof(
+            &self,
+            request: tonic::Request<grpc::AggProofRequest>,
+        ) -> Result<tonic::Response<grpc::AggProofResponse>, tonic::Status>;
+
+        async fn cancel_agg_proof(
+            &self,
+            request: tonic::Request<grpc::CancelAggProofRequest>,
+        ) -> Result<tonic::Response<grpc::CancelAggProofResponse>, tonic::Status>;
+
         async fn get_mock_proof(
             &self,
             request: Request<GetMockProofRequest>
@@ -1,5 +1,7 @@
 pub mod mock_server;
 
+mod client;
+
 mod proposer_rpc;
 
 mod prover_rpc;
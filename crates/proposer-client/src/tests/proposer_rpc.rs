@@ -1,17 +1,24 @@
 #![allow(clippy::result_large_err)]
 
-use std::time::Duration;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use alloy_primitives::B256;
 use prost::bytes::Bytes;
 
 use crate::{
+    config::{ProposerClientConfig, ProposerTlsConfig},
     rpc::{
         grpc::{AggProofRequest, AggProofResponse},
         AggregationProofProposer, AggregationProofProposerRequest, ProposerRpcClient,
     },
     tests::{mock_server, mock_server::MockProofsService},
-    RequestId,
+    GrpcUri, RequestId,
 };
 
 fn create_agg_proof_request() -> AggregationProofProposerRequest {
@@ -75,9 +82,7 @@ async fn request_an_aggregated_span_proof() {
     .await
     .expect("valid mock server");
 
-    let service = ProposerRpcClient::new(server.uri(), Duration::from_millis(500))
-        .await
-        .unwrap();
+    let service = ProposerRpcClient::new(server.uri(), Duration::from_millis(500)).unwrap();
 
     let response = service
         .request_agg_proof(request.clone())
@@ -114,9 +119,7 @@ async fn request_and_receive_an_error() {
     .await
     .expect("valid mock server");
 
-    let service = ProposerRpcClient::new(server.uri(), Duration::from_millis(500))
-        .await
-        .unwrap();
+    let service = ProposerRpcClient::new(server.uri(), Duration::from_millis(500)).unwrap();
     let response = service.request_agg_proof(request.clone()).await;
 
     assert!(response.is_err());
@@ -162,9 +165,7 @@ async fn receive_end_block_higher_than_last_chain_block() {
     .await
     .expect("valid mock server");
 
-    let service = ProposerRpcClient::new(server.uri(), Duration::from_millis(500))
-        .await
-        .unwrap();
+    let service = ProposerRpcClient::new(server.uri(), Duration::from_millis(500)).unwrap();
     let response = service.request_agg_proof(request.clone()).await;
 
     assert!(response.is_err());
@@ -210,9 +211,7 @@ async fn receive_an_invalid_start_end_block() {
     .await
     .expect("valid mock server");
 
-    let service = ProposerRpcClient::new(server.uri(), Duration::from_millis(500))
-        .await
-        .unwrap();
+    let service = ProposerRpcClient::new(server.uri(), Duration::from_millis(500)).unwrap();
     let response = service.request_agg_proof(request.clone()).await;
 
     assert!(response.is_err());
@@ -229,3 +228,145 @@ async fn receive_an_invalid_start_end_block() {
 
     server.stop().await.unwrap();
 }
+
+#[test_log::test(tokio::test)]
+async fn proposer_without_the_query_has_no_existing_request() {
+    let mut server = MockProofsService::new();
+    server
+        .expect_get_agg_proof()
+        .returning(|_| Err(tonic::Status::unimplemented("Unknown method")));
+    let server = server.run().await.unwrap();
+
+    let service = ProposerRpcClient::new(server.uri(), Duration::from_millis(500)).unwrap();
+
+    let response = service
+        .find_agg_proof(&create_agg_proof_request())
+        .await
+        .expect("unimplemented query is not an error");
+
+    assert!(response.is_none());
+
+    server.stop().await.unwrap();
+}
+
+/// Uri of a local port nothing listens on.
+fn down_proposer_uri() -> GrpcUri {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    format!("http://{}", listener.local_addr().unwrap())
+        .parse()
+        .unwrap()
+}
+
+fn agg_proof_response(req: tonic::Request<AggProofRequest>) -> AggProofResponse {
+    let req = req.into_inner();
+    AggProofResponse {
+        last_proven_block: req.last_proven_block,
+        end_block: req.requested_end_block,
+        proof_request_id: Bytes::from_owner([1u8; 32].to_vec()),
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn client_is_created_while_the_proposer_is_down() {
+    let service = ProposerRpcClient::new(down_proposer_uri(), Duration::from_millis(500)).unwrap();
+
+    let response = service.request_agg_proof(create_agg_proof_request()).await;
+
+    match response.unwrap_err() {
+        crate::error::Error::Requesting(err) => match *err {
+            crate::error::ProofRequestError::Grpc(status) => {
+                assert_eq!(status.code(), tonic::Code::Unavailable)
+            }
+            err => panic!("Expected a grpc error, got {err:?}"),
+        },
+        err => panic!("Expected a requesting error, got {err:?}"),
+    }
+}
+
+#[test_log::test(tokio::test)]
+async fn unavailable_proposer_fails_over() {
+    let request = create_agg_proof_request();
+    let server = create_mock_grpc_proposer(request.clone().into(), |req| {
+        Ok(tonic::Response::new(agg_proof_response(req)))
+    })
+    .await
+    .expect("valid mock server");
+
+    let service = ProposerRpcClient::from_config(&ProposerClientConfig {
+        proposer_endpoint: down_proposer_uri(),
+        fallback_proposer_endpoints: vec![server.uri()],
+        request_timeout: Duration::from_millis(500),
+        max_retries: 0,
+        ..Default::default()
+    })
+    .unwrap();
+
+    let response = service
+        .request_agg_proof(request.clone())
+        .await
+        .expect("successful reply from the fallback endpoint");
+
+    assert_eq!(response.request_id, RequestId(B256::new([1u8; 32])));
+
+    server.stop().await.unwrap();
+}
+
+#[test_log::test(tokio::test)]
+async fn unavailable_request_is_retried() {
+    let request = create_agg_proof_request();
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let server = create_mock_grpc_proposer(request.clone().into(), {
+        let attempts = attempts.clone();
+        move |req: tonic::Request<AggProofRequest>| {
+            if attempts.fetch_add(1, Ordering::Relaxed) == 0 {
+                return Err(tonic::Status::unavailable("Proposer is starting"));
+            }
+            Ok(tonic::Response::new(agg_proof_response(req)))
+        }
+    })
+    .await
+    .expect("valid mock server");
+
+    let service = ProposerRpcClient::from_config(&ProposerClientConfig {
+        proposer_endpoint: server.uri(),
+        request_timeout: Duration::from_millis(500),
+        initial_backoff: Duration::from_millis(10),
+        ..Default::default()
+    })
+    .unwrap();
+
+    service
+        .request_agg_proof(request)
+        .await
+        .expect("successful reply after a retry");
+
+    assert_eq!(attempts.load(Ordering::Relaxed), 2);
+
+    server.stop().await.unwrap();
+}
+
+#[test]
+fn client_identity_needs_both_certificate_and_key() {
+    let tls_configs = [
+        ProposerTlsConfig {
+            client_certificate: Some("client.pem".into()),
+            ..Default::default()
+        },
+        ProposerTlsConfig {
+            client_key: Some("client.key".into()),
+            ..Default::default()
+        },
+    ];
+
+    for tls in tls_configs {
+        let result = ProposerRpcClient::from_config(&ProposerClientConfig {
+            tls: Some(tls),
+            ..Default::default()
+        });
+
+        assert!(
+            matches!(result, Err(crate::error::Error::TlsConfig(_))),
+            "Expected a TLS config error"
+        );
+    }
+}
@@ -10,6 +10,6 @@ pub struct ProposerServiceConfig {
 
     pub client: ProposerClientConfig,
 
-    /// JSON-RPC endpoint of the l1 node.
+    /// JSON-RPC endpoints of the l1 node.
     pub l1_rpc_endpoint: L1RpcEndpoint,
 }
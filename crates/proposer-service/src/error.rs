@@ -1,3 +1,4 @@
+use alloy_primitives::B256;
 use proposer_client::error::Error as ProposerClientError;
 
 #[derive(Debug, thiserror::Error)]
@@ -11,6 +12,28 @@ pub enum Error {
     #[error("Failure on the deserialization of the FEP public values")]
     FepPublicValuesDeserializeFailure(#[source] alloy_sol_types::Error),
 
+    #[error(
+        "Proposer returned an aggregation proof from the last proven block {got} instead of \
+         {requested}"
+    )]
+    LastProvenBlockMismatch { requested: u64, got: u64 },
+
+    #[error(
+        "Proposer returned an aggregation proof up to the block {end_block}, out of the requested \
+         range ({last_proven_block}, {requested_end_block}]"
+    )]
+    EndBlockOutOfRange {
+        last_proven_block: u64,
+        requested_end_block: u64,
+        end_block: u64,
+    },
+
+    #[error("Aggregation proof is anchored at the l1 block {got} instead of {requested}")]
+    L1HeadMismatch { requested: B256, got: B256 },
+
+    #[error("Aggregation proof is for the l2 block {got} instead of the end block {end_block}")]
+    L2BlockNumberMismatch { end_block: u64, got: u64 },
+
     #[error(transparent)]
     Other(eyre::Error),
 }
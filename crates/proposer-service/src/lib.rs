@@ -5,6 +5,7 @@ use std::{
 
 use aggchain_proof_core::full_execution_proof::AggregationProofPublicValues;
 use agglayer_evm_client::GetBlockNumber;
+use alloy_primitives::B256;
 use alloy_sol_types::SolType;
 use educe::Educe;
 pub use error::Error;
@@ -13,7 +14,7 @@ use proposer_client::{
     aggregation_prover::AggregationProver,
     mock_grpc_prover::MockGrpcProver,
     network_prover::new_network_prover,
-    rpc::{AggregationProofProposerRequest, ProposerRpcClient},
+    rpc::{AggregationProofProposerRequest, AggregationProofProposerResponse, ProposerRpcClient},
     FepProposerRequest,
 };
 use sp1_sdk::{NetworkProver, SP1ProofWithPublicValues, SP1VerifyingKey};
@@ -56,13 +57,7 @@ where
         config: &ProposerServiceConfig,
         l1_rpc: Arc<L1Rpc>,
     ) -> Result<Self, Error> {
-        let proposer_rpc_client = Arc::new(
-            ProposerRpcClient::new(
-                config.client.proposer_endpoint.clone(),
-                config.client.request_timeout,
-            )
-            .await?,
-        );
+        let proposer_rpc_client = Arc::new(ProposerRpcClient::from_config(&config.client)?);
 
         // Use the op-succinct aggregation vkey in effect: the configured override
         // when installed at startup (see `proposer_elfs::install_overrides`),
@@ -71,11 +66,15 @@ where
 
         Ok(Self {
             l1_rpc,
-            client: Arc::new(proposer_client::client::Client::new(
-                proposer_rpc_client,
-                prover,
-                Some(config.client.proving_timeout),
-            )?),
+            client: Arc::new(
+                proposer_client::client::Client::new(
+                    proposer_rpc_client,
+                    prover,
+                    Some(config.client.proving_timeout),
+                )?
+                .set_request_reuse_timeout(config.client.request_reuse_timeout)
+                .set_cancellation_policy(config.client.cancellation_policy),
+            ),
             aggregation_vkey,
         })
     }
@@ -93,9 +92,12 @@ impl<L1Rpc>
             "Building a network proposer service with a mock config"
         );
         Self::new(
-            new_network_prover(&config.client.sp1_cluster_endpoint)
-                .await
-                .map_err(Error::Other)?,
+            new_network_prover(
+                &config.client.sp1_cluster_endpoint,
+                &config.client.private_key,
+            )
+            .await
+            .map_err(Error::Other)?,
             config,
             l1_rpc,
         )
@@ -117,13 +119,7 @@ impl<L1Rpc>
             config.mock,
             "Building a mock proposer service with a non-mock config"
         );
-        let proposer_rpc_client = Arc::new(
-            ProposerRpcClient::new(
-                config.client.proposer_endpoint.clone(),
-                config.client.request_timeout,
-            )
-            .await?,
-        );
+        let proposer_rpc_client = Arc::new(ProposerRpcClient::from_config(&config.client)?);
 
         Self::new(
             MockGrpcProver::new(proposer_rpc_client).await,
@@ -134,6 +130,18 @@ impl<L1Rpc>
     }
 }
 
+impl<L1Rpc, ProposerClient> ProposerService<L1Rpc, ProposerClient>
+where
+    ProposerClient: proposer_client::ProposerClient + Send + Sync,
+{
+    /// Checks that the proposer can be reached.
+    pub async fn check_health(&self) -> Result<(), Error> {
+        self.client.check_connectivity().await?;
+
+        Ok(())
+    }
+}
+
 impl<L1Rpc, ProposerClient> tower::Service<FepProposerRequest>
     for ProposerService<L1Rpc, ProposerClient>
 where
@@ -183,17 +191,30 @@ where
                     l1_block_hash,
                 })
                 .await?;
-            let request_id = response.request_id;
+            let request_id = response.request_id.clone();
             let end_block = response.end_block;
-            let last_proven_block = response.last_proven_block;
             debug!(%last_proven_block, %end_block, %request_id, "Aggregation proof request submitted");
 
-            // Wait for the prover to finish aggregating span proofs
+            // A request the proposer answered with another range is not waited for,
+            // nor reused by the next request of the same range, and its proof is
+            // not generated for nothing.
+            validate_range(last_proven_block, requested_end_block, &response)
+                .inspect_err(|_| client.cancel_request(&response))?;
+
+            // Wait for the prover to finish aggregating span proofs. The request is
+            // abandoned if the wait fails or is dropped by the caller.
+            let abandon_guard = AbandonGuard::new(client.as_ref(), &response);
             let proof_with_pv = client.wait_for_proof(request_id.clone()).await?;
+            abandon_guard.complete();
 
             let public_values =
                 AggregationProofPublicValues::abi_decode(proof_with_pv.public_values.as_slice())
-                    .map_err(Error::FepPublicValuesDeserializeFailure)?;
+                    .map_err(Error::FepPublicValuesDeserializeFailure)
+                    .and_then(|public_values| {
+                        validate_public_values(l1_block_hash, end_block, &public_values)?;
+                        Ok(public_values)
+                    })
+                    .inspect_err(|_| client.discard_request(&request_id))?;
 
             debug!(%last_proven_block, %end_block, %request_id, "Aggregation proof received from the proposer");
 
@@ -214,3 +235,81 @@ where
         .boxed()
     }
 }
+
+/// Abandons the aggregation proof request to the proposer client when
+/// dropped before [`AbandonGuard::complete`], that is once its proof is no
+/// longer waited for.
+struct AbandonGuard<'a, ProposerClient: proposer_client::ProposerClient> {
+    client: &'a ProposerClient,
+    response: Option<&'a AggregationProofProposerResponse>,
+}
+
+impl<'a, ProposerClient: proposer_client::ProposerClient> AbandonGuard<'a, ProposerClient> {
+    fn new(client: &'a ProposerClient, response: &'a AggregationProofProposerResponse) -> Self {
+        Self {
+            client,
+            response: Some(response),
+        }
+    }
+
+    fn complete(mut self) {
+        self.response = None;
+    }
+}
+
+impl<ProposerClient: proposer_client::ProposerClient> Drop for AbandonGuard<'_, ProposerClient> {
+    fn drop(&mut self) {
+        if let Some(response) = self.response.take() {
+            self.client.abandon_request(response);
+        }
+    }
+}
+
+/// Checks that the proposer answered with a range starting at the requested
+/// last proven block, and ending within the requested range.
+fn validate_range(
+    last_proven_block: u64,
+    requested_end_block: u64,
+    response: &AggregationProofProposerResponse,
+) -> Result<(), Error> {
+    if response.last_proven_block != last_proven_block {
+        return Err(Error::LastProvenBlockMismatch {
+            requested: last_proven_block,
+            got: response.last_proven_block,
+        });
+    }
+
+    if response.end_block <= last_proven_block || response.end_block > requested_end_block {
+        return Err(Error::EndBlockOutOfRange {
+            last_proven_block,
+            requested_end_block,
+            end_block: response.end_block,
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks that the aggregation proof is anchored at the requested l1 block,
+/// and proves the l2 chain up to the end block.
+fn validate_public_values(
+    l1_block_hash: B256,
+    end_block: u64,
+    public_values: &AggregationProofPublicValues,
+) -> Result<(), Error> {
+    if public_values.l1_head != l1_block_hash {
+        return Err(Error::L1HeadMismatch {
+            requested: l1_block_hash,
+            got: public_values.l1_head,
+        });
+    }
+
+    if public_values.l2_block_number != end_block {
+        return Err(Error::L2BlockNumberMismatch {
+            end_block,
+            got: public_values.l2_block_number,
+        });
+    }
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
+use aggchain_proof_core::full_execution_proof::AggregationProofPublicValues;
 use agglayer_evm_client::MockRpc;
-use alloy_primitives::FixedBytes;
+use alloy_primitives::{Address, FixedBytes, B256};
+use alloy_sol_types::SolType as _;
 use proposer_client::{
     rpc::AggregationProofProposerRequest, FepProposerRequest, MockProposerClient, RequestId,
 };
@@ -12,35 +14,32 @@ use crate::{Error, ProposerService};
 
 const ELF: &[u8] = proposer_elfs::aggregation::ELF;
 
+/// ABI encoded public values of an aggregation proof anchored at `l1_head`
+/// and ending at `l2_block_number`.
+fn public_values(l1_head: B256, l2_block_number: u64) -> SP1PublicValues {
+    SP1PublicValues::from(&AggregationProofPublicValues::abi_encode(
+        &AggregationProofPublicValues {
+            l1_head,
+            l2_pre_root: B256::ZERO,
+            l2_post_root: B256::ZERO,
+            l2_block_number,
+            rollup_config_hash: B256::ZERO,
+            multi_block_vkey: B256::ZERO,
+            prover_address: Address::ZERO,
+        },
+    ))
+}
+
 async fn generate_keys() -> (
     sp1_sdk::SP1ProvingKey,
     sp1_sdk::SP1VerifyingKey,
     SP1PublicValues,
 ) {
-    use alloy_primitives::B256;
-    use serde::{Deserialize, Serialize};
-
     let client = sp1_sdk::ProverClient::builder().mock().build().await;
     let pk = client.setup(ELF.into()).await.unwrap();
     let vk = pk.verifying_key().clone();
 
-    #[derive(Default, Serialize, Deserialize)]
-    struct TestAggregationOutputs {
-        l1_head: B256,
-        l2_pre_root: B256,
-        l2_post_root: B256,
-        l2_block_number: u64,
-        rollup_config_hash: B256,
-        multi_block_vkey: B256,
-        prover_address: B256,
-    }
-    let data = TestAggregationOutputs::default();
-    let public_values = SP1PublicValues::from(
-        &aggkit_prover_types::bincode::sp1_compatible()
-            .serialize(&data)
-            .unwrap(),
-    );
-    (pk, vk, public_values)
+    (pk, vk, public_values(B256::ZERO, 10))
 }
 
 #[tokio::test]
@@ -137,6 +136,161 @@ async fn unable_to_fetch_block_hash() {
     ));
 }
 
+/// Proposer service over `client`, requesting from the block 0 up to the
+/// block 10.
+async fn proposer_service_with(
+    client: MockProposerClient,
+) -> (
+    ProposerService<MockRpc, MockProposerClient>,
+    FepProposerRequest,
+) {
+    let mut l1_rpc = MockRpc::new();
+    l1_rpc.expect_get_block_number().returning(|_| Ok(10));
+
+    let (_pkey, vkey, _public_values) = generate_keys().await;
+    let proposer_service = ProposerService {
+        client: Arc::new(client),
+        l1_rpc: Arc::new(l1_rpc),
+        aggregation_vkey: vkey,
+    };
+
+    let request = FepProposerRequest {
+        last_proven_block: 0,
+        requested_end_block: 10,
+        l1_block_hash: Default::default(),
+    };
+
+    (proposer_service, request)
+}
+
+fn expect_agg_proof_response(
+    client: &mut MockProposerClient,
+    last_proven_block: u64,
+    end_block: u64,
+) {
+    client
+        .expect_request_agg_proof()
+        .once()
+        .returning(move |_| {
+            Box::pin(async move {
+                Ok(proposer_client::rpc::AggregationProofProposerResponse {
+                    request_id: RequestId(FixedBytes::new([0; 32])),
+                    last_proven_block,
+                    end_block,
+                })
+            })
+        });
+}
+
+#[tokio::test]
+async fn other_last_proven_block_is_rejected() {
+    let mut client = MockProposerClient::new();
+    expect_agg_proof_response(&mut client, 1, 10);
+    client
+        .expect_cancel_request()
+        .once()
+        .withf(|response| response.last_proven_block == 1)
+        .return_const(());
+
+    let (mut proposer_service, request) = proposer_service_with(client).await;
+
+    let error = proposer_service.call(request).await.unwrap_err();
+    assert!(matches!(
+        error,
+        Error::LastProvenBlockMismatch {
+            requested: 0,
+            got: 1
+        }
+    ));
+}
+
+#[tokio::test]
+async fn end_block_out_of_the_requested_range_is_rejected() {
+    for end_block in [0, 11] {
+        let mut client = MockProposerClient::new();
+        expect_agg_proof_response(&mut client, 0, end_block);
+        client
+            .expect_cancel_request()
+            .once()
+            .withf(move |response| response.end_block == end_block)
+            .return_const(());
+
+        let (mut proposer_service, request) = proposer_service_with(client).await;
+
+        let error = proposer_service.call(request).await.unwrap_err();
+        assert!(
+            matches!(error, Error::EndBlockOutOfRange { end_block: got, .. } if got == end_block),
+            "unexpected error {error:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn proof_of_another_range_is_rejected() {
+    let error = another_range_error(B256::repeat_byte(1), 10).await;
+    assert!(
+        matches!(
+            error,
+            Error::L1HeadMismatch { requested, got }
+                if requested == B256::ZERO && got == B256::repeat_byte(1)
+        ),
+        "unexpected error {error:?}"
+    );
+
+    let error = another_range_error(B256::ZERO, 9).await;
+    assert!(
+        matches!(
+            error,
+            Error::L2BlockNumberMismatch {
+                end_block: 10,
+                got: 9
+            }
+        ),
+        "unexpected error {error:?}"
+    );
+}
+
+/// Runs a request answered with a proof of the given l1 head and l2 block
+/// number, and returns the error rejecting it.
+async fn another_range_error(l1_head: B256, l2_block_number: u64) -> Error {
+    let mut client = MockProposerClient::new();
+    expect_agg_proof_response(&mut client, 0, 10);
+
+    let (_pkey, vkey, _public_values) = generate_keys().await;
+    let mock_proof = sp1_sdk::SP1ProofWithPublicValues::create_mock_proof(
+        &vkey,
+        public_values(l1_head, l2_block_number),
+        sp1_sdk::SP1ProofMode::Compressed,
+        SP1_CIRCUIT_VERSION,
+    );
+    client
+        .expect_wait_for_proof()
+        .once()
+        .return_once(move |_| Box::pin(async move { Ok(mock_proof) }));
+    client.expect_discard_request().once().return_const(());
+
+    let (mut proposer_service, request) = proposer_service_with(client).await;
+
+    proposer_service.call(request).await.unwrap_err()
+}
+
+#[tokio::test]
+async fn abandoned_proof_wait_abandons_the_request() {
+    let mut client = MockProposerClient::new();
+    expect_agg_proof_response(&mut client, 0, 10);
+    client
+        .expect_wait_for_proof()
+        .once()
+        .return_once(|_| Box::pin(std::future::pending()));
+    client.expect_abandon_request().once().return_const(());
+
+    let (mut proposer_service, request) = proposer_service_with(client).await;
+
+    let result =
+        tokio::time::timeout(Duration::from_millis(100), proposer_service.call(request)).await;
+    assert!(result.is_err());
+}
+
 #[test]
 #[ignore = "to be implemented"]
 fn test_invalid_proof_vkey_verificatinon_fails() {}
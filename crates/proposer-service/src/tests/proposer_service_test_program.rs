@@ -49,16 +49,12 @@ pub async fn main() -> eyre::Result<()> {
     color_eyre::install()?;
 
     // Initialize the tracing
-    prover_logger::tracing(&Log::default());
+    let _tracing_guard = prover_logger::tracing(&Log::default());
 
     let cli = Cli::parse();
 
     // Setup the l1 rpc client
-    let client = prover_alloy::AlloyProvider::new(
-        &cli.l1_rpc_endpoint.url,
-        prover_alloy::DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS,
-        prover_alloy::DEFAULT_HTTP_RPC_NODE_BACKOFF_MAX_RETRIES,
-    )?;
+    let client = prover_alloy::AlloyProvider::new("l1", &cli.l1_rpc_endpoint.endpoints)?;
     let l1_rpc_client = Arc::new(client);
 
     info!("L1 RPC client initialized");
@@ -71,6 +67,8 @@ pub async fn main() -> eyre::Result<()> {
             sp1_cluster_endpoint: cli.sp1_cluster_endpoint,
             request_timeout: proposer_client::config::default_request_timeout(),
             proving_timeout: proposer_client::config::default_proving_timeout(),
+            request_reuse_timeout: proposer_client::config::default_request_reuse_timeout(),
+            ..Default::default()
         },
         l1_rpc_endpoint: cli.l1_rpc_endpoint,
     };
@@ -0,0 +1,319 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize, Serializer};
+use url::Url;
+
+use crate::{DEFAULT_HTTP_RPC_NODE_BACKOFF_MAX_RETRIES, DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS};
+
+/// How the requests to a node are spread over its endpoints.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum EndpointPolicy {
+    /// Send the requests to the first healthy endpoint, falling back to the
+    /// next ones when it fails.
+    #[default]
+    Failover,
+    /// Start each request at the next endpoint in turn, falling back to the
+    /// others when it fails.
+    RoundRobin,
+    /// Send the quorum methods to all the endpoints and accept a response only
+    /// once enough of them returned it. The other methods fail over.
+    Quorum,
+}
+
+/// JSON-RPC endpoint of a node.
+///
+/// Either a plain url, or a table overriding the backoff of the url.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case", from = "RpcEndpointRepr")]
+pub struct RpcEndpoint {
+    pub url: Url,
+
+    /// Initial backoff in milliseconds before retrying a failed request.
+    pub initial_backoff_ms: u64,
+
+    /// Maximum number of retries of a failed request.
+    pub max_retries: u32,
+}
+
+impl From<Url> for RpcEndpoint {
+    fn from(url: Url) -> Self {
+        Self {
+            url,
+            initial_backoff_ms: DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS,
+            max_retries: DEFAULT_HTTP_RPC_NODE_BACKOFF_MAX_RETRIES,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RpcEndpointRepr {
+    Url(Url),
+    #[serde(rename_all = "kebab-case")]
+    Table {
+        url: Url,
+        #[serde(default = "default_initial_backoff_ms")]
+        initial_backoff_ms: u64,
+        #[serde(default = "default_max_retries")]
+        max_retries: u32,
+    },
+}
+
+impl From<RpcEndpointRepr> for RpcEndpoint {
+    fn from(repr: RpcEndpointRepr) -> Self {
+        match repr {
+            RpcEndpointRepr::Url(url) => url.into(),
+            RpcEndpointRepr::Table {
+                url,
+                initial_backoff_ms,
+                max_retries,
+            } => Self {
+                url,
+                initial_backoff_ms,
+                max_retries,
+            },
+        }
+    }
+}
+
+/// JSON-RPC endpoints of a node, and the policy spreading the requests over
+/// them.
+///
+/// Either a plain url, or a table listing the endpoints:
+///
+/// ```toml
+/// [l1-rpc-endpoint]
+/// policy = "quorum"
+/// quorum = 2
+/// endpoints = [
+///     "http://l1-a:8545",
+///     "http://l1-b:8545",
+///     { url = "http://l1-c:8545", initial-backoff-ms = 1000, max-retries = 8 },
+/// ]
+/// ```
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(try_from = "RpcEndpointsRepr")]
+pub struct RpcEndpoints {
+    /// Endpoints of the node, in order of preference.
+    pub endpoints: Vec<RpcEndpoint>,
+
+    /// Policy spreading the requests over the endpoints.
+    pub policy: EndpointPolicy,
+
+    /// Number of endpoints which must return the same response under the
+    /// quorum policy, a majority of them if not set.
+    pub quorum: Option<usize>,
+
+    /// Methods sent to all the endpoints under the quorum policy.
+    pub quorum_methods: Vec<String>,
+}
+
+impl RpcEndpoints {
+    /// Url of the preferred endpoint, for the clients which cannot spread
+    /// their requests.
+    pub fn primary_url(&self) -> &Url {
+        &self.endpoints[0].url
+    }
+
+    /// Number of endpoints which must agree on a response under the quorum
+    /// policy.
+    pub fn quorum_size(&self) -> usize {
+        self.quorum.unwrap_or(self.endpoints.len() / 2 + 1)
+    }
+
+    fn validate(self) -> Result<Self, String> {
+        if self.endpoints.is_empty() {
+            return Err("at least one rpc endpoint is required".to_string());
+        }
+
+        if let Some(quorum) = self.quorum {
+            if quorum == 0 || quorum > self.endpoints.len() {
+                return Err(format!(
+                    "quorum of {quorum} out of {} rpc endpoints",
+                    self.endpoints.len()
+                ));
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Whether the endpoints are a single url without any setting, which is
+    /// then serialized as a plain url.
+    fn is_single_url(&self) -> bool {
+        match self.endpoints.as_slice() {
+            [endpoint] => {
+                *endpoint == RpcEndpoint::from(endpoint.url.clone())
+                    && self.policy == EndpointPolicy::default()
+                    && self.quorum.is_none()
+                    && self.quorum_methods == default_quorum_methods()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<Url> for RpcEndpoints {
+    fn from(url: Url) -> Self {
+        Self {
+            endpoints: vec![url.into()],
+            policy: EndpointPolicy::default(),
+            quorum: None,
+            quorum_methods: default_quorum_methods(),
+        }
+    }
+}
+
+impl FromStr for RpcEndpoints {
+    type Err = url::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Url::from_str(s).map(Self::from)
+    }
+}
+
+impl Serialize for RpcEndpoints {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "kebab-case")]
+        struct Table<'a> {
+            policy: EndpointPolicy,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            quorum: Option<usize>,
+            quorum_methods: &'a [String],
+            endpoints: &'a [RpcEndpoint],
+        }
+
+        if self.is_single_url() {
+            return self.primary_url().serialize(serializer);
+        }
+
+        Table {
+            policy: self.policy,
+            quorum: self.quorum,
+            quorum_methods: &self.quorum_methods,
+            endpoints: &self.endpoints,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RpcEndpointsRepr {
+    Url(Url),
+    #[serde(rename_all = "kebab-case")]
+    Table {
+        endpoints: Vec<RpcEndpoint>,
+        #[serde(default)]
+        policy: EndpointPolicy,
+        #[serde(default)]
+        quorum: Option<usize>,
+        #[serde(default = "default_quorum_methods")]
+        quorum_methods: Vec<String>,
+    },
+}
+
+impl TryFrom<RpcEndpointsRepr> for RpcEndpoints {
+    type Error = String;
+
+    fn try_from(repr: RpcEndpointsRepr) -> Result<Self, Self::Error> {
+        match repr {
+            RpcEndpointsRepr::Url(url) => Ok(url.into()),
+            RpcEndpointsRepr::Table {
+                endpoints,
+                policy,
+                quorum,
+                quorum_methods,
+            } => Self {
+                endpoints,
+                policy,
+                quorum,
+                quorum_methods,
+            }
+            .validate(),
+        }
+    }
+}
+
+fn default_initial_backoff_ms() -> u64 {
+    DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS
+}
+
+fn default_max_retries() -> u32 {
+    DEFAULT_HTTP_RPC_NODE_BACKOFF_MAX_RETRIES
+}
+
+/// Contract reads and L2 outputs, which the proofs commit to.
+fn default_quorum_methods() -> Vec<String> {
+    vec!["eth_call".to_string(), "optimism_outputAtBlock".to_string()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    #[serde(rename_all = "kebab-case")]
+    struct Config {
+        rpc_endpoint: RpcEndpoints,
+    }
+
+    #[test]
+    fn single_url_roundtrips_as_plain_url() {
+        let input = "rpc-endpoint = \"http://l1:8545/\"\n";
+
+        let config: Config = toml::from_str(input).unwrap();
+        assert_eq!(
+            config.rpc_endpoint,
+            RpcEndpoints::from_str("http://l1:8545").unwrap()
+        );
+        assert_eq!(toml::to_string(&config).unwrap(), input);
+    }
+
+    #[test]
+    fn endpoints_table() {
+        let config: Config = toml::from_str(
+            r#"
+            [rpc-endpoint]
+            policy = "quorum"
+            endpoints = [
+                "http://l1-a:8545",
+                { url = "http://l1-b:8545", initial-backoff-ms = 100, max-retries = 2 },
+                "http://l1-c:8545",
+            ]
+            "#,
+        )
+        .unwrap();
+
+        let endpoints = config.rpc_endpoint;
+        assert_eq!(endpoints.policy, EndpointPolicy::Quorum);
+        assert_eq!(endpoints.quorum_size(), 2);
+        assert_eq!(endpoints.primary_url().as_str(), "http://l1-a:8545/");
+        assert_eq!(
+            endpoints.endpoints[0].initial_backoff_ms,
+            DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS
+        );
+        assert_eq!(endpoints.endpoints[1].initial_backoff_ms, 100);
+        assert_eq!(endpoints.endpoints[1].max_retries, 2);
+
+        let roundtrip: Config = toml::from_str(
+            &toml::to_string(&Config {
+                rpc_endpoint: endpoints.clone(),
+            })
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(roundtrip.rpc_endpoint, endpoints);
+    }
+
+    #[test]
+    fn rejects_invalid_endpoints() {
+        assert!(toml::from_str::<Config>("[rpc-endpoint]\nendpoints = []\n").is_err());
+        assert!(toml::from_str::<Config>(
+            "[rpc-endpoint]\nquorum = 3\nendpoints = [\"http://a\", \"http://b\"]\n"
+        )
+        .is_err());
+    }
+}
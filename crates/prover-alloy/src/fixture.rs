@@ -0,0 +1,296 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use alloy::{
+    rpc::json_rpc::{RequestPacket, Response, ResponsePacket, SerializedRequest},
+    transports::{BoxTransport, TransportError, TransportErrorKind, TransportFut, TransportResult},
+};
+use eyre::Context as _;
+use serde::{Deserialize, Serialize};
+use tower::Service;
+use tracing::warn;
+
+/// Whether the JSON-RPC traffic is recorded to a fixture or replayed from it.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RpcFixtureMode {
+    /// Forward the requests to the nodes and record their responses.
+    Record,
+    /// Answer the requests with the recorded responses, without any network
+    /// access.
+    Replay,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RpcFixtureConfig {
+    pub mode: RpcFixtureMode,
+
+    /// Fixture file, overwritten when recording.
+    pub path: PathBuf,
+}
+
+/// Recorded responses, by node and then by request.
+type Recordings = BTreeMap<String, BTreeMap<String, serde_json::Value>>;
+
+/// JSON-RPC traffic recorded to a fixture file, for the tests to run against
+/// real chain data without any network access.
+///
+/// The responses are keyed by the method and parameters of their request, so
+/// the requests of a replay must be made with the same parameters as the
+/// recording. When recording, the fixture file is written once the last
+/// client using the fixture is dropped, or on [`RpcFixture::save`].
+#[derive(Clone)]
+pub struct RpcFixture {
+    inner: Arc<FixtureInner>,
+}
+
+struct FixtureInner {
+    mode: RpcFixtureMode,
+    path: PathBuf,
+    recordings: Mutex<Recordings>,
+}
+
+impl RpcFixture {
+    pub fn open(config: &RpcFixtureConfig) -> eyre::Result<Self> {
+        let recordings = match config.mode {
+            RpcFixtureMode::Record => Recordings::default(),
+            RpcFixtureMode::Replay => {
+                let file = File::open(&config.path).with_context(|| {
+                    format!("Unable to open the rpc fixture {}", config.path.display())
+                })?;
+                serde_json::from_reader(BufReader::new(file)).with_context(|| {
+                    format!("Unable to parse the rpc fixture {}", config.path.display())
+                })?
+            }
+        };
+
+        Ok(Self {
+            inner: Arc::new(FixtureInner {
+                mode: config.mode,
+                path: config.path.clone(),
+                recordings: Mutex::new(recordings),
+            }),
+        })
+    }
+
+    pub fn mode(&self) -> RpcFixtureMode {
+        self.inner.mode
+    }
+
+    /// Writes the recorded traffic to the fixture file.
+    pub fn save(&self) -> eyre::Result<()> {
+        self.inner.save()
+    }
+
+    /// Wraps the `node` transport, recording or replaying its traffic.
+    pub(crate) fn transport(&self, node: &'static str, inner: BoxTransport) -> FixtureTransport {
+        FixtureTransport {
+            node,
+            fixture: self.clone(),
+            inner,
+        }
+    }
+
+    fn record(&self, node: &str, request: &RequestPacket, response: &ResponsePacket) {
+        let mut recordings = self.inner.lock();
+        let node_recordings = recordings.entry(node.to_string()).or_default();
+
+        for request in request.requests() {
+            let Some(response) = response
+                .responses()
+                .iter()
+                .find(|response| &response.id == request.id())
+            else {
+                continue;
+            };
+
+            match serde_json::to_value(response) {
+                Ok(mut response) => {
+                    if let Some(response) = response.as_object_mut() {
+                        response.remove("id");
+                    }
+                    node_recordings.insert(request_key(request), response);
+                }
+                Err(error) => warn!(node, "Unable to record the rpc response: {error}"),
+            }
+        }
+    }
+
+    fn replay(&self, node: &str, request: &RequestPacket) -> TransportResult<ResponsePacket> {
+        let recordings = self.inner.lock();
+
+        let mut responses = request
+            .requests()
+            .iter()
+            .map(|request| {
+                let key = request_key(request);
+                let mut response = recordings
+                    .get(node)
+                    .and_then(|node_recordings| node_recordings.get(&key))
+                    .cloned()
+                    .ok_or_else(|| {
+                        TransportErrorKind::custom_str(&format!(
+                            "No recorded response of the {node} node to {key}"
+                        ))
+                    })?;
+                response["id"] =
+                    serde_json::to_value(request.id()).map_err(TransportError::ser_err)?;
+
+                serde_json::from_str::<Response>(&response.to_string())
+                    .map_err(TransportErrorKind::custom)
+            })
+            .collect::<TransportResult<Vec<_>>>()?;
+
+        match request {
+            RequestPacket::Single(_) => responses
+                .pop()
+                .map(ResponsePacket::Single)
+                .ok_or_else(|| TransportErrorKind::custom_str("Empty rpc request")),
+            RequestPacket::Batch(_) => Ok(ResponsePacket::Batch(responses)),
+        }
+    }
+}
+
+impl FixtureInner {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Recordings> {
+        self.recordings
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn save(&self) -> eyre::Result<()> {
+        if self.mode != RpcFixtureMode::Record {
+            return Ok(());
+        }
+
+        let file = File::create(&self.path)
+            .with_context(|| format!("Unable to create the rpc fixture {}", self.path.display()))?;
+        serde_json::to_writer_pretty(BufWriter::new(file), &*self.lock())
+            .with_context(|| format!("Unable to write the rpc fixture {}", self.path.display()))?;
+
+        Ok(())
+    }
+}
+
+impl Drop for FixtureInner {
+    fn drop(&mut self) {
+        if let Err(error) = self.save() {
+            warn!("Unable to save the rpc fixture: {error:?}");
+        }
+    }
+}
+
+/// Key of the recorded response to `request`.
+fn request_key(request: &SerializedRequest) -> String {
+    match request.params() {
+        Some(params) => format!("{} {}", request.method(), params.get()),
+        None => request.method().to_string(),
+    }
+}
+
+/// Transport recording the traffic of a node to an [`RpcFixture`], or
+/// replaying it from there.
+#[derive(Clone)]
+pub(crate) struct FixtureTransport {
+    node: &'static str,
+    fixture: RpcFixture,
+    inner: BoxTransport,
+}
+
+impl Service<RequestPacket> for FixtureTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let transport = self.clone();
+
+        Box::pin(async move {
+            match transport.fixture.mode() {
+                RpcFixtureMode::Replay => transport.fixture.replay(transport.node, &request),
+                RpcFixtureMode::Record => {
+                    let response = transport.inner.clone().call(request.clone()).await?;
+                    transport
+                        .fixture
+                        .record(transport.node, &request, &response);
+
+                    Ok(response)
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::rpc::client::RpcClient;
+    use url::Url;
+
+    use super::*;
+    use crate::{build_rpc_client, RpcEndpoints};
+
+    fn client(url: &str, fixture: &RpcFixture) -> RpcClient {
+        let endpoints = RpcEndpoints::from(Url::parse(url).unwrap());
+
+        build_rpc_client("l1", &endpoints, Some(fixture)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn replays_the_recorded_traffic() {
+        let path = std::env::temp_dir().join(format!("rpc-fixture-{}.json", std::process::id()));
+
+        let mut node = mockito::Server::new_async().await;
+        let mock = node
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"jsonrpc":"2.0","id":0,"result":"0x2a"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let recording = RpcFixture::open(&RpcFixtureConfig {
+            mode: RpcFixtureMode::Record,
+            path: path.clone(),
+        })
+        .unwrap();
+        let block_number: String = client(&node.url(), &recording)
+            .request_noparams("eth_blockNumber")
+            .await
+            .unwrap();
+        assert_eq!(block_number, "0x2a");
+        drop(recording);
+        mock.assert_async().await;
+        drop(node);
+
+        let replay = RpcFixture::open(&RpcFixtureConfig {
+            mode: RpcFixtureMode::Replay,
+            path: path.clone(),
+        })
+        .unwrap();
+        let client = client("http://0.0.0.0:0", &replay);
+
+        // The ids of the replayed responses follow the requests.
+        for _ in 0..2 {
+            let block_number: String = client.request_noparams("eth_blockNumber").await.unwrap();
+            assert_eq!(block_number, "0x2a");
+        }
+        assert!(client
+            .request::<_, String>("eth_getBalance", ("0x00", "latest"))
+            .await
+            .is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -7,8 +7,11 @@ use alloy::{
         fillers::{BlobGasFiller, ChainIdFiller, FillProvider, GasFiller, JoinFill, NonceFiller},
         Identity, ProviderBuilder, RootProvider,
     },
-    rpc::client::ClientBuilder,
-    transports::{http::reqwest, layers::RetryBackoffLayer},
+    rpc::client::{ClientBuilder, RpcClient},
+    transports::{
+        http::{reqwest, Http},
+        BoxTransport,
+    },
 };
 pub use async_trait::async_trait;
 use derive_more::{From, FromStr};
@@ -16,6 +19,16 @@ use educe::Educe;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+pub use crate::{
+    endpoints::{EndpointPolicy, RpcEndpoint, RpcEndpoints},
+    fixture::{RpcFixture, RpcFixtureConfig, RpcFixtureMode},
+    transport::MultiEndpointTransport,
+};
+
+mod endpoints;
+mod fixture;
+mod transport;
+
 const HTTP_CLIENT_CONNECTION_POOL_IDLE_TIMEOUT: u64 = 90;
 const HTTP_CLIENT_MAX_IDLE_CONNECTIONS_PER_HOST: usize = 64;
 pub const DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS: u64 = 5000;
@@ -30,12 +43,23 @@ pub type AlloyFillProvider = FillProvider<
     Ethereum,
 >;
 
+/// Builds the provider of the `node` endpoints, labelling the endpoint metrics
+/// with `node`, and recording or replaying its traffic with the `fixture`.
 pub fn build_alloy_fill_provider(
-    rpc_url: &url::Url,
-    backoff: u64,
-    max_retries: u32,
+    node: &'static str,
+    endpoints: &RpcEndpoints,
+    fixture: Option<&RpcFixture>,
 ) -> eyre::Result<AlloyFillProvider> {
-    let retry_policy = RetryBackoffLayer::new(max_retries, backoff, 5);
+    Ok(ProviderBuilder::new().connect_client(build_rpc_client(node, endpoints, fixture)?))
+}
+
+/// Builds the raw JSON-RPC client of the `node` endpoints, for the methods
+/// not covered by the alloy `Provider`.
+pub fn build_rpc_client(
+    node: &'static str,
+    endpoints: &RpcEndpoints,
+    fixture: Option<&RpcFixture>,
+) -> eyre::Result<RpcClient> {
     let reqwest_client = reqwest::ClientBuilder::new()
         .pool_max_idle_per_host(HTTP_CLIENT_MAX_IDLE_CONNECTIONS_PER_HOST)
         .pool_idle_timeout(Duration::from_secs(
@@ -43,13 +67,18 @@ pub fn build_alloy_fill_provider(
         ))
         .build()?;
 
-    let http = alloy::transports::http::Http::with_client(reqwest_client, rpc_url.clone());
-    let is_local = http.guess_local();
-    let client = ClientBuilder::default()
-        .layer(retry_policy)
-        .transport(http, is_local);
+    let is_local = endpoints.endpoints.iter().all(|endpoint| {
+        Http::with_client(reqwest_client.clone(), endpoint.url.clone()).guess_local()
+    });
+    let transport = MultiEndpointTransport::new(node, endpoints, reqwest_client)?;
 
-    Ok(ProviderBuilder::new().connect_client(client))
+    Ok(match fixture {
+        Some(fixture) => ClientBuilder::default().transport(
+            fixture.transport(node, BoxTransport::new(transport)),
+            is_local,
+        ),
+        None => ClientBuilder::default().transport(transport, is_local),
+    })
 }
 
 /// Wrapper around alloy `Provider` client.
@@ -60,23 +89,9 @@ pub struct AlloyProvider {
 }
 
 impl AlloyProvider {
-    pub fn new(rpc_url: &url::Url, backoff: u64, max_retries: u32) -> eyre::Result<AlloyProvider> {
-        let retry_policy = RetryBackoffLayer::new(max_retries, backoff, 5);
-        let reqwest_client = reqwest::ClientBuilder::new()
-            .pool_max_idle_per_host(HTTP_CLIENT_MAX_IDLE_CONNECTIONS_PER_HOST)
-            .pool_idle_timeout(Duration::from_secs(
-                HTTP_CLIENT_CONNECTION_POOL_IDLE_TIMEOUT,
-            ))
-            .build()?;
-
-        let http = alloy::transports::http::Http::with_client(reqwest_client, rpc_url.clone());
-        let is_local = http.guess_local();
-        let client = ClientBuilder::default()
-            .layer(retry_policy)
-            .transport(http, is_local);
-
+    pub fn new(node: &'static str, endpoints: &RpcEndpoints) -> eyre::Result<AlloyProvider> {
         Ok(AlloyProvider {
-            client: ProviderBuilder::new().connect_client(client),
+            client: build_alloy_fill_provider(node, endpoints, None)?,
         })
     }
 }
@@ -91,14 +106,22 @@ impl AlloyRpc for AlloyProvider {
 #[serde(transparent)]
 #[educe(Default)]
 pub struct L1RpcEndpoint {
-    #[educe(Default = Url::from_str("http://anvil-mock-l1-rpc:8545").unwrap())]
-    pub url: Url,
+    #[educe(Default = RpcEndpoints::from_str("http://anvil-mock-l1-rpc:8545").unwrap())]
+    pub endpoints: RpcEndpoints,
+}
+
+impl From<Url> for L1RpcEndpoint {
+    fn from(url: Url) -> Self {
+        Self {
+            endpoints: url.into(),
+        }
+    }
 }
 
-pub fn default_l2_execution_layer_url() -> Url {
-    Url::from_str("http://anvil-mock-l2-rpc:8545").unwrap()
+pub fn default_l2_execution_layer_endpoints() -> RpcEndpoints {
+    RpcEndpoints::from_str("http://anvil-mock-l2-rpc:8545").unwrap()
 }
 
-pub fn default_l2_consensus_layer_url() -> Url {
-    Url::from_str("http://rollup-node-mock-l2-rpc:8545").unwrap()
+pub fn default_l2_consensus_layer_endpoints() -> RpcEndpoints {
+    RpcEndpoints::from_str("http://rollup-node-mock-l2-rpc:8545").unwrap()
 }
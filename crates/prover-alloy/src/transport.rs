@@ -0,0 +1,585 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use alloy::{
+    rpc::json_rpc::{RequestPacket, ResponsePacket},
+    transports::{
+        http::{reqwest, Http},
+        layers::RetryBackoffLayer,
+        BoxTransport, TransportError, TransportErrorKind, TransportFut, TransportResult,
+    },
+};
+use futures::{stream::FuturesUnordered, StreamExt as _};
+use opentelemetry::{
+    global,
+    metrics::{Counter, Gauge},
+    KeyValue,
+};
+use prover_logger::propagation::TraceContextLayer;
+use tower::{Service, ServiceBuilder};
+use tracing::warn;
+use url::Url;
+
+use crate::{EndpointPolicy, RpcEndpoints};
+
+/// Time during which a failed endpoint is only tried after the healthy ones.
+/// It then gets its place in the order back, so that a recovered endpoint
+/// gets its traffic back.
+const UNHEALTHY_ENDPOINT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Transport spreading the JSON-RPC requests to a node over its endpoints,
+/// following the [`EndpointPolicy`] of the [`RpcEndpoints`].
+///
+/// Each endpoint retries its own failed requests with its backoff before the
+/// request moves on to the next endpoint.
+#[derive(Clone)]
+pub struct MultiEndpointTransport {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    node: &'static str,
+    endpoints: Vec<Endpoint>,
+    policy: EndpointPolicy,
+    quorum: usize,
+    quorum_methods: HashSet<String>,
+    next: AtomicUsize,
+    unhealthy_cooldown: Duration,
+    metrics: Metrics,
+}
+
+struct Endpoint {
+    transport: BoxTransport,
+    /// Host and port of the url. Its path and credentials often carry an api
+    /// key, so they are kept out of the logs and metrics.
+    label: String,
+    /// When the last request to the endpoint failed, if it did.
+    failed_at: Mutex<Option<Instant>>,
+}
+
+struct Metrics {
+    requests: Counter<u64>,
+    healthy: Gauge<u64>,
+    quorum_disagreements: Counter<u64>,
+}
+
+impl MultiEndpointTransport {
+    /// Creates the transport to the `node` endpoints, labelling its metrics
+    /// with `node`.
+    pub fn new(
+        node: &'static str,
+        endpoints: &RpcEndpoints,
+        client: reqwest::Client,
+    ) -> eyre::Result<Self> {
+        Self::with_unhealthy_cooldown(node, endpoints, client, UNHEALTHY_ENDPOINT_COOLDOWN)
+    }
+
+    pub(crate) fn with_unhealthy_cooldown(
+        node: &'static str,
+        endpoints: &RpcEndpoints,
+        client: reqwest::Client,
+        unhealthy_cooldown: Duration,
+    ) -> eyre::Result<Self> {
+        if endpoints.endpoints.is_empty() {
+            eyre::bail!("No rpc endpoint configured for the {node} node");
+        }
+
+        let quorum = endpoints.quorum_size();
+        if quorum == 0 || quorum > endpoints.endpoints.len() {
+            eyre::bail!(
+                "Invalid quorum of {quorum} out of {} rpc endpoints for the {node} node",
+                endpoints.endpoints.len()
+            );
+        }
+
+        let endpoints_transports = endpoints
+            .endpoints
+            .iter()
+            .map(|endpoint| {
+                let http = Http::with_client(client.clone(), endpoint.url.clone());
+                let transport = ServiceBuilder::new()
+                    .layer(RetryBackoffLayer::new(
+                        endpoint.max_retries,
+                        endpoint.initial_backoff_ms,
+                        5,
+                    ))
+                    .layer(TraceContextLayer)
+                    .service(http);
+
+                Endpoint {
+                    transport: BoxTransport::new(transport),
+                    label: endpoint_label(&endpoint.url),
+                    failed_at: Mutex::new(None),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                node,
+                endpoints: endpoints_transports,
+                policy: endpoints.policy,
+                quorum,
+                quorum_methods: endpoints.quorum_methods.iter().cloned().collect(),
+                next: AtomicUsize::new(0),
+                unhealthy_cooldown,
+                metrics: Metrics::new(),
+            }),
+        })
+    }
+}
+
+impl Service<RequestPacket> for MultiEndpointTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // The endpoints are polled when the request reaches them.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let inner = self.inner.clone();
+
+        Box::pin(async move {
+            if inner.requires_quorum(&request) {
+                inner.call_quorum(request).await
+            } else {
+                inner.call_failover(request).await
+            }
+        })
+    }
+}
+
+impl Inner {
+    fn requires_quorum(&self, request: &RequestPacket) -> bool {
+        self.policy == EndpointPolicy::Quorum
+            && request
+                .method_names()
+                .any(|method| self.quorum_methods.contains(method))
+    }
+
+    /// Indices of the endpoints in the order a request tries them, the
+    /// healthy ones first. An endpoint which failed less than the cooldown
+    /// ago is unhealthy.
+    fn order(&self) -> Vec<usize> {
+        let len = self.endpoints.len();
+        let start = match self.policy {
+            EndpointPolicy::RoundRobin => self.next.fetch_add(1, Ordering::Relaxed) % len,
+            EndpointPolicy::Failover | EndpointPolicy::Quorum => 0,
+        };
+
+        let (healthy, unhealthy): (Vec<_>, Vec<_>) = (0..len)
+            .map(|offset| (start + offset) % len)
+            .partition(|index| self.endpoints[*index].is_healthy(self.unhealthy_cooldown));
+
+        healthy.into_iter().chain(unhealthy).collect()
+    }
+
+    async fn call_failover(&self, request: RequestPacket) -> TransportResult<ResponsePacket> {
+        let mut last_error = None;
+        for index in self.order() {
+            match self.call_endpoint(index, request.clone()).await {
+                Ok(response) => return Ok(response),
+                Err(error) => {
+                    warn!(
+                        node = self.node,
+                        endpoint = %self.endpoints[index].label,
+                        "Request to the rpc endpoint failed: {error}"
+                    );
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| TransportErrorKind::custom_str("No rpc endpoint")))
+    }
+
+    /// Sends `request` to all the endpoints, returning the response as soon as
+    /// `quorum` of them returned it, without waiting for the others.
+    async fn call_quorum(&self, request: RequestPacket) -> TransportResult<ResponsePacket> {
+        let mut pending: FuturesUnordered<_> = (0..self.endpoints.len())
+            .map(|index| {
+                let request = request.clone();
+                async move { (index, self.call_endpoint(index, request).await) }
+            })
+            .collect();
+
+        // Endpoints grouped by the response they returned, and all the
+        // endpoints which returned a response.
+        let mut groups: Vec<(Vec<serde_json::Value>, Vec<usize>)> = Vec::new();
+        let mut answered = Vec::new();
+        while let Some((index, result)) = pending.next().await {
+            match result {
+                Ok(response) => {
+                    answered.push(index);
+                    if let Some(key) = comparable_responses(&response) {
+                        let group = match groups.iter().position(|(group_key, _)| *group_key == key)
+                        {
+                            Some(group) => group,
+                            None => {
+                                groups.push((key, Vec::new()));
+                                groups.len() - 1
+                            }
+                        };
+                        let agreeing = &mut groups[group].1;
+                        agreeing.push(index);
+
+                        if agreeing.len() >= self.quorum {
+                            self.report_disagreements(&answered, agreeing);
+                            return Ok(response);
+                        }
+                    }
+                }
+                Err(error) => {
+                    warn!(
+                        node = self.node,
+                        endpoint = %self.endpoints[index].label,
+                        "Request to the rpc endpoint failed: {error}"
+                    );
+                }
+            }
+
+            // No need to wait for the other endpoints once the quorum is out of
+            // reach.
+            let largest = groups
+                .iter()
+                .map(|(_, indices)| indices.len())
+                .max()
+                .unwrap_or_default();
+            if largest + pending.len() < self.quorum {
+                break;
+            }
+        }
+
+        let methods = request.method_names().collect::<Vec<_>>().join(", ");
+        Err(TransportErrorKind::custom_str(&format!(
+            "No quorum of {} rpc endpoints of the {} node agreed on the response to {methods}",
+            self.quorum, self.node
+        )))
+    }
+
+    /// Reports the endpoints which `answered` otherwise than the `agreeing`
+    /// ones.
+    fn report_disagreements(&self, answered: &[usize], agreeing: &[usize]) {
+        for index in answered.iter().filter(|index| !agreeing.contains(index)) {
+            let endpoint = &self.endpoints[*index];
+            warn!(
+                node = self.node,
+                endpoint = %endpoint.label,
+                "Rpc endpoint response disagrees with the quorum"
+            );
+            self.metrics
+                .quorum_disagreements
+                .add(1, &self.attributes(endpoint));
+        }
+    }
+
+    async fn call_endpoint(
+        &self,
+        index: usize,
+        request: RequestPacket,
+    ) -> TransportResult<ResponsePacket> {
+        let endpoint = &self.endpoints[index];
+        let result = endpoint.transport.clone().call(request).await;
+
+        let attributes = self.attributes(endpoint);
+        let outcome = if result.is_ok() { "success" } else { "failure" };
+        self.metrics.requests.add(
+            1,
+            &[attributes.as_slice(), &[KeyValue::new("outcome", outcome)]].concat(),
+        );
+        self.metrics
+            .healthy
+            .record(u64::from(result.is_ok()), &attributes);
+        *endpoint.lock_failed_at() = result.is_err().then(Instant::now);
+
+        result
+    }
+
+    fn attributes(&self, endpoint: &Endpoint) -> [KeyValue; 2] {
+        [
+            KeyValue::new("node", self.node),
+            KeyValue::new("endpoint", endpoint.label.clone()),
+        ]
+    }
+}
+
+impl Endpoint {
+    fn is_healthy(&self, cooldown: Duration) -> bool {
+        self.lock_failed_at()
+            .is_none_or(|failed_at| failed_at.elapsed() >= cooldown)
+    }
+
+    fn lock_failed_at(&self) -> std::sync::MutexGuard<'_, Option<Instant>> {
+        // The instant is always left consistent, so a poisoned lock is safe to
+        // recover.
+        self.failed_at
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let meter = global::meter("prover_alloy");
+
+        Self {
+            requests: meter
+                .u64_counter("prover_rpc_endpoint_requests")
+                .with_description("Number of requests sent to an rpc endpoint, by outcome")
+                .build(),
+            healthy: meter
+                .u64_gauge("prover_rpc_endpoint_healthy")
+                .with_description("Whether the last request to an rpc endpoint succeeded")
+                .build(),
+            quorum_disagreements: meter
+                .u64_counter("prover_rpc_endpoint_quorum_disagreements")
+                .with_description(
+                    "Number of responses of an rpc endpoint disagreeing with the quorum",
+                )
+                .build(),
+        }
+    }
+}
+
+/// Responses of a packet in a form comparable across endpoints, independent
+/// of the formatting of the node and of the order of the batch responses.
+fn comparable_responses(response: &ResponsePacket) -> Option<Vec<serde_json::Value>> {
+    let mut responses = response
+        .responses()
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    responses.sort_by_key(|response| response["id"].to_string());
+
+    Some(responses)
+}
+
+fn endpoint_label(url: &Url) -> String {
+    match (url.host_str(), url.port_or_known_default()) {
+        (Some(host), Some(port)) => format!("{host}:{port}"),
+        (Some(host), None) => host.to_string(),
+        (None, _) => url.scheme().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::rpc::client::RpcClient;
+    use mockito::{Mock, ServerGuard};
+    use serde_json::json;
+
+    use super::*;
+    use crate::{build_rpc_client, RpcEndpoint};
+
+    /// Node answering all the requests with `result`.
+    async fn node(result: &'static str) -> (ServerGuard, Mock) {
+        let mut server = mockito::Server::new_async().await;
+        let mock = answer(&mut server, result).await;
+
+        (server, mock)
+    }
+
+    /// Makes `server` answer all the requests with `result`.
+    async fn answer(server: &mut ServerGuard, result: &'static str) -> Mock {
+        server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(move |request| {
+                let request: serde_json::Value =
+                    serde_json::from_slice(request.body().unwrap()).unwrap();
+                json!({ "jsonrpc": "2.0", "id": request["id"], "result": result })
+                    .to_string()
+                    .into()
+            })
+            .create_async()
+            .await
+    }
+
+    fn client(policy: EndpointPolicy, quorum: Option<usize>, nodes: &[&ServerGuard]) -> RpcClient {
+        let endpoints = RpcEndpoints {
+            endpoints: nodes
+                .iter()
+                .map(|node| RpcEndpoint {
+                    url: Url::parse(&node.url()).unwrap(),
+                    initial_backoff_ms: 0,
+                    max_retries: 0,
+                })
+                .collect(),
+            policy,
+            quorum,
+            quorum_methods: vec!["eth_call".to_string()],
+        };
+
+        build_rpc_client("test", &endpoints, None).unwrap()
+    }
+
+    #[tokio::test]
+    async fn fails_over_to_the_next_endpoint() {
+        let mut down = mockito::Server::new_async().await;
+        let down_mock = down.mock("POST", "/").with_status(500).create_async().await;
+        let (up, up_mock) = node("0x1").await;
+
+        let client = client(EndpointPolicy::Failover, None, &[&down, &up]);
+        let block_number: String = client.request_noparams("eth_blockNumber").await.unwrap();
+
+        assert_eq!(block_number, "0x1");
+        down_mock.assert_async().await;
+        up_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn recovered_endpoint_gets_the_traffic_back_after_the_cooldown() {
+        let cooldown = Duration::from_millis(200);
+        let mut primary = mockito::Server::new_async().await;
+        let failure = primary
+            .mock("POST", "/")
+            .with_status(500)
+            .create_async()
+            .await;
+        let (backup, _backup_mock) = node("0x2").await;
+
+        let endpoints = RpcEndpoints {
+            endpoints: [&primary, &backup]
+                .iter()
+                .map(|node| RpcEndpoint {
+                    url: Url::parse(&node.url()).unwrap(),
+                    initial_backoff_ms: 0,
+                    max_retries: 0,
+                })
+                .collect(),
+            policy: EndpointPolicy::Failover,
+            quorum: None,
+            quorum_methods: Vec::new(),
+        };
+        let transport = MultiEndpointTransport::with_unhealthy_cooldown(
+            "test",
+            &endpoints,
+            reqwest::Client::new(),
+            cooldown,
+        )
+        .unwrap();
+        let client = RpcClient::new(transport, true);
+
+        let block_number: String = client.request_noparams("eth_blockNumber").await.unwrap();
+        assert_eq!(block_number, "0x2");
+
+        // The recovered primary waits for the cooldown before getting the
+        // requests again.
+        failure.remove_async().await;
+        let recovered = answer(&mut primary, "0x1").await;
+        let block_number: String = client.request_noparams("eth_blockNumber").await.unwrap();
+        assert_eq!(block_number, "0x2");
+        assert!(!recovered.matched_async().await);
+
+        tokio::time::sleep(cooldown).await;
+        let block_number: String = client.request_noparams("eth_blockNumber").await.unwrap();
+        assert_eq!(block_number, "0x1");
+    }
+
+    #[tokio::test]
+    async fn round_robin_rotates_the_endpoints() {
+        let (first, first_mock) = node("0x1").await;
+        let (second, second_mock) = node("0x2").await;
+
+        let client = client(EndpointPolicy::RoundRobin, None, &[&first, &second]);
+        let mut block_numbers = Vec::new();
+        for _ in 0..2 {
+            let block_number: String = client.request_noparams("eth_blockNumber").await.unwrap();
+            block_numbers.push(block_number);
+        }
+
+        assert_eq!(block_numbers, ["0x1", "0x2"]);
+        first_mock.assert_async().await;
+        second_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn quorum_outvotes_a_diverging_endpoint() {
+        let (first, _first_mock) = node("0x1").await;
+        let (diverging, _diverging_mock) = node("0x2").await;
+        let (third, _third_mock) = node("0x1").await;
+
+        let client = client(EndpointPolicy::Quorum, None, &[&first, &diverging, &third]);
+        let output: String = client.request_noparams("eth_call").await.unwrap();
+
+        assert_eq!(output, "0x1");
+    }
+
+    #[tokio::test]
+    async fn quorum_does_not_wait_for_a_hanging_endpoint() {
+        let (first, _first_mock) = node("0x1").await;
+        let (second, _second_mock) = node("0x1").await;
+        // Accepts the connections but never answers.
+        let hanging = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+        let endpoints = RpcEndpoints {
+            endpoints: [
+                format!("http://{}", hanging.local_addr().unwrap()),
+                first.url(),
+                second.url(),
+            ]
+            .iter()
+            .map(|url| RpcEndpoint {
+                url: Url::parse(url).unwrap(),
+                initial_backoff_ms: 0,
+                max_retries: 0,
+            })
+            .collect(),
+            policy: EndpointPolicy::Quorum,
+            quorum: Some(2),
+            quorum_methods: vec!["eth_call".to_string()],
+        };
+        let client = build_rpc_client("test", &endpoints, None).unwrap();
+
+        let output: String = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            client.request_noparams("eth_call"),
+        )
+        .await
+        .expect("quorum waited for the hanging endpoint")
+        .unwrap();
+
+        assert_eq!(output, "0x1");
+    }
+
+    #[tokio::test]
+    async fn quorum_not_reached() {
+        let (first, _first_mock) = node("0x1").await;
+        let (diverging, _diverging_mock) = node("0x2").await;
+        let (third, _third_mock) = node("0x1").await;
+
+        let client = client(
+            EndpointPolicy::Quorum,
+            Some(3),
+            &[&first, &diverging, &third],
+        );
+        let result = client.request_noparams::<String>("eth_call").await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn quorum_only_applies_to_the_quorum_methods() {
+        let (first, first_mock) = node("0x1").await;
+        let (second, second_mock) = node("0x2").await;
+
+        let client = client(EndpointPolicy::Quorum, Some(2), &[&first, &second]);
+        let block_number: String = client.request_noparams("eth_blockNumber").await.unwrap();
+
+        assert_eq!(block_number, "0x1");
+        first_mock.assert_async().await;
+        assert!(!second_mock.matched_async().await);
+    }
+}
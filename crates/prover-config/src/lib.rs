@@ -1,4 +1,4 @@
-use std::{str::FromStr, time::Duration};
+use std::{path::PathBuf, str::FromStr, time::Duration};
 
 use prover_utils::{from_env_or_default, with};
 use serde::{Deserialize, Serialize};
@@ -8,6 +8,9 @@ use url::Url;
 /// The default url endpoint for the grpc cluster service
 const DEFAULT_SP1_CLUSTER_ENDPOINT: &str = "https://rpc.production.succinct.xyz/";
 
+/// The environment variable the network private key is read from by default
+const DEFAULT_NETWORK_PRIVATE_KEY_ENV: &str = "NETWORK_PRIVATE_KEY";
+
 /// Type of the prover to be used for generation of the pessimistic proof
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
@@ -69,18 +72,71 @@ pub struct NetworkProverConfig {
     #[serde(with = "crate::with::HumanDuration")]
     pub proving_timeout: Duration,
 
+    /// Proving timeout of the stark proofs, the proving timeout if not set.
+    #[serde_as(as = "Option<crate::with::HumanDuration>")]
+    pub stark_proving_timeout: Option<Duration>,
+
+    /// Proving timeout of the plonk proofs, the proving timeout if not set.
+    #[serde_as(as = "Option<crate::with::HumanDuration>")]
+    pub plonk_proving_timeout: Option<Duration>,
+
+    /// How the proof requests are fulfilled by the sp1 proving network.
+    #[serde(
+        default,
+        skip_serializing_if = "NetworkFulfillmentStrategy::is_default"
+    )]
+    pub fulfillment_strategy: NetworkFulfillmentStrategy,
+
+    /// Maximum price per prover gas unit (PGU) paid for a proof, in the
+    /// smallest unit of the network token. The network default if not set.
+    #[serde(default)]
+    pub max_price_per_pgu: Option<u64>,
+
+    /// Maximum number of cycles of a proven execution. The simulated number
+    /// of cycles if not set.
+    #[serde(default)]
+    pub cycle_limit: Option<u64>,
+
+    /// Whether the proof requests are sent without simulating the execution
+    /// locally first, which the cycle limit should be set along with.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skip_simulation: bool,
+
+    /// What happens to a proof request on the sp1 proving network once its
+    /// caller gives up on it.
+    #[serde(default, skip_serializing_if = "CancellationPolicy::is_default")]
+    pub cancellation_policy: CancellationPolicy,
+
     /// The sp1 proving cluster endpoint.
     #[serde(default = "default_sp1_cluster_endpoint")]
     pub sp1_cluster_endpoint: url::Url,
+
+    /// Where the key signing the requests to the sp1 proving network is
+    /// read from.
+    #[serde(default, skip_serializing_if = "NetworkKeyConfig::is_default")]
+    pub private_key: NetworkKeyConfig,
 }
 
 impl NetworkProverConfig {
     // This constant represents the number of second added to the proving_timeout
     pub const DEFAULT_PROVING_TIMEOUT_PADDING: Duration = Duration::from_secs(1);
 
+    /// The proving request timeout, covering the longest of the proving
+    /// timeouts if not set.
     pub fn get_proving_request_timeout(&self) -> Duration {
-        self.proving_request_timeout
-            .unwrap_or_else(|| self.proving_timeout + Self::DEFAULT_PROVING_TIMEOUT_PADDING)
+        self.proving_request_timeout.unwrap_or_else(|| {
+            self.get_stark_proving_timeout()
+                .max(self.get_plonk_proving_timeout())
+                + Self::DEFAULT_PROVING_TIMEOUT_PADDING
+        })
+    }
+
+    pub fn get_stark_proving_timeout(&self) -> Duration {
+        self.stark_proving_timeout.unwrap_or(self.proving_timeout)
+    }
+
+    pub fn get_plonk_proving_timeout(&self) -> Duration {
+        self.plonk_proving_timeout.unwrap_or(self.proving_timeout)
     }
 }
 
@@ -89,11 +145,97 @@ impl Default for NetworkProverConfig {
         Self {
             proving_request_timeout: None,
             proving_timeout: default_network_proving_timeout(),
+            stark_proving_timeout: None,
+            plonk_proving_timeout: None,
+            fulfillment_strategy: NetworkFulfillmentStrategy::default(),
+            max_price_per_pgu: None,
+            cycle_limit: None,
+            skip_simulation: false,
+            cancellation_policy: CancellationPolicy::default(),
             sp1_cluster_endpoint: default_sp1_cluster_endpoint(),
+            private_key: NetworkKeyConfig::default(),
         }
     }
 }
 
+/// Fulfillment strategy of the proof requests sent to the sp1 proving
+/// network.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum NetworkFulfillmentStrategy {
+    /// Fulfilled by the capacity reserved for the requester.
+    #[default]
+    Reserved,
+
+    /// Fulfilled by the provers hosted by Succinct.
+    Hosted,
+
+    /// Auctioned to the provers of the network.
+    Auction,
+}
+
+impl NetworkFulfillmentStrategy {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// What happens to a remote proof request once its caller gives up on it,
+/// either disconnecting or timing out.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum CancellationPolicy {
+    /// The request is cancelled where the remote prover allows it, and
+    /// forgotten otherwise.
+    Cancel,
+
+    /// The request is left to finish, and its proof is reused by the next
+    /// request of the same proof.
+    #[default]
+    FinishAndCache,
+}
+
+impl CancellationPolicy {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Source of the private key signing the requests to the sp1 proving
+/// network.
+///
+/// Only the location of the key is configured, so that the key itself never
+/// shows up in the configuration.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum NetworkKeyConfig {
+    /// Hex encoded key, read from the given environment variable.
+    Env(String),
+
+    /// Hex encoded key, read from the given file.
+    File(PathBuf),
+
+    /// Encrypted JSON keystore, decrypted with the password read from
+    /// `password-file`.
+    #[serde(rename_all = "kebab-case")]
+    Keystore {
+        path: PathBuf,
+        password_file: PathBuf,
+    },
+}
+
+impl NetworkKeyConfig {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for NetworkKeyConfig {
+    fn default() -> Self {
+        NetworkKeyConfig::Env(DEFAULT_NETWORK_PRIVATE_KEY_ENV.to_string())
+    }
+}
+
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
@@ -1,5 +1,8 @@
 use pretty_assertions::assert_eq;
-use prover_config::{CpuProverConfig, MockProverConfig, NetworkProverConfig, ProverType};
+use prover_config::{
+    CancellationPolicy, CpuProverConfig, MockProverConfig, NetworkFulfillmentStrategy,
+    NetworkKeyConfig, NetworkProverConfig, ProverType,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -20,10 +23,93 @@ fn network_prover() {
             proving_request_timeout: Some(std::time::Duration::from_secs(300)),
             proving_timeout: std::time::Duration::from_secs(600),
             sp1_cluster_endpoint: url::Url::parse("https://rpc.production.succinct.xyz/").unwrap(),
+            ..Default::default()
         })
     );
 }
 
+#[test]
+fn network_prover_keystore() {
+    let input = "./tests/fixtures/validate_config/prover_config_network_prover_keystore.toml";
+    let config: TestConfig = toml::from_str(&std::fs::read_to_string(input).unwrap()).unwrap();
+
+    let ProverType::NetworkProver(network_prover_config) = &config.primary_prover else {
+        panic!("Expected a network prover, got {:?}", config.primary_prover);
+    };
+    assert_eq!(
+        network_prover_config.private_key,
+        NetworkKeyConfig::Keystore {
+            path: "/etc/aggkit-prover/network-keystore.json".into(),
+            password_file: "/etc/aggkit-prover/network-keystore.password".into(),
+        }
+    );
+
+    // Only the location of the key is serialized back.
+    let serialized = toml::to_string(&config).unwrap();
+    assert!(serialized.contains("password-file = \"/etc/aggkit-prover/network-keystore.password\""));
+}
+
+#[test]
+fn network_prover_key_file() {
+    let config: TestConfig = toml::from_str(
+        r#"
+        [primary-prover.network-prover]
+        private-key = { file = "/etc/aggkit-prover/network.key" }
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.primary_prover,
+        ProverType::NetworkProver(NetworkProverConfig {
+            private_key: NetworkKeyConfig::File("/etc/aggkit-prover/network.key".into()),
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn network_prover_fulfillment() {
+    let config: TestConfig = toml::from_str(
+        r#"
+        [primary-prover.network-prover]
+        proving-timeout = "10m"
+        plonk-proving-timeout = "30m"
+        fulfillment-strategy = "auction"
+        max-price-per-pgu = 2000000000
+        cycle-limit = 100000000
+        skip-simulation = true
+        cancellation-policy = "cancel"
+        "#,
+    )
+    .unwrap();
+
+    let ProverType::NetworkProver(network_prover_config) = config.primary_prover else {
+        panic!("Expected a network prover, got {:?}", config.primary_prover);
+    };
+    assert_eq!(
+        network_prover_config,
+        NetworkProverConfig {
+            proving_timeout: std::time::Duration::from_secs(600),
+            plonk_proving_timeout: Some(std::time::Duration::from_secs(1800)),
+            fulfillment_strategy: NetworkFulfillmentStrategy::Auction,
+            max_price_per_pgu: Some(2_000_000_000),
+            cycle_limit: Some(100_000_000),
+            skip_simulation: true,
+            cancellation_policy: CancellationPolicy::Cancel,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        network_prover_config.get_stark_proving_timeout(),
+        std::time::Duration::from_secs(600)
+    );
+    assert_eq!(
+        network_prover_config.get_proving_request_timeout(),
+        std::time::Duration::from_secs(1801)
+    );
+}
+
 #[test]
 fn cpu_prover() {
     let input = "./tests/fixtures/validate_config/prover_config_cpu_prover.toml";
@@ -50,6 +136,7 @@ fn network_and_cpu_prover() {
             proving_request_timeout: Some(std::time::Duration::from_secs(300)),
             proving_timeout: std::time::Duration::from_secs(600),
             sp1_cluster_endpoint: url::Url::parse("https://rpc.production.succinct.xyz/").unwrap(),
+            ..Default::default()
         })
     );
 
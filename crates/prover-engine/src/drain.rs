@@ -0,0 +1,216 @@
+use std::{
+    convert::Infallible,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use http::{Request, Response};
+use tokio::sync::Notify;
+use tonic::{body::Body, server::NamedService, Status};
+use tower::Service;
+use tracing::{info, warn};
+
+/// Interval at which the number of in-flight requests is logged while
+/// draining.
+const DRAIN_PROGRESS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Tracks the requests in flight on the RPC services, and refuses new ones
+/// once the drain has started.
+#[derive(Clone, Debug, Default)]
+pub struct Drain {
+    state: Arc<DrainState>,
+}
+
+#[derive(Debug, Default)]
+struct DrainState {
+    draining: AtomicBool,
+    in_flight: AtomicUsize,
+    idle: Notify,
+}
+
+impl Drain {
+    /// Starts draining: every new request is refused from now on.
+    pub fn start(&self) {
+        self.state.draining.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` once the drain has started.
+    pub fn is_draining(&self) -> bool {
+        self.state.draining.load(Ordering::SeqCst)
+    }
+
+    /// Number of requests currently being processed.
+    pub fn in_flight(&self) -> usize {
+        self.state.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Waits until no request is in flight anymore.
+    pub async fn idle(&self) {
+        loop {
+            let notified = self.state.idle.notified();
+            if self.in_flight() == 0 {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Waits for the in-flight requests to complete, for at most `timeout`.
+    ///
+    /// Returns `true` if every in-flight request completed in time.
+    pub async fn wait(&self, timeout: Duration) -> bool {
+        let deadline = tokio::time::sleep(timeout);
+        let idle = self.idle();
+        tokio::pin!(deadline, idle);
+
+        let mut progress = tokio::time::interval(DRAIN_PROGRESS_INTERVAL);
+
+        loop {
+            tokio::select! {
+                biased;
+
+                _ = &mut idle => {
+                    info!("All in-flight requests completed");
+                    return true;
+                }
+                _ = &mut deadline => {
+                    warn!(
+                        in_flight = self.in_flight(),
+                        "Drain timeout reached, aborting the remaining in-flight requests"
+                    );
+                    return false;
+                }
+                _ = progress.tick() => {
+                    info!(in_flight = self.in_flight(), "Waiting for in-flight requests to complete");
+                }
+            }
+        }
+    }
+
+    fn enter(&self) -> Option<InFlightGuard> {
+        if self.is_draining() {
+            return None;
+        }
+
+        self.state.in_flight.fetch_add(1, Ordering::SeqCst);
+
+        Some(InFlightGuard {
+            drain: self.clone(),
+        })
+    }
+}
+
+/// Marks a request as in flight until dropped.
+struct InFlightGuard {
+    drain: Drain,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if self.drain.state.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.drain.state.idle.notify_waiters();
+        }
+    }
+}
+
+/// RPC service wrapper counting the in-flight requests of the inner service
+/// and answering `Unavailable` once the drain has started.
+#[derive(Clone)]
+pub(crate) struct DrainService<S> {
+    inner: S,
+    drain: Drain,
+}
+
+impl<S> DrainService<S> {
+    pub(crate) fn new(inner: S, drain: Drain) -> Self {
+        Self { inner, drain }
+    }
+}
+
+impl<S: NamedService> NamedService for DrainService<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<S> Service<Request<Body>> for DrainService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>, Error = Infallible>,
+    S::Future: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let Some(guard) = self.drain.enter() else {
+            return Box::pin(std::future::ready(Ok(Status::unavailable(
+                "The prover is shutting down",
+            )
+            .into_http())));
+        };
+
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let response = response.await;
+            drop(guard);
+            response
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Drain;
+
+    #[tokio::test]
+    async fn refuses_requests_once_draining() {
+        let drain = Drain::default();
+
+        let guard = drain.enter().expect("accepted before draining");
+        assert_eq!(drain.in_flight(), 1);
+
+        drain.start();
+        assert!(drain.enter().is_none());
+        assert_eq!(drain.in_flight(), 1);
+
+        drop(guard);
+        assert_eq!(drain.in_flight(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn waits_for_in_flight_requests() {
+        let drain = Drain::default();
+        let guard = drain.enter().expect("accepted before draining");
+        drain.start();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            drop(guard);
+        });
+
+        assert!(drain.wait(Duration::from_secs(120)).await);
+        assert_eq!(drain.in_flight(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn gives_up_after_the_timeout() {
+        let drain = Drain::default();
+        let _guard = drain.enter().expect("accepted before draining");
+        drain.start();
+
+        assert!(!drain.wait(Duration::from_secs(30)).await);
+        assert_eq!(drain.in_flight(), 1);
+    }
+}
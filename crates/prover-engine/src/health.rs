@@ -0,0 +1,219 @@
+use std::{collections::BTreeMap, future::Future, pin::Pin, sync::Arc, time::Duration};
+
+use tonic_health::{server::HealthReporter, ServingStatus};
+use tracing::{debug, info, warn};
+
+/// Default interval between two rounds of health checks.
+pub(crate) const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Upper bound of the interval between two rounds of health checks while a
+/// service is not serving, so that it is reported as serving again shortly
+/// after its dependencies recover.
+const NOT_SERVING_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Default time after which a health check is considered failed.
+pub(crate) const DEFAULT_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub(crate) type HealthCheck =
+    Arc<dyn Fn() -> Pin<Box<dyn Future<Output = eyre::Result<()>> + Send>> + Send + Sync>;
+
+/// Periodically runs the health checks registered for the RPC services and
+/// reports the outcome through the gRPC health service.
+///
+/// A service is `Serving` when all of its checks pass, and the overall server
+/// status (the empty service name) is `Serving` when every checked service
+/// is.
+pub(crate) struct HealthMonitor {
+    checks: BTreeMap<&'static str, Vec<HealthCheck>>,
+    interval: Duration,
+    timeout: Duration,
+    reporter: HealthReporter,
+}
+
+impl HealthMonitor {
+    pub(crate) fn new(
+        checks: BTreeMap<&'static str, Vec<HealthCheck>>,
+        interval: Duration,
+        timeout: Duration,
+        reporter: HealthReporter,
+    ) -> Self {
+        Self {
+            checks,
+            interval,
+            timeout,
+            reporter,
+        }
+    }
+
+    pub(crate) async fn run(self) {
+        // Services are reported as not serving until their first successful
+        // round of checks.
+        let mut statuses: BTreeMap<&'static str, bool> = self
+            .checks
+            .keys()
+            .map(|service| (*service, false))
+            .collect();
+        let mut overall = false;
+
+        loop {
+            for (service, checks) in &self.checks {
+                let healthy = match self.check(checks).await {
+                    Ok(()) => true,
+                    Err(error) => {
+                        debug!(service, "Health check failed: {error:?}");
+
+                        if statuses[*service] {
+                            warn!(service, "Service reported as not serving: {error:?}");
+                        }
+                        false
+                    }
+                };
+
+                if healthy && !statuses[*service] {
+                    info!(service, "Service reported as serving");
+                }
+
+                statuses.insert(*service, healthy);
+                self.reporter
+                    .set_service_status(service, status(healthy))
+                    .await;
+            }
+
+            let healthy = statuses.values().all(|healthy| *healthy);
+            if healthy != overall {
+                info!(serving = healthy, "Overall health status changed");
+                overall = healthy;
+            }
+            self.reporter.set_service_status("", status(healthy)).await;
+
+            let delay = if healthy {
+                self.interval
+            } else {
+                self.interval.min(NOT_SERVING_CHECK_INTERVAL)
+            };
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    async fn check(&self, checks: &[HealthCheck]) -> eyre::Result<()> {
+        for check in checks {
+            tokio::time::timeout(self.timeout, check())
+                .await
+                .map_err(|_| eyre::eyre!("Health check timed out after {:?}", self.timeout))??;
+        }
+
+        Ok(())
+    }
+}
+
+fn status(healthy: bool) -> ServingStatus {
+    if healthy {
+        ServingStatus::Serving
+    } else {
+        ServingStatus::NotServing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use tonic_health::{
+        pb::{health_check_response::ServingStatus, health_server::Health, HealthCheckRequest},
+        server::{HealthReporter, HealthService},
+    };
+
+    use super::{HealthCheck, HealthMonitor};
+
+    async fn status(health: &impl Health, service: &str) -> ServingStatus {
+        let response = health
+            .check(tonic::Request::new(HealthCheckRequest {
+                service: service.to_string(),
+            }))
+            .await
+            .unwrap();
+
+        response.into_inner().status()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn follows_the_dependencies_health() {
+        let reporter = HealthReporter::new();
+        let health = HealthService::from_health_reporter(reporter.clone());
+
+        let healthy = Arc::new(AtomicBool::new(true));
+        let check: HealthCheck = {
+            let healthy = healthy.clone();
+            Arc::new(move || {
+                let healthy = healthy.load(Ordering::SeqCst);
+                Box::pin(async move {
+                    if healthy {
+                        Ok(())
+                    } else {
+                        Err(eyre::eyre!("dependency unreachable"))
+                    }
+                })
+            })
+        };
+
+        let monitor = HealthMonitor::new(
+            BTreeMap::from([("test.Service", vec![check])]),
+            Duration::from_secs(30),
+            Duration::from_secs(10),
+            reporter,
+        );
+        tokio::spawn(monitor.run());
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(
+            status(&health, "test.Service").await,
+            ServingStatus::Serving
+        );
+        assert_eq!(status(&health, "").await, ServingStatus::Serving);
+
+        healthy.store(false, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        assert_eq!(
+            status(&health, "test.Service").await,
+            ServingStatus::NotServing
+        );
+        assert_eq!(status(&health, "").await, ServingStatus::NotServing);
+
+        // Not serving services are checked again sooner.
+        healthy.store(true, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(
+            status(&health, "test.Service").await,
+            ServingStatus::Serving
+        );
+        assert_eq!(status(&health, "").await, ServingStatus::Serving);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn hanging_checks_time_out() {
+        let reporter = HealthReporter::new();
+        let health = HealthService::from_health_reporter(reporter.clone());
+
+        let check: HealthCheck = Arc::new(|| Box::pin(std::future::pending()));
+        let monitor = HealthMonitor::new(
+            BTreeMap::from([("test.Service", vec![check])]),
+            Duration::from_secs(30),
+            Duration::from_secs(10),
+            reporter,
+        );
+        tokio::spawn(monitor.run());
+
+        tokio::time::sleep(Duration::from_secs(11)).await;
+        assert_eq!(
+            status(&health, "test.Service").await,
+            ServingStatus::NotServing
+        );
+    }
+}
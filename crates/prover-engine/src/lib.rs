@@ -1,7 +1,19 @@
-use std::{convert::Infallible, future::IntoFuture, net::SocketAddr, time::Duration};
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    future::{Future, IntoFuture},
+    net::SocketAddr,
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
 
 use agglayer_telemetry::ServerBuilder as MetricsBuilder;
+use drain::{Drain, DrainService};
 use eyre::Context as _;
+use health::{
+    HealthCheck, HealthMonitor, DEFAULT_HEALTH_CHECK_INTERVAL, DEFAULT_HEALTH_CHECK_TIMEOUT,
+};
 use http::{Request, Response};
 use tokio::{net::TcpListener, runtime::Runtime};
 use tokio_util::sync::CancellationToken;
@@ -9,6 +21,10 @@ use tonic::{body::Body, server::NamedService};
 use tower::{Service, ServiceExt};
 use tracing::{debug, info};
 
+mod drain;
+mod health;
+mod probes;
+
 pub struct ProverEngine {
     rpc_server: axum::Router,
     rpc_runtime: Option<Runtime>,
@@ -18,7 +34,16 @@ pub struct ProverEngine {
     cancellation_token: Option<CancellationToken>,
     metric_socket_addr: SocketAddr,
     rpc_socket_addr: SocketAddr,
+    rpc_listener: Option<std::net::TcpListener>,
     runtime_shutdown_timeout: Duration,
+    drain: Drain,
+    drain_timeout: Duration,
+    health_checks: BTreeMap<&'static str, Vec<HealthCheck>>,
+    health_check_interval: Duration,
+    health_check_timeout: Duration,
+    background_tasks: Vec<Pin<Box<dyn Future<Output = ()> + Send>>>,
+    admin_server: axum::Router,
+    admin_socket_addr: Option<SocketAddr>,
 }
 
 impl ProverEngine {
@@ -36,7 +61,16 @@ impl ProverEngine {
             cancellation_token: None,
             metric_socket_addr,
             rpc_socket_addr,
+            rpc_listener: None,
             runtime_shutdown_timeout,
+            drain: Drain::default(),
+            drain_timeout: Duration::ZERO,
+            health_checks: BTreeMap::new(),
+            health_check_interval: DEFAULT_HEALTH_CHECK_INTERVAL,
+            health_check_timeout: DEFAULT_HEALTH_CHECK_TIMEOUT,
+            background_tasks: vec![],
+            admin_server: axum::Router::new(),
+            admin_socket_addr: None,
         }
     }
 
@@ -52,11 +86,86 @@ impl ProverEngine {
         self
     }
 
+    /// Sets the token cancelled on shutdown. Cancelling it from outside
+    /// shuts the engine down as a shutdown signal does.
     pub fn set_cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
         self.cancellation_token = Some(cancellation_token);
         self
     }
 
+    /// Sets how long in-flight requests are given to complete on shutdown,
+    /// before the cancellation token is triggered.
+    pub fn set_drain_timeout(mut self, drain_timeout: Duration) -> Self {
+        self.drain_timeout = drain_timeout;
+        self
+    }
+
+    /// Registers a check of the dependencies of the RPC service named
+    /// `service_name`. The service is reported as not serving through the
+    /// gRPC health service while any of its checks fails.
+    pub fn add_health_check<F, Fut>(mut self, service_name: &'static str, check: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        self.health_checks
+            .entry(service_name)
+            .or_default()
+            .push(Arc::new(move || Box::pin(check())));
+
+        self
+    }
+
+    /// Sets the interval between two rounds of health checks.
+    pub fn set_health_check_interval(mut self, interval: Duration) -> Self {
+        self.health_check_interval = interval;
+
+        self
+    }
+
+    /// Sets the time after which a health check is considered failed.
+    pub fn set_health_check_timeout(mut self, timeout: Duration) -> Self {
+        self.health_check_timeout = timeout;
+
+        self
+    }
+
+    /// Registers a task spawned on the RPC runtime once the metrics, health
+    /// and RPC servers are up.
+    pub fn add_background_task<F>(mut self, task: F) -> Self
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.background_tasks.push(Box::pin(task));
+
+        self
+    }
+
+    /// Serves the RPC server on `rpc_listener` instead of binding its address,
+    /// e.g. on a port picked by the caller.
+    pub fn set_rpc_listener(mut self, rpc_listener: std::net::TcpListener) -> Self {
+        self.rpc_listener = Some(rpc_listener);
+
+        self
+    }
+
+    /// Sets the address of the admin HTTP server, serving the `/livez` and
+    /// `/readyz` probes along with the operator endpoints registered with
+    /// [`Self::add_admin_routes`] on the metrics runtime. The admin server is
+    /// disabled when no address is set.
+    pub fn set_admin_socket_addr(mut self, admin_socket_addr: SocketAddr) -> Self {
+        self.admin_socket_addr = Some(admin_socket_addr);
+
+        self
+    }
+
+    /// Registers operator endpoints on the admin HTTP server.
+    pub fn add_admin_routes(mut self, routes: axum::Router) -> Self {
+        self.admin_server = self.admin_server.merge(routes);
+
+        self
+    }
+
     pub fn add_rpc_reflection(mut self, reflection: &'static [u8]) -> Self {
         self.reflection.push(reflection);
 
@@ -74,7 +183,10 @@ impl ProverEngine {
         S::Future: Send + 'static,
         S::Error: Into<eyre::Report> + Send,
     {
-        self.rpc_server = add_rpc_service(self.rpc_server, rpc_service);
+        self.rpc_server = add_rpc_service(
+            self.rpc_server,
+            DrainService::new(rpc_service, self.drain.clone()),
+        );
         self.healthy_service.push(S::NAME);
 
         self
@@ -129,10 +241,63 @@ impl ProverEngine {
             // Spawn the metrics server
             metrics_runtime.spawn(metric_server.into_future())
         };
-        let tcp_listener = prover_runtime.block_on(TcpListener::bind(self.rpc_socket_addr))?;
 
         let (health_reporter, health_service) = tonic_health::server::health_reporter();
 
+        debug!("Setting the initial health status of the services");
+        prover_runtime.block_on(async {
+            // Services with health checks are not serving until their first
+            // successful round of checks.
+            for service_name in self.healthy_service.iter() {
+                let status = if self.health_checks.contains_key(service_name) {
+                    tonic_health::ServingStatus::NotServing
+                } else {
+                    tonic_health::ServingStatus::Serving
+                };
+                health_reporter
+                    .set_service_status(service_name, status)
+                    .await;
+            }
+
+            if !self.health_checks.is_empty() {
+                health_reporter
+                    .set_service_status("", tonic_health::ServingStatus::NotServing)
+                    .await;
+            }
+        });
+
+        let admin_handle = match self.admin_socket_addr {
+            Some(admin_socket_addr) => {
+                debug!("Starting the admin server..");
+                let admin_listener =
+                    metrics_runtime.block_on(TcpListener::bind(admin_socket_addr))?;
+                let token = cancellation_token.clone();
+                let admin_handle = metrics_runtime.spawn(
+                    axum::serve(
+                        admin_listener,
+                        std::mem::take(&mut self.admin_server)
+                            .merge(probes::probe_routes(health_reporter.clone())),
+                    )
+                    .with_graceful_shutdown(async move { token.cancelled().await })
+                    .into_future(),
+                );
+                info!("Admin server started on {admin_socket_addr}");
+
+                Some(admin_handle)
+            }
+            None => None,
+        };
+
+        let tcp_listener = match self.rpc_listener.take() {
+            Some(rpc_listener) => {
+                let _guard = prover_runtime.enter();
+                rpc_listener.set_nonblocking(true)?;
+                TcpListener::from_std(rpc_listener)?
+            }
+            None => prover_runtime.block_on(TcpListener::bind(self.rpc_socket_addr))?,
+        };
+        let rpc_socket_addr = tcp_listener.local_addr()?;
+
         let (reflection_v1, reflection_v1alpha) = self.reflection.iter().fold(
             (
                 tonic_reflection::server::Builder::configure(),
@@ -153,21 +318,25 @@ impl ProverEngine {
             .build_v1alpha()
             .context("Unable to build the reflection_v1alpha")?;
 
-        debug!("Setting the health status of the services to healthy");
-        prover_runtime.block_on(async {
-            for service_name in self.healthy_service.iter() {
-                health_reporter
-                    .set_service_status(service_name, tonic_health::ServingStatus::Serving)
-                    .await;
-            }
-        });
-
         debug!("Adding the reflection and health services to the RPC server");
         // Adding the reflection and health services to the RPC server
         let rpc_server = add_rpc_service(self.rpc_server, reflection_v1);
         let rpc_server = add_rpc_service(rpc_server, reflection_v1alpha);
         let rpc_server = add_rpc_service(rpc_server, health_service);
 
+        let health_monitor = (!self.health_checks.is_empty()).then(|| {
+            debug!("Starting the health monitor");
+            prover_runtime.spawn(
+                HealthMonitor::new(
+                    std::mem::take(&mut self.health_checks),
+                    self.health_check_interval,
+                    self.health_check_timeout,
+                    health_reporter.clone(),
+                )
+                .run(),
+            )
+        });
+
         let token = cancellation_token.clone();
         let prover_handle = prover_runtime.spawn(
             axum::serve(tcp_listener, rpc_server)
@@ -176,7 +345,11 @@ impl ProverEngine {
         );
 
         info!("Metrics server started on {}", self.metric_socket_addr);
-        info!("RPC server started on {}", self.rpc_socket_addr);
+        info!("RPC server started on {rpc_socket_addr}");
+
+        for task in self.background_tasks.drain(..) {
+            prover_runtime.spawn(task);
+        }
         let terminate_signal = async {
             tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
                 .expect("Fail to setup SIGTERM signal")
@@ -191,22 +364,47 @@ impl ProverEngine {
                 tokio::select! {
                     _ = terminate_signal => {
                         info!("Received SIGTERM, shutting down...");
-                        // Cancel the global cancellation token to start the shutdown process.
-                        cancellation_token.cancel();
-                        // Wait for the prover to shutdown.
-                        _ = prover_handle.await;
-                        // Wait for the metrics server to shutdown.
-                        _ = metrics_handle.await;
                     }
                     _ = tokio::signal::ctrl_c() => {
                         info!("Received SIGINT (ctrl-c), shutting down...");
-                        // Cancel the global cancellation token to start the shutdown process.
-                        cancellation_token.cancel();
-                        // Wait for the prover to shutdown.
-                        _ = prover_handle.await;
-                        // Wait for the metrics server to shutdown.
-                        _ = metrics_handle.await;
                     }
+                    _ = cancellation_token.cancelled() => {
+                        info!("Cancellation requested, shutting down...");
+                    }
+                }
+
+                // Stop the health monitor so that it does not report the services
+                // as serving again while draining.
+                if let Some(health_monitor) = health_monitor {
+                    health_monitor.abort();
+                    _ = health_monitor.await;
+                }
+
+                // Refuse new requests and report the services as not serving, so
+                // that no new work is routed to this instance while draining.
+                self.drain.start();
+                for service_name in self.healthy_service.iter().chain(std::iter::once(&"")) {
+                    health_reporter
+                        .set_service_status(service_name, tonic_health::ServingStatus::NotServing)
+                        .await;
+                }
+
+                info!(
+                    in_flight = self.drain.in_flight(),
+                    drain_timeout = ?self.drain_timeout,
+                    "Draining in-flight requests"
+                );
+                self.drain.wait(self.drain_timeout).await;
+
+                // Cancel the global cancellation token to start the shutdown process.
+                cancellation_token.cancel();
+                // Wait for the prover to shutdown.
+                _ = prover_handle.await;
+                // Wait for the metrics server to shutdown.
+                _ = metrics_handle.await;
+                // Wait for the admin server to shutdown.
+                if let Some(admin_handle) = admin_handle {
+                    _ = admin_handle.await;
                 }
             });
 
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::StatusCode, routing::get, Router};
+use tonic_health::{
+    pb::{health_check_response::ServingStatus, health_server::Health, HealthCheckRequest},
+    server::{HealthReporter, HealthService},
+};
+
+/// Liveness and readiness probes served by the admin HTTP server:
+///
+/// - `GET /livez` succeeds as long as the admin server is able to answer.
+/// - `GET /readyz` succeeds while the overall gRPC health status is `SERVING`,
+///   that is once the health checks of every RPC service pass and until the
+///   engine starts draining on shutdown.
+pub(crate) fn probe_routes(health_reporter: HealthReporter) -> Router {
+    Router::new()
+        .route("/livez", get(|| async { "ok" }))
+        .route("/readyz", get(readyz))
+        .with_state(Arc::new(HealthService::from_health_reporter(
+            health_reporter,
+        )))
+}
+
+async fn readyz(State(health): State<Arc<HealthService>>) -> (StatusCode, &'static str) {
+    let request = tonic::Request::new(HealthCheckRequest {
+        service: String::new(),
+    });
+
+    let serving = health
+        .check(request)
+        .await
+        .is_ok_and(|response| response.into_inner().status() == ServingStatus::Serving);
+
+    if serving {
+        (StatusCode::OK, "ok")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "not serving")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request};
+    use tonic_health::ServingStatus;
+    use tower::ServiceExt as _;
+
+    use super::*;
+
+    async fn probe(health_reporter: &HealthReporter, uri: &str) -> StatusCode {
+        probe_routes(health_reporter.clone())
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn readiness_follows_the_overall_health_status() {
+        let (health_reporter, _) = tonic_health::server::health_reporter();
+        assert_eq!(probe(&health_reporter, "/readyz").await, StatusCode::OK);
+
+        health_reporter
+            .set_service_status("", ServingStatus::NotServing)
+            .await;
+        assert_eq!(
+            probe(&health_reporter, "/readyz").await,
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        assert_eq!(probe(&health_reporter, "/livez").await, StatusCode::OK);
+
+        health_reporter
+            .set_service_status("", ServingStatus::Serving)
+            .await;
+        assert_eq!(probe(&health_reporter, "/readyz").await, StatusCode::OK);
+    }
+}
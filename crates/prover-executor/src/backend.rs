@@ -0,0 +1,138 @@
+//! Reachability of the backends the provers send their proof requests to.
+
+use std::time::Duration;
+
+use eyre::{eyre, Context as _};
+use prover_config::ProverType;
+
+/// Time after which a backend is considered unreachable.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Debug)]
+enum Backend {
+    /// The sp1 proving network, reached at its endpoint.
+    Network(url::Url),
+    /// A prover running in-process, always reachable.
+    Local,
+}
+
+impl Backend {
+    fn new(prover_type: &ProverType) -> Self {
+        match prover_type {
+            ProverType::NetworkProver(config) => Self::Network(config.sp1_cluster_endpoint.clone()),
+            ProverType::CpuProver(_) | ProverType::MockProver(_) => Self::Local,
+        }
+    }
+
+    async fn check(&self) -> eyre::Result<()> {
+        let Self::Network(endpoint) = self else {
+            return Ok(());
+        };
+
+        let host = endpoint
+            .host_str()
+            .ok_or_else(|| eyre!("No host in the sp1 network endpoint {endpoint}"))?;
+        let port = endpoint
+            .port_or_known_default()
+            .ok_or_else(|| eyre!("No port in the sp1 network endpoint {endpoint}"))?;
+
+        tokio::time::timeout(
+            CONNECT_TIMEOUT,
+            tokio::net::TcpStream::connect((host, port)),
+        )
+        .await
+        .map_err(|_| eyre!("Timed out connecting to the sp1 network at {endpoint}"))?
+        .with_context(|| format!("Failed to connect to the sp1 network at {endpoint}"))?;
+
+        Ok(())
+    }
+}
+
+/// Probe of the backends of the primary and fallback provers of an
+/// [`Executor`](crate::Executor).
+///
+/// The backends are reachable as long as one of them is, since the executor
+/// falls back to the fallback prover when the primary one fails.
+#[derive(Clone, Debug, Default)]
+pub struct BackendProbe {
+    primary: Option<Backend>,
+    fallback: Option<Backend>,
+}
+
+impl BackendProbe {
+    pub(crate) fn new(primary: &ProverType, fallback: Option<&ProverType>) -> Self {
+        Self {
+            primary: Some(Backend::new(primary)),
+            fallback: fallback.map(Backend::new),
+        }
+    }
+
+    /// Checks that the primary prover backend, or else the fallback one, can
+    /// be reached.
+    pub async fn check(&self) -> eyre::Result<()> {
+        let Some(primary) = &self.primary else {
+            return Ok(());
+        };
+
+        match primary.check().await {
+            Ok(()) => Ok(()),
+            Err(error) => match &self.fallback {
+                Some(fallback) => fallback
+                    .check()
+                    .await
+                    .with_context(|| format!("Primary prover backend unreachable: {error:?}")),
+                None => Err(error),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prover_config::{CpuProverConfig, NetworkProverConfig, ProverType};
+
+    use super::BackendProbe;
+
+    fn network_prover(endpoint: &str) -> ProverType {
+        ProverType::NetworkProver(NetworkProverConfig {
+            sp1_cluster_endpoint: endpoint.parse().unwrap(),
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn unreachable_network_is_reported() {
+        // Nothing listens on the port of a dropped listener.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let probe = BackendProbe::new(&network_prover(&endpoint), None);
+
+        assert!(probe.check().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn reachable_network_passes() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+        let probe = BackendProbe::new(&network_prover(&endpoint), None);
+
+        probe.check().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn local_fallback_stands_in_for_an_unreachable_network() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let probe = BackendProbe::new(
+            &network_prover(&endpoint),
+            Some(&ProverType::CpuProver(CpuProverConfig::default())),
+        );
+
+        probe.check().await.unwrap();
+    }
+}
@@ -3,17 +3,18 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
+use alloy_primitives::B256;
 pub use error::Error;
 use eyre::Context as _;
 use futures::{Future, TryFutureExt};
-use prover_config::ProverType;
+use prover_config::{NetworkFulfillmentStrategy, NetworkProverConfig, ProverType};
 use sp1_sdk::{
     network::FulfillmentStrategy, CpuProver, LightProver, MockProver, NetworkProver,
-    ProveRequest as _, Prover, ProverClient, ProvingKey as _, SP1ProofWithPublicValues,
-    SP1ProvingKey, SP1Stdin, SP1VerifyingKey,
+    ProveRequest as _, Prover, ProvingKey as _, SP1ProofWithPublicValues, SP1ProvingKey, SP1Stdin,
+    SP1VerifyingKey,
 };
 use tower::{
     limit::ConcurrencyLimitLayer, timeout::TimeoutLayer, util::BoxCloneService, Service,
@@ -21,12 +22,18 @@ use tower::{
 };
 use tracing::{debug, error, info};
 
+use crate::network_requests::NetworkRequests;
+
 #[cfg(test)]
 mod tests;
 
+mod backend;
 mod error;
+pub mod network_key;
+mod network_requests;
 mod utils;
 
+pub use backend::BackendProbe;
 pub use utils::*;
 
 #[derive(Clone)]
@@ -34,6 +41,7 @@ pub struct Executor {
     vkey: Arc<SP1VerifyingKey>,
     primary: BoxCloneService<Request, Response, Error>,
     fallback: Option<BoxCloneService<Request, Response, Error>>,
+    backend_probe: BackendProbe,
 }
 
 impl Executor {
@@ -41,6 +49,11 @@ impl Executor {
         &self.vkey
     }
 
+    /// Probe of the backends the proof requests are sent to.
+    pub fn backend_probe(&self) -> BackendProbe {
+        self.backend_probe.clone()
+    }
+
     pub fn build_network_service<S>(
         timeout: Duration,
         service: S,
@@ -91,6 +104,7 @@ impl Executor {
             vkey,
             primary,
             fallback,
+            backend_probe: BackendProbe::default(),
         }
     }
 
@@ -101,11 +115,11 @@ impl Executor {
         match prover_type {
             ProverType::NetworkProver(network_prover_config) => {
                 debug!("Creating network prover executor...");
-                let network_prover = ProverClient::builder()
-                    .network()
-                    .rpc_url(network_prover_config.sp1_cluster_endpoint.as_str())
-                    .build()
-                    .await;
+                let network_prover = network_key::new_network_prover(
+                    network_prover_config.sp1_cluster_endpoint.as_str(),
+                    &network_prover_config.private_key,
+                )
+                .await?;
                 let proving_key = network_prover
                     .setup(program.into())
                     .await
@@ -120,7 +134,8 @@ impl Executor {
                             prover: Arc::new(network_prover),
                             proving_key,
                             verification_key,
-                            timeout: network_prover_config.proving_timeout,
+                            config: Arc::new(network_prover_config),
+                            requests: NetworkRequests::default(),
                         },
                     ),
                 ))
@@ -181,6 +196,7 @@ impl Executor {
         fallback: Option<ProverType>,
         program: &'static [u8],
     ) -> eyre::Result<Self> {
+        let backend_probe = BackendProbe::new(&primary, fallback.as_ref());
         let (vkey, primary) = Self::create_prover(primary, program)
             .await
             .context("Failed creating primary prover")?;
@@ -198,6 +214,7 @@ impl Executor {
             vkey: Arc::new(vkey),
             primary,
             fallback,
+            backend_probe,
         })
     }
 
@@ -229,6 +246,10 @@ pub struct Request {
 #[derive(Debug, Clone)]
 pub struct Response {
     pub proof: SP1ProofWithPublicValues,
+
+    /// Id of the request of the proof on the sp1 proving network, if proven
+    /// there.
+    pub network_request_id: Option<B256>,
 }
 
 impl Service<Request> for Executor {
@@ -372,7 +393,10 @@ impl Service<Request> for LocalExecutor {
             }?;
 
             debug!("Proof verification completed successfully");
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                network_request_id: None,
+            })
         })
     }
 }
@@ -382,7 +406,42 @@ struct NetworkExecutor {
     prover: Arc<NetworkProver>,
     proving_key: SP1ProvingKey,
     verification_key: SP1VerifyingKey,
+    config: Arc<NetworkProverConfig>,
+    requests: NetworkRequests,
+}
+
+fn fulfillment_strategy(strategy: NetworkFulfillmentStrategy) -> FulfillmentStrategy {
+    match strategy {
+        NetworkFulfillmentStrategy::Reserved => FulfillmentStrategy::Reserved,
+        NetworkFulfillmentStrategy::Hosted => FulfillmentStrategy::Hosted,
+        NetworkFulfillmentStrategy::Auction => FulfillmentStrategy::Auction,
+    }
+}
+
+/// Settings of a proof request to the sp1 proving network, as configured for
+/// its proof type.
+#[derive(Debug, Clone, PartialEq)]
+struct NetworkRequestSettings {
     timeout: Duration,
+    strategy: FulfillmentStrategy,
+    skip_simulation: bool,
+    max_price_per_pgu: Option<u64>,
+    cycle_limit: Option<u64>,
+}
+
+impl NetworkRequestSettings {
+    fn new(config: &NetworkProverConfig, proof_type: &ProofType) -> Self {
+        Self {
+            timeout: match proof_type {
+                ProofType::Plonk => config.get_plonk_proving_timeout(),
+                ProofType::Stark => config.get_stark_proving_timeout(),
+            },
+            strategy: fulfillment_strategy(config.fulfillment_strategy),
+            skip_simulation: config.skip_simulation,
+            max_price_per_pgu: config.max_price_per_pgu,
+            cycle_limit: config.cycle_limit,
+        }
+    }
 }
 
 impl Service<Request> for NetworkExecutor {
@@ -402,7 +461,10 @@ impl Service<Request> for NetworkExecutor {
 
         let verification_key = self.verification_key.clone();
         let proving_key = self.proving_key.clone();
-        let timeout = self.timeout;
+        let config = self.config.clone();
+        let requests = self.requests.clone();
+        let settings = NetworkRequestSettings::new(&config, &req.proof_type);
+        let timeout = settings.timeout;
 
         debug!("Proving with network prover with timeout: {:?}", timeout);
         let fut = sp1_async(AssertUnwindSafe(async move {
@@ -411,26 +473,61 @@ impl Service<Request> for NetworkExecutor {
             // TODO: Figure out a way to kill only the NetworkExecutor service, marking it
             // as unhealthy and potentially restarting it automatically.
             debug!("Starting the proving of the requested MultiBatchHeader");
-            let proof_request = prover.prove(&proving_key, stdin);
+            let key = network_requests::request_key(&req.proof_type, &stdin);
+            let request_id = match requests.running_request(&key) {
+                Some(request_id) => {
+                    info!(%request_id, proof_type = ?req.proof_type,
+                        "Waiting for the proof request abandoned on the sp1 proving network");
+                    request_id
+                }
+                None => {
+                    let proof_request = prover.prove(&proving_key, stdin);
+
+                    let proof_request = match req.proof_type {
+                        ProofType::Plonk => proof_request.plonk(),
+                        ProofType::Stark => proof_request.compressed(),
+                    };
+
+                    let mut proof_request = proof_request
+                        .timeout(settings.timeout)
+                        .strategy(settings.strategy)
+                        .skip_simulation(settings.skip_simulation);
+                    if let Some(max_price_per_pgu) = settings.max_price_per_pgu {
+                        proof_request = proof_request.max_price_per_pgu(max_price_per_pgu);
+                    }
+                    if let Some(cycle_limit) = settings.cycle_limit {
+                        proof_request = proof_request.cycle_limit(cycle_limit);
+                    }
 
-            let proof_request = match req.proof_type {
-                ProofType::Plonk => proof_request.plonk(),
-                ProofType::Stark => proof_request.compressed(),
+                    let request_id = proof_request
+                        .request()
+                        .await
+                        .map_err(|error| Error::ProverFailed(error.to_string()))?;
+                    info!(%request_id, proof_type = ?req.proof_type, strategy = ?config.fulfillment_strategy,
+                        "Proof requested to the sp1 proving network");
+                    requests.insert(key, request_id, Instant::now() + timeout);
+                    request_id
+                }
             };
 
-            let proof = proof_request
-                .timeout(timeout)
-                .strategy(FulfillmentStrategy::Reserved)
-                .await
+            let abandon_guard =
+                requests.abandon_guard(key, request_id, config.cancellation_policy);
+            let proof = prover.wait_proof(request_id, Some(timeout), None).await;
+            abandon_guard.complete();
+            let proof = proof
+                .inspect_err(|error| error!(%request_id, ?error, "Network proving failed"))
                 .map_err(|error| Error::ProverFailed(error.to_string()))?;
 
-            debug!("Proving completed. Verifying the proof...");
+            debug!(%request_id, "Proving completed. Verifying the proof...");
             prover
                 .verify(&proof, &verification_key, None)
                 .map_err(|error| Error::ProofVerificationFailed(error.into()))?;
 
             debug!("Proof verification completed successfully");
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                network_request_id: Some(request_id),
+            })
         }))
         .map_err(|_| Error::UnableToExecuteProver)
         .and_then(|res| async { res });
@@ -0,0 +1,172 @@
+//! Key signing the requests to the sp1 proving network.
+//!
+//! The key is only ever held by a [`PrivateKeySigner`], whose debug output
+//! shows its address, and handed over to the sp1 network client. The errors
+//! name where the key was read from, never its content.
+
+use std::{panic::AssertUnwindSafe, path::Path, str::FromStr as _};
+
+use alloy_primitives::hex;
+pub use alloy_signer_local::PrivateKeySigner;
+use eyre::{eyre, Context as _};
+use prover_config::NetworkKeyConfig;
+use sp1_sdk::NetworkProver;
+use tracing::info;
+
+use crate::sp1_async;
+
+/// Source of the signer of the requests to the sp1 proving network.
+///
+/// The configured sources read the key from the environment, a file or an
+/// encrypted keystore, while a local [`PrivateKeySigner`] stands in for them
+/// in the tests.
+#[async_trait::async_trait]
+pub trait NetworkKeySource: Send + Sync {
+    async fn signer(&self) -> eyre::Result<PrivateKeySigner>;
+}
+
+#[async_trait::async_trait]
+impl NetworkKeySource for PrivateKeySigner {
+    async fn signer(&self) -> eyre::Result<PrivateKeySigner> {
+        Ok(self.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl NetworkKeySource for NetworkKeyConfig {
+    async fn signer(&self) -> eyre::Result<PrivateKeySigner> {
+        match self {
+            NetworkKeyConfig::Env(variable) => {
+                let key = std::env::var(variable).with_context(|| {
+                    format!("Failed to get the network private key from {variable}")
+                })?;
+                parse_private_key(&key)
+                    .ok_or_else(|| eyre!("Invalid network private key in {variable}"))
+            }
+            NetworkKeyConfig::File(path) => {
+                let key = tokio::fs::read_to_string(path).await.with_context(|| {
+                    format!("Failed to read the network private key {}", path.display())
+                })?;
+                parse_private_key(&key)
+                    .ok_or_else(|| eyre!("Invalid network private key in {}", path.display()))
+            }
+            NetworkKeyConfig::Keystore {
+                path,
+                password_file,
+            } => decrypt_keystore(path, password_file).await,
+        }
+    }
+}
+
+/// Parses the hex encoded `key`, dropping the parsing error as it may quote
+/// the key.
+fn parse_private_key(key: &str) -> Option<PrivateKeySigner> {
+    PrivateKeySigner::from_str(key.trim()).ok()
+}
+
+async fn decrypt_keystore(path: &Path, password_file: &Path) -> eyre::Result<PrivateKeySigner> {
+    let password = tokio::fs::read_to_string(password_file)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to read the network keystore password {}",
+                password_file.display()
+            )
+        })?;
+    let password = password.trim_end_matches(['\n', '\r']).to_string();
+
+    // The key derivation of the keystores is purposely slow.
+    let keystore = path.to_path_buf();
+    tokio::task::spawn_blocking(move || PrivateKeySigner::decrypt_keystore(&keystore, password))
+        .await
+        .context("Network keystore decryption panicked")?
+        .with_context(|| format!("Failed to decrypt the network keystore {}", path.display()))
+}
+
+/// Builds the client of the sp1 proving network at `endpoint`, signing its
+/// requests with the key of `key_source`.
+pub async fn new_network_prover(
+    endpoint: &str,
+    key_source: &dyn NetworkKeySource,
+) -> eyre::Result<NetworkProver> {
+    let signer = key_source
+        .signer()
+        .await
+        .context("Failed to get the network private key")?;
+    info!(address = %signer.address(), %endpoint, "Building the network prover");
+
+    let endpoint = endpoint.to_string();
+    let private_key = hex::encode_prefixed(signer.to_bytes());
+    sp1_async(AssertUnwindSafe(async move {
+        sp1_sdk::ProverClient::builder()
+            .network()
+            .rpc_url(&endpoint)
+            .private_key(&private_key)
+            .build()
+            .await
+    }))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use prover_config::NetworkKeyConfig;
+
+    use super::{NetworkKeySource as _, PrivateKeySigner};
+
+    const PRIVATE_KEY: &str = "0xaabbccddff000000000000000000000000000000000000000000000000000000";
+
+    fn temp_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{name}-{}", std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn key_is_read_from_a_file() {
+        let path = temp_file("network-key", &format!("{PRIVATE_KEY}\n"));
+
+        let signer = NetworkKeyConfig::File(path.clone()).signer().await.unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(
+            signer.address(),
+            PRIVATE_KEY.parse::<PrivateKeySigner>().unwrap().address()
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_key_is_not_reported() {
+        let path = temp_file("invalid-network-key", "0xsecret");
+
+        let error = NetworkKeyConfig::File(path.clone())
+            .signer()
+            .await
+            .unwrap_err();
+        std::fs::remove_file(path).unwrap();
+
+        assert!(!format!("{error:?}").contains("secret"));
+    }
+
+    #[tokio::test]
+    async fn missing_keystore_password_is_reported() {
+        let error = NetworkKeyConfig::Keystore {
+            path: "/nonexistent/keystore.json".into(),
+            password_file: "/nonexistent/keystore.password".into(),
+        }
+        .signer()
+        .await
+        .unwrap_err();
+
+        assert!(error.to_string().contains("/nonexistent/keystore.password"));
+    }
+
+    #[tokio::test]
+    async fn local_signer_stands_in_for_the_configured_key() {
+        let signer = PrivateKeySigner::random();
+
+        assert_eq!(signer.signer().await.unwrap().address(), signer.address());
+    }
+}
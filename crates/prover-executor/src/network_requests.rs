@@ -0,0 +1,219 @@
+//! Proof requests running on the sp1 proving network.
+//!
+//! The sp1 network client offers no way to cancel a proof request, which
+//! keeps running until its deadline even once its caller gave up on it.
+//! Under the [`CancellationPolicy::FinishAndCache`] policy, such a request is
+//! kept so that the next request of the same proof waits for it instead of
+//! paying for another one.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Instant,
+};
+
+use alloy_primitives::{Keccak256, B256};
+use prover_config::CancellationPolicy;
+use sp1_sdk::SP1Stdin;
+use tracing::{info, warn};
+
+use crate::ProofType;
+
+/// Key of the proof requested by `proof_type` and `stdin`.
+///
+/// Only the stdin buffer is hashed, as it commits to the statements of the
+/// deferred proofs.
+pub(crate) fn request_key(proof_type: &ProofType, stdin: &SP1Stdin) -> B256 {
+    let mut hasher = Keccak256::new();
+    hasher.update([match proof_type {
+        ProofType::Stark => 0,
+        ProofType::Plonk => 1,
+    }]);
+    for input in &stdin.buffer {
+        hasher.update((input.len() as u64).to_be_bytes());
+        hasher.update(input);
+    }
+
+    hasher.finalize()
+}
+
+struct RunningRequest {
+    request_id: B256,
+    deadline: Instant,
+}
+
+/// Requests running on the sp1 proving network, by [`request_key`].
+#[derive(Clone, Default)]
+pub(crate) struct NetworkRequests {
+    running: Arc<Mutex<HashMap<B256, RunningRequest>>>,
+}
+
+impl NetworkRequests {
+    fn running(&self) -> MutexGuard<'_, HashMap<B256, RunningRequest>> {
+        self.running
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// The request of `key` running until before its deadline, if any.
+    pub(crate) fn running_request(&self, key: &B256) -> Option<B256> {
+        let mut running = self.running();
+        let now = Instant::now();
+        running.retain(|_, request| request.deadline > now);
+
+        running.get(key).map(|request| request.request_id)
+    }
+
+    pub(crate) fn insert(&self, key: B256, request_id: B256, deadline: Instant) {
+        self.running().insert(
+            key,
+            RunningRequest {
+                request_id,
+                deadline,
+            },
+        );
+    }
+
+    pub(crate) fn remove(&self, key: &B256) {
+        self.running().remove(key);
+    }
+
+    /// Guard applying the cancellation `policy` to the request of `key` when
+    /// dropped before being disarmed, that is once its caller gave up on it.
+    pub(crate) fn abandon_guard(
+        &self,
+        key: B256,
+        request_id: B256,
+        policy: CancellationPolicy,
+    ) -> AbandonGuard {
+        AbandonGuard {
+            requests: self.clone(),
+            key,
+            request_id,
+            policy,
+            armed: true,
+        }
+    }
+}
+
+pub(crate) struct AbandonGuard {
+    requests: NetworkRequests,
+    key: B256,
+    request_id: B256,
+    policy: CancellationPolicy,
+    armed: bool,
+}
+
+impl AbandonGuard {
+    /// Forgets the request, which was waited for until the end.
+    pub(crate) fn complete(mut self) {
+        self.armed = false;
+        self.requests.remove(&self.key);
+    }
+}
+
+impl Drop for AbandonGuard {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+
+        let request_id = self.request_id;
+        match self.policy {
+            CancellationPolicy::Cancel => {
+                self.requests.remove(&self.key);
+                warn!(
+                    %request_id,
+                    "Network proof request abandoned, it cannot be cancelled and runs until its \
+                     deadline"
+                );
+            }
+            CancellationPolicy::FinishAndCache => {
+                info!(
+                    %request_id,
+                    "Network proof request abandoned, left to finish for the next request of the \
+                     same proof"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use alloy_primitives::B256;
+    use prover_config::CancellationPolicy;
+    use sp1_sdk::SP1Stdin;
+
+    use super::{request_key, NetworkRequests};
+    use crate::ProofType;
+
+    fn stdin(input: u64) -> SP1Stdin {
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&input);
+        stdin
+    }
+
+    #[test]
+    fn request_key_depends_on_the_proof_type_and_the_stdin() {
+        let key = request_key(&ProofType::Stark, &stdin(1));
+
+        assert_eq!(key, request_key(&ProofType::Stark, &stdin(1)));
+        assert_ne!(key, request_key(&ProofType::Plonk, &stdin(1)));
+        assert_ne!(key, request_key(&ProofType::Stark, &stdin(2)));
+    }
+
+    #[test]
+    fn abandoned_request_is_kept_until_its_deadline() {
+        let requests = NetworkRequests::default();
+        let deadline = Instant::now() + Duration::from_secs(60);
+        requests.insert(B256::repeat_byte(1), B256::repeat_byte(0xa), deadline);
+        requests.insert(B256::repeat_byte(2), B256::repeat_byte(0xb), Instant::now());
+
+        drop(requests.abandon_guard(
+            B256::repeat_byte(1),
+            B256::repeat_byte(0xa),
+            CancellationPolicy::FinishAndCache,
+        ));
+
+        assert_eq!(
+            requests.running_request(&B256::repeat_byte(1)),
+            Some(B256::repeat_byte(0xa))
+        );
+        assert_eq!(requests.running_request(&B256::repeat_byte(2)), None);
+    }
+
+    #[test]
+    fn abandoned_request_is_forgotten_under_the_cancel_policy() {
+        let requests = NetworkRequests::default();
+        let deadline = Instant::now() + Duration::from_secs(60);
+        requests.insert(B256::repeat_byte(1), B256::repeat_byte(0xa), deadline);
+
+        drop(requests.abandon_guard(
+            B256::repeat_byte(1),
+            B256::repeat_byte(0xa),
+            CancellationPolicy::Cancel,
+        ));
+
+        assert_eq!(requests.running_request(&B256::repeat_byte(1)), None);
+    }
+
+    #[test]
+    fn completed_request_is_forgotten() {
+        let requests = NetworkRequests::default();
+        let deadline = Instant::now() + Duration::from_secs(60);
+        requests.insert(B256::repeat_byte(1), B256::repeat_byte(0xa), deadline);
+
+        requests
+            .abandon_guard(
+                B256::repeat_byte(1),
+                B256::repeat_byte(0xa),
+                CancellationPolicy::FinishAndCache,
+            )
+            .complete();
+
+        assert_eq!(requests.running_request(&B256::repeat_byte(1)), None);
+    }
+}
@@ -1,14 +1,17 @@
 use std::{sync::Arc, time::Duration};
 
-use prover_config::MockProverConfig;
+use prover_config::{MockProverConfig, NetworkFulfillmentStrategy, NetworkProverConfig};
 use sp1_sdk::{
-    MockProver, Prover, ProvingKey as _, SP1ProofMode, SP1ProofWithPublicValues, SP1ProvingKey,
-    SP1PublicValues, SP1Stdin, SP1VerifyingKey, SP1_CIRCUIT_VERSION,
+    network::FulfillmentStrategy, MockProver, Prover, ProvingKey as _, SP1ProofMode,
+    SP1ProofWithPublicValues, SP1ProvingKey, SP1PublicValues, SP1Stdin, SP1VerifyingKey,
+    SP1_CIRCUIT_VERSION,
 };
 use tokio::sync::OnceCell;
 use tower::{service_fn, timeout::TimeoutLayer, Service, ServiceBuilder, ServiceExt};
 
-use crate::{Executor, LocalExecutor, LocalProver, ProofType, Request, Response};
+use crate::{
+    Executor, LocalExecutor, LocalProver, NetworkRequestSettings, ProofType, Request, Response,
+};
 const ELF: &[u8] = proposer_elfs::aggregation::ELF;
 
 async fn mock_prover() -> &'static MockProver {
@@ -64,7 +67,10 @@ async fn executor_normal_behavior_with_precomputed_network_response() {
         SP1_CIRCUIT_VERSION,
     );
     proof.sp1_version = "from_network".to_string();
-    let response = Response { proof };
+    let response = Response {
+        proof,
+        network_request_id: None,
+    };
 
     let network = Executor::build_network_service(
         Duration::from_secs(1),
@@ -104,7 +110,10 @@ async fn executor_normal_behavior_only_network() {
         SP1_CIRCUIT_VERSION,
     );
     proof.sp1_version = "from_network".to_string();
-    let response = Response { proof };
+    let response = Response {
+        proof,
+        network_request_id: None,
+    };
 
     let network = Executor::build_network_service(
         Duration::from_secs(1),
@@ -143,7 +152,10 @@ async fn executor_fallback_behavior_cpu() {
         SP1_CIRCUIT_VERSION,
     );
     proof.sp1_version = "from_local".to_string();
-    let response = Response { proof };
+    let response = Response {
+        proof,
+        network_request_id: None,
+    };
 
     let local = Executor::build_local_service(
         Duration::from_secs(1),
@@ -178,7 +190,10 @@ async fn executor_fallback_because_of_timeout_cpu() {
             let mut proof = mock_proof(r.stdin).await;
             proof.sp1_version = "from_network".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                network_request_id: None,
+            })
         }),
     );
 
@@ -189,7 +204,10 @@ async fn executor_fallback_because_of_timeout_cpu() {
         SP1_CIRCUIT_VERSION,
     );
     proof.sp1_version = "from_local".to_string();
-    let response = Response { proof };
+    let response = Response {
+        proof,
+        network_request_id: None,
+    };
 
     let local = Executor::build_local_service(
         Duration::from_secs(1),
@@ -225,7 +243,10 @@ async fn executor_fails_because_of_timeout_cpu() {
             let mut proof = mock_proof(r.stdin).await;
             proof.sp1_version = "from_network".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                network_request_id: None,
+            })
         }),
     );
 
@@ -237,7 +258,10 @@ async fn executor_fails_because_of_timeout_cpu() {
             let mut proof = mock_proof(r.stdin).await;
             proof.sp1_version = "from_local".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                network_request_id: None,
+            })
         }),
     );
 
@@ -268,7 +292,10 @@ async fn executor_fails_because_of_concurrency_cpu() {
             let mut proof = mock_proof(r.stdin).await;
             proof.sp1_version = "from_network".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                network_request_id: None,
+            })
         }),
     );
 
@@ -280,7 +307,10 @@ async fn executor_fails_because_of_concurrency_cpu() {
             let mut proof = mock_proof(r.stdin).await;
             proof.sp1_version = "from_local".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                network_request_id: None,
+            })
         }),
     );
 
@@ -351,3 +381,47 @@ async fn executor_normal_behavior_mock_prover() {
         .verify(&result.unwrap().proof, &verification_key, None)
         .is_ok());
 }
+
+#[test]
+fn network_requests_are_sent_with_the_configured_settings() {
+    let config = NetworkProverConfig {
+        stark_proving_timeout: Some(Duration::from_secs(30)),
+        plonk_proving_timeout: Some(Duration::from_secs(60)),
+        fulfillment_strategy: NetworkFulfillmentStrategy::Auction,
+        max_price_per_pgu: Some(2_000_000),
+        cycle_limit: Some(100_000_000),
+        skip_simulation: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        NetworkRequestSettings::new(&config, &ProofType::Stark),
+        NetworkRequestSettings {
+            timeout: Duration::from_secs(30),
+            strategy: FulfillmentStrategy::Auction,
+            skip_simulation: true,
+            max_price_per_pgu: Some(2_000_000),
+            cycle_limit: Some(100_000_000),
+        }
+    );
+    assert_eq!(
+        NetworkRequestSettings::new(&config, &ProofType::Plonk).timeout,
+        Duration::from_secs(60)
+    );
+}
+
+#[test]
+fn network_requests_are_reserved_by_default() {
+    let config = NetworkProverConfig::default();
+
+    assert_eq!(
+        NetworkRequestSettings::new(&config, &ProofType::Stark),
+        NetworkRequestSettings {
+            timeout: config.proving_timeout,
+            strategy: FulfillmentStrategy::Reserved,
+            skip_simulation: false,
+            max_price_per_pgu: None,
+            cycle_limit: None,
+        }
+    );
+}
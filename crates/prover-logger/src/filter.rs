@@ -0,0 +1,143 @@
+//! Runtime control of the log filters.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::time::Instant;
+use tracing_subscriber::{filter::ParseError, reload, EnvFilter, Registry};
+
+use crate::{env_filter, log::LogLevel};
+
+pub(crate) type ReloadHandle = reload::Handle<EnvFilter, Registry>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LogFilterError {
+    #[error("Invalid log filter directive")]
+    InvalidDirective(#[source] ParseError),
+
+    #[error("Unable to reload the log filter")]
+    Reload(#[source] reload::Error),
+}
+
+/// The log filters in effect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilterStatus {
+    /// The filter directive of each log output and of the traces export.
+    pub directives: Vec<String>,
+    /// Whether the configured filters are overridden at runtime.
+    pub overridden: bool,
+    /// The time left before the configured filters are restored.
+    pub revert_in: Option<Duration>,
+}
+
+/// Handle on the log filters installed by [`crate::tracing`], allowing to
+/// override them at runtime without a restart.
+///
+/// The override applies to every log output and to the traces export, and can
+/// be reverted automatically after a TTL.
+#[derive(Clone, Default)]
+pub struct LogFilterHandle {
+    filters: Arc<Vec<(ReloadHandle, LogLevel)>>,
+    state: Arc<Mutex<OverrideState>>,
+}
+
+#[derive(Default)]
+struct OverrideState {
+    overridden: bool,
+    revert_at: Option<Instant>,
+    /// Incremented on every change, so that a scheduled revert is discarded
+    /// once superseded.
+    generation: u64,
+}
+
+impl LogFilterHandle {
+    pub(crate) fn new(filters: Vec<(ReloadHandle, LogLevel)>) -> Self {
+        Self {
+            filters: Arc::new(filters),
+            state: Arc::default(),
+        }
+    }
+
+    /// Returns the log filters in effect.
+    pub fn status(&self) -> LogFilterStatus {
+        let directives = self
+            .filters
+            .iter()
+            .filter_map(|(handle, _)| handle.with_current(ToString::to_string).ok())
+            .collect();
+
+        let state = self.state.lock().unwrap();
+
+        LogFilterStatus {
+            directives,
+            overridden: state.overridden,
+            revert_in: state
+                .revert_at
+                .map(|revert_at| revert_at.saturating_duration_since(Instant::now())),
+        }
+    }
+
+    /// Overrides the filters with `directive`, using the `RUST_LOG` syntax.
+    ///
+    /// When a `ttl` is given, the configured filters are restored once it
+    /// expires. This requires a Tokio runtime.
+    pub fn set(&self, directive: &str, ttl: Option<Duration>) -> Result<(), LogFilterError> {
+        // Validate the directive before touching any filter.
+        EnvFilter::try_new(directive).map_err(LogFilterError::InvalidDirective)?;
+
+        let mut state = self.state.lock().unwrap();
+        for (handle, _) in self.filters.iter() {
+            handle
+                .reload(EnvFilter::new(directive))
+                .map_err(LogFilterError::Reload)?;
+        }
+
+        state.overridden = true;
+        state.generation += 1;
+        state.revert_at = ttl.map(|ttl| Instant::now() + ttl);
+
+        if let Some(ttl) = ttl {
+            let handle = self.clone();
+            let generation = state.generation;
+            tokio::spawn(async move {
+                tokio::time::sleep(ttl).await;
+                if let Err(error) = handle.revert(Some(generation)) {
+                    tracing::warn!("Unable to restore the configured log filters: {error}");
+                }
+            });
+        }
+
+        tracing::info!(directive, ?ttl, "Overrode the log filters");
+
+        Ok(())
+    }
+
+    /// Restores the configured filters.
+    pub fn reset(&self) -> Result<(), LogFilterError> {
+        self.revert(None)
+    }
+
+    fn revert(&self, generation: Option<u64>) -> Result<(), LogFilterError> {
+        let mut state = self.state.lock().unwrap();
+        if generation.is_some_and(|generation| generation != state.generation) {
+            return Ok(());
+        }
+
+        for (handle, level) in self.filters.iter() {
+            handle
+                .reload(env_filter(*level))
+                .map_err(LogFilterError::Reload)?;
+        }
+
+        if state.overridden {
+            tracing::info!("Restored the configured log filters");
+        }
+        state.overridden = false;
+        state.revert_at = None;
+        state.generation += 1;
+
+        Ok(())
+    }
+}
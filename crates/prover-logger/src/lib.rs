@@ -1,8 +1,17 @@
-use log::Log;
+use filter::{LogFilterHandle, ReloadHandle};
+use log::{Log, LogLevel, LogOutput, LogOutputConfig};
+use opentelemetry_sdk::trace::SdkTracerProvider;
 use serde::{Deserialize, Serialize};
-use tracing_subscriber::{prelude::*, EnvFilter};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::{
+    fmt::writer::BoxMakeWriter, prelude::*, reload, EnvFilter, Layer, Registry,
+};
 
+pub mod filter;
 pub mod log;
+mod otlp;
+pub mod propagation;
+mod rotation;
 
 /// The log format.
 #[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -12,23 +21,125 @@ pub enum LogFormat {
     Pretty,
     Json,
 }
-pub fn tracing(config: &Log) {
-    // TODO: Support multiple outputs.
-    let writer = config.outputs.first().cloned().unwrap_or_default();
 
-    let layer = match config.format {
+/// Keeps the traces export and the non-blocking log writers running. The
+/// pending spans and logs are flushed when the guard is dropped.
+#[must_use = "the traces export and the file logs are stopped when the guard is dropped"]
+#[derive(Default)]
+pub struct TracingGuard {
+    tracer_provider: Option<SdkTracerProvider>,
+    log_filter: LogFilterHandle,
+    _writer_guards: Vec<WorkerGuard>,
+}
+
+impl TracingGuard {
+    /// Returns the handle overriding the log filters at runtime.
+    pub fn log_filter(&self) -> LogFilterHandle {
+        self.log_filter.clone()
+    }
+}
+
+impl Drop for TracingGuard {
+    fn drop(&mut self) {
+        if let Some(tracer_provider) = self.tracer_provider.take() {
+            if let Err(error) = tracer_provider.shutdown() {
+                eprintln!("Unable to flush the pending traces: {error}");
+            }
+        }
+    }
+}
+
+pub fn tracing(config: &Log) -> TracingGuard {
+    let default_outputs = [LogOutputConfig::default()];
+    let outputs = if config.outputs.is_empty() {
+        &default_outputs[..]
+    } else {
+        &config.outputs[..]
+    };
+
+    let mut layers = Vec::with_capacity(outputs.len() + 1);
+    let mut filters = Vec::with_capacity(outputs.len() + 1);
+    let mut writer_guards = Vec::new();
+    let mut output_errors = Vec::new();
+    for output in outputs {
+        match output.make_writer() {
+            Ok((writer, guard)) => {
+                let level = output.level.unwrap_or(config.level);
+                let (filter, handle) = reloadable_filter(level);
+
+                layers.push(output_layer(config, output, writer, filter));
+                filters.push((handle, level));
+                writer_guards.extend(guard);
+            }
+            Err(error) => output_errors.push((&output.output, error)),
+        }
+    }
+
+    let (tracer_provider, otlp_error) =
+        match config.otlp.as_ref().map(otlp::tracer_provider).transpose() {
+            Ok(Some((tracer_provider, tracer))) => {
+                let (filter, handle) = reloadable_filter(config.level);
+
+                layers.push(
+                    tracing_opentelemetry::layer()
+                        .with_tracer(tracer)
+                        .with_filter(filter)
+                        .boxed(),
+                );
+                filters.push((handle, config.level));
+
+                (Some(tracer_provider), None)
+            }
+            Ok(None) => (None, None),
+            Err(error) => (None, Some(error)),
+        };
+
+    Registry::default().with(layers).init();
+
+    for (output, error) in output_errors {
+        tracing::error!(?output, "Unable to open the log output: {error}");
+    }
+    if let Some(error) = otlp_error {
+        tracing::error!("Unable to setup the OTLP traces export: {error}");
+    }
+
+    TracingGuard {
+        tracer_provider,
+        log_filter: LogFilterHandle::new(filters),
+        _writer_guards: writer_guards,
+    }
+}
+
+fn output_layer(
+    config: &Log,
+    output: &LogOutputConfig,
+    writer: BoxMakeWriter,
+    filter: reload::Layer<EnvFilter, Registry>,
+) -> Box<dyn Layer<Registry> + Send + Sync> {
+    // Colors are only meant for terminals.
+    let ansi = !matches!(output.output, LogOutput::File(_));
+
+    match output.format.unwrap_or(config.format) {
         LogFormat::Pretty => tracing_subscriber::fmt::layer()
             .pretty()
-            .with_writer(writer.as_make_writer())
-            .with_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| config.level.into()))
+            .with_ansi(ansi)
+            .with_writer(writer)
+            .with_filter(filter)
             .boxed(),
 
         LogFormat::Json => tracing_subscriber::fmt::layer()
             .json()
-            .with_writer(writer.as_make_writer())
-            .with_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| config.level.into()))
+            .with_ansi(ansi)
+            .with_writer(writer)
+            .with_filter(filter)
             .boxed(),
-    };
+    }
+}
+
+fn reloadable_filter(level: LogLevel) -> (reload::Layer<EnvFilter, Registry>, ReloadHandle) {
+    reload::Layer::new(env_filter(level))
+}
 
-    tracing_subscriber::Registry::default().with(layer).init();
+fn env_filter(level: LogLevel) -> EnvFilter {
+    EnvFilter::try_from_default_env().unwrap_or_else(|_| level.into())
 }
@@ -1,22 +1,82 @@
-use std::{fmt::Display, path::PathBuf};
+use std::{
+    fmt::Display,
+    io,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+};
 
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tracing_appender::{
+    non_blocking::WorkerGuard,
+    rolling::{RollingFileAppender, Rotation},
+};
 use tracing_subscriber::{fmt::writer::BoxMakeWriter, EnvFilter};
+use url::Url;
 
-use crate::LogFormat;
+use crate::{rotation::SizeRollingWriter, LogFormat};
+
+/// The default maximum size of a log file rotated by size: 100 MiB.
+const DEFAULT_MAX_FILE_SIZE: u64 = 100 * 1024 * 1024;
 
 /// The log configuration.
-#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 #[serde(rename_all = "kebab-case")]
 pub struct Log {
     /// The `RUST_LOG` environment variable will take precedence over the
     /// configuration log level.
     #[serde(default)]
     pub level: LogLevel,
-    #[serde(default)]
-    pub outputs: Vec<LogOutput>,
+    /// The log outputs, each of them installed as its own layer. The logs are
+    /// written to `stdout` when empty.
+    #[serde(default, deserialize_with = "deserialize_outputs")]
+    pub outputs: Vec<LogOutputConfig>,
     #[serde(default)]
     pub format: LogFormat,
+    /// Export of the traces to an OpenTelemetry collector. Disabled when
+    /// absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub otlp: Option<OtlpConfig>,
+}
+
+/// The OpenTelemetry traces export configuration.
+///
+/// The traces are exported over OTLP/HTTP with protobuf encoding.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct OtlpConfig {
+    /// The OTLP/HTTP traces endpoint of the collector.
+    #[serde(default = "default_otlp_endpoint")]
+    pub endpoint: Url,
+    /// The service name attached to the exported traces.
+    #[serde(default = "default_otlp_service_name")]
+    pub service_name: String,
+    /// The ratio of the traces started by the prover that are sampled, between
+    /// `0.0` and `1.0`. Traces continued from a caller follow the sampling
+    /// decision of the caller.
+    #[serde(default = "default_otlp_sampling_ratio")]
+    pub sampling_ratio: f64,
+}
+
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: default_otlp_endpoint(),
+            service_name: default_otlp_service_name(),
+            sampling_ratio: default_otlp_sampling_ratio(),
+        }
+    }
+}
+
+fn default_otlp_endpoint() -> Url {
+    Url::parse("http://localhost:4318/v1/traces").unwrap()
+}
+
+fn default_otlp_service_name() -> String {
+    "aggkit-prover".to_string()
+}
+
+const fn default_otlp_sampling_ratio() -> f64 {
+    1.0
 }
 
 /// The log level.
@@ -51,22 +111,169 @@ impl From<LogLevel> for EnvFilter {
     fn from(value: LogLevel) -> Self {
         EnvFilter::new(format!(
             "warn,aggkit_prover={value},aggchain_proof_builder={value},\
-             aggchain_proof_contracts={value},aggchain_proof_service={value},\
-             agglayer_telemetry={value},proposer_client={value}, \
-             proposer_service={value},prover_engine={value},prover_executor={value}",
+             aggchain_proof_contracts={value},aggchain_proof_core={value},\
+             aggchain_proof_service={value},aggchain_proof_types={value},\
+             aggkit_prover_types={value},agglayer_telemetry={value},proposer_client={value},\
+             proposer_service={value},prover_alloy={value},prover_engine={value},\
+             prover_executor={value},prover_logger={value}",
         ))
     }
 }
 
+/// A log output along with its settings.
+///
+/// In the configuration file, an output is either a plain [`LogOutput`]
+/// string, using the settings of the [`Log`] configuration, or a table:
+///
+/// ```toml
+/// [[log.outputs]]
+/// output = "/var/log/aggkit-prover/prover.log"
+/// format = "json"
+/// level = "debug"
+/// rotation = "size"
+/// max-file-size = 52428800
+/// max-files = 10
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct LogOutputConfig {
+    pub output: LogOutput,
+    /// Overrides the format of the [`Log`] configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<LogFormat>,
+    /// Overrides the level of the [`Log`] configuration. The `RUST_LOG`
+    /// environment variable still takes precedence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<LogLevel>,
+    /// The rotation of a file output. Ignored for `stdout` and `stderr`.
+    #[serde(default)]
+    pub rotation: LogRotation,
+    /// The size in bytes above which a file output is rotated, when rotating
+    /// by size.
+    #[serde(default = "default_max_file_size")]
+    pub max_file_size: u64,
+    /// The maximum number of files kept for a rotated file output, including
+    /// the current one. All of them are kept when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_files: Option<NonZeroUsize>,
+}
+
+impl Default for LogOutputConfig {
+    fn default() -> Self {
+        LogOutput::default().into()
+    }
+}
+
+impl From<LogOutput> for LogOutputConfig {
+    fn from(output: LogOutput) -> Self {
+        Self {
+            output,
+            format: None,
+            level: None,
+            rotation: LogRotation::default(),
+            max_file_size: default_max_file_size(),
+            max_files: None,
+        }
+    }
+}
+
+const fn default_max_file_size() -> u64 {
+    DEFAULT_MAX_FILE_SIZE
+}
+
+fn deserialize_outputs<'de, D>(deserializer: D) -> Result<Vec<LogOutputConfig>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Output {
+        Output(LogOutput),
+        Config(LogOutputConfig),
+    }
+
+    let outputs = Vec::<Output>::deserialize(deserializer)?;
+
+    Ok(outputs
+        .into_iter()
+        .map(|output| match output {
+            Output::Output(output) => output.into(),
+            Output::Config(config) => config,
+        })
+        .collect())
+}
+
+impl LogOutputConfig {
+    /// Get a [`BoxMakeWriter`] for the log output, along with the guard
+    /// flushing the non-blocking writer of a file output.
+    ///
+    /// This can be used to plug the log output into the tracing subscriber.
+    pub(crate) fn make_writer(&self) -> io::Result<(BoxMakeWriter, Option<WorkerGuard>)> {
+        let path = match &self.output {
+            LogOutput::Stdout => return Ok((BoxMakeWriter::new(std::io::stdout), None)),
+            LogOutput::Stderr => return Ok((BoxMakeWriter::new(std::io::stderr), None)),
+            LogOutput::File(path) => path,
+        };
+
+        let (writer, guard) = match self.rotation {
+            LogRotation::Size => tracing_appender::non_blocking(SizeRollingWriter::new(
+                path,
+                self.max_file_size,
+                self.max_files,
+            )?),
+            rotation => {
+                let file_name = path.file_name().ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "missing log file name")
+                })?;
+                let directory = path
+                    .parent()
+                    .filter(|directory| !directory.as_os_str().is_empty())
+                    .unwrap_or(Path::new("."));
+
+                let mut builder = RollingFileAppender::builder()
+                    .rotation(match rotation {
+                        LogRotation::Hourly => Rotation::HOURLY,
+                        LogRotation::Daily => Rotation::DAILY,
+                        LogRotation::Never | LogRotation::Size => Rotation::NEVER,
+                    })
+                    .filename_prefix(file_name.to_string_lossy());
+                if let Some(max_files) = self.max_files {
+                    builder = builder.max_log_files(max_files.get());
+                }
+
+                tracing_appender::non_blocking(builder.build(directory).map_err(io::Error::other)?)
+            }
+        };
+
+        Ok((BoxMakeWriter::new(writer), Some(guard)))
+    }
+}
+
+/// The rotation of a file log output.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogRotation {
+    /// The file is never rotated.
+    #[default]
+    Never,
+    /// A new file, suffixed with the date and hour, is started every hour.
+    Hourly,
+    /// A new file, suffixed with the date, is started every day.
+    Daily,
+    /// The file is rotated once it reaches `max-file-size` bytes.
+    Size,
+}
+
 /// The log output.
 ///
-/// This can be either `stdout`, `stderr`, or a file path.
+/// This can be either `stdout`, `stderr`, or a file path, absolute or relative
+/// to the working directory.
 ///
 /// The [`Deserialize`] implementation allows for the configuration file to
 /// specify the output location as a string, which is then parsed into the
 /// appropriate enum variant. If the string is not recognized to be either
 /// `stdout` or `stderr`, it is assumed to be a file path.
-#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum LogOutput {
     #[default]
     Stdout,
@@ -90,10 +297,25 @@ impl<'de> Deserialize<'de> for LogOutput {
     }
 }
 
+impl Serialize for LogOutput {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            LogOutput::Stdout => serializer.serialize_str("stdout"),
+            LogOutput::Stderr => serializer.serialize_str("stderr"),
+            LogOutput::File(path) => path.serialize(serializer),
+        }
+    }
+}
+
 impl LogOutput {
     /// Get a [`BoxMakeWriter`] for the log output.
     ///
     /// This can be used to plug the log output into the tracing subscriber.
+    /// Unlike the outputs installed by [`crate::tracing`], a file output is
+    /// written synchronously and never rotated.
     pub fn as_make_writer(&self) -> BoxMakeWriter {
         match self {
             LogOutput::Stdout => BoxMakeWriter::new(std::io::stdout),
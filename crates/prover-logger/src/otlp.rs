@@ -0,0 +1,39 @@
+use opentelemetry::{global, trace::TracerProvider as _};
+use opentelemetry_otlp::{ExporterBuildError, SpanExporter, WithExportConfig as _};
+use opentelemetry_sdk::{
+    propagation::TraceContextPropagator,
+    trace::{Sampler, SdkTracerProvider, Tracer},
+    Resource,
+};
+
+use crate::log::OtlpConfig;
+
+/// Builds the tracer provider exporting the spans to the configured OTLP
+/// collector, and installs the W3C trace context propagator.
+pub(crate) fn tracer_provider(
+    config: &OtlpConfig,
+) -> Result<(SdkTracerProvider, Tracer), ExporterBuildError> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(config.endpoint.as_str())
+        .build()?;
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            config.sampling_ratio,
+        ))))
+        .with_resource(
+            Resource::builder()
+                .with_service_name(config.service_name.clone())
+                .build(),
+        )
+        .build();
+
+    let tracer = provider.tracer(env!("CARGO_PKG_NAME"));
+
+    global::set_tracer_provider(provider.clone());
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    Ok((provider, tracer))
+}
@@ -0,0 +1,100 @@
+//! W3C trace context propagation across the RPC boundaries.
+//!
+//! The trace context is only propagated when the OTLP export is configured,
+//! see [`crate::log::OtlpConfig`].
+
+use std::task::{Context, Poll};
+
+use opentelemetry::global;
+use opentelemetry_http::{HeaderExtractor, HeaderInjector};
+use tower::{Layer, Service};
+use tracing_opentelemetry::OpenTelemetrySpanExt as _;
+
+/// Continues the trace of the caller in `span`, using the `traceparent`
+/// header of the incoming request.
+///
+/// Must be called before `span` is first entered.
+pub fn set_parent_from_headers(span: &tracing::Span, headers: &http::HeaderMap) {
+    let parent =
+        global::get_text_map_propagator(|propagator| propagator.extract(&HeaderExtractor(headers)));
+
+    // Without the OTLP export, there is no trace to continue and the parent is
+    // ignored.
+    span.set_parent(parent);
+}
+
+/// Injects the trace context of the current span into the headers of an
+/// outgoing request.
+pub fn inject_into_headers(headers: &mut http::HeaderMap) {
+    let context = tracing::Span::current().context();
+
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut HeaderInjector(headers))
+    });
+}
+
+/// Outgoing request able to carry the trace context in its headers.
+pub trait TraceContextCarrier {
+    /// Headers the trace context is injected into, if any.
+    fn trace_context_headers(&mut self) -> Option<&mut http::HeaderMap>;
+}
+
+impl<B> TraceContextCarrier for http::Request<B> {
+    fn trace_context_headers(&mut self) -> Option<&mut http::HeaderMap> {
+        Some(self.headers_mut())
+    }
+}
+
+#[cfg(feature = "alloy")]
+impl TraceContextCarrier for alloy::rpc::json_rpc::RequestPacket {
+    fn trace_context_headers(&mut self) -> Option<&mut http::HeaderMap> {
+        // The headers of the requests of a batch are merged, so the trace
+        // context only needs to be carried by one of them.
+        self.requests_mut()
+            .last_mut()
+            .map(|request| request.headers_mut())
+    }
+}
+
+/// Layer injecting the trace context of the current span into the outgoing
+/// requests: the HTTP ones, including the gRPC ones, and with the `alloy`
+/// feature the JSON-RPC ones.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TraceContextLayer;
+
+impl<S> Layer<S> for TraceContextLayer {
+    type Service = TraceContextService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TraceContextService { inner }
+    }
+}
+
+/// Service injecting the trace context of the current span into the outgoing
+/// requests, see [`TraceContextLayer`].
+#[derive(Debug, Clone)]
+pub struct TraceContextService<S> {
+    inner: S,
+}
+
+impl<S, R> Service<R> for TraceContextService<S>
+where
+    S: Service<R>,
+    R: TraceContextCarrier,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: R) -> Self::Future {
+        if let Some(headers) = request.trace_context_headers() {
+            inject_into_headers(headers);
+        }
+
+        self.inner.call(request)
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+};
+
+/// File writer rolling over to a new file once the current one reaches
+/// `max_size` bytes.
+///
+/// The rotated files are renamed `<file>.1`, `<file>.2`, ..., the lowest index
+/// being the most recent. At most `max_files` files are kept, including the
+/// current one, and all of them when there is no limit.
+pub(crate) struct SizeRollingWriter {
+    path: PathBuf,
+    max_size: u64,
+    max_files: Option<NonZeroUsize>,
+    file: File,
+    size: u64,
+}
+
+impl SizeRollingWriter {
+    pub(crate) fn new(
+        path: &Path,
+        max_size: u64,
+        max_files: Option<NonZeroUsize>,
+    ) -> io::Result<Self> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let size = file.metadata()?.len();
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            max_size,
+            max_files,
+            file,
+            size,
+        })
+    }
+
+    fn rotated(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        path.into()
+    }
+
+    fn roll(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        // Number of rotated files kept once the current one is rotated.
+        let rotated = match self.max_files {
+            Some(max_files) => max_files.get() - 1,
+            None => (1..)
+                .find(|index| !self.rotated(*index).exists())
+                .unwrap_or(1),
+        };
+
+        if rotated > 0 {
+            ignore_not_found(fs::remove_file(self.rotated(rotated)))?;
+            for index in (1..rotated).rev() {
+                ignore_not_found(fs::rename(self.rotated(index), self.rotated(index + 1)))?;
+            }
+            fs::rename(&self.path, self.rotated(1))?;
+        }
+
+        self.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.size = 0;
+
+        Ok(())
+    }
+}
+
+impl Write for SizeRollingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_size {
+            self.roll()?;
+        }
+
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn ignore_not_found(result: io::Result<()>) -> io::Result<()> {
+    match result {
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, io::Write as _, num::NonZeroUsize, path::PathBuf};
+
+    use super::SizeRollingWriter;
+
+    fn log_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir()
+            .join(format!("prover-logger-{}", std::process::id()))
+            .join(name);
+        _ = fs::remove_dir_all(&directory);
+
+        directory
+    }
+
+    #[test]
+    fn rolls_over_when_the_file_is_full() {
+        let directory = log_directory("rolls_over");
+        let path = directory.join("nested").join("prover.log");
+
+        let mut writer = SizeRollingWriter::new(&path, 10, None).unwrap();
+        for line in ["first\n", "second\n", "third\n"] {
+            writer.write_all(line.as_bytes()).unwrap();
+        }
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "third\n");
+        assert_eq!(
+            fs::read_to_string(directory.join("nested/prover.log.1")).unwrap(),
+            "second\n"
+        );
+        assert_eq!(
+            fs::read_to_string(directory.join("nested/prover.log.2")).unwrap(),
+            "first\n"
+        );
+    }
+
+    #[test]
+    fn keeps_at_most_max_files() {
+        let directory = log_directory("retention");
+        let path = directory.join("prover.log");
+
+        let mut writer = SizeRollingWriter::new(&path, 10, NonZeroUsize::new(2)).unwrap();
+        for line in ["first\n", "second\n", "third\n"] {
+            writer.write_all(line.as_bytes()).unwrap();
+        }
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "third\n");
+        assert_eq!(
+            fs::read_to_string(directory.join("prover.log.1")).unwrap(),
+            "second\n"
+        );
+        assert!(!directory.join("prover.log.2").exists());
+    }
+
+    #[test]
+    fn appends_to_the_existing_file() {
+        let directory = log_directory("append");
+        let path = directory.join("prover.log");
+
+        SizeRollingWriter::new(&path, 10, None)
+            .unwrap()
+            .write_all(b"first\n")
+            .unwrap();
+        SizeRollingWriter::new(&path, 10, None)
+            .unwrap()
+            .write_all(b"second\n")
+            .unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second\n");
+        assert_eq!(
+            fs::read_to_string(directory.join("prover.log.1")).unwrap(),
+            "first\n"
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::{fs, time::Duration};
+
+use prover_logger::{
+    filter::LogFilterError,
+    log::{Log, LogLevel, LogOutput},
+};
+
+#[tokio::test(start_paused = true)]
+async fn filters_are_overridden_at_runtime() {
+    let path = std::env::temp_dir()
+        .join(format!("prover-logger-filter-{}", std::process::id()))
+        .join("prover.log");
+    _ = fs::remove_file(&path);
+
+    let guard = prover_logger::tracing(&Log {
+        level: LogLevel::Info,
+        outputs: vec![LogOutput::File(path.clone()).into()],
+        ..Default::default()
+    });
+    let log_filter = guard.log_filter();
+
+    let status = log_filter.status();
+    assert!(!status.overridden);
+    assert!(
+        status.directives[0].contains("prover_alloy=info"),
+        "{status:?}"
+    );
+
+    assert!(matches!(
+        log_filter.set("aggkit_prover=not-a-level", None),
+        Err(LogFilterError::InvalidDirective(_))
+    ));
+
+    tracing::debug!(target: "aggkit_prover", "hidden before the override");
+    log_filter
+        .set("aggkit_prover=debug", Some(Duration::from_secs(60)))
+        .unwrap();
+    tracing::debug!(target: "aggkit_prover", "shown during the override");
+
+    let status = log_filter.status();
+    assert!(status.overridden);
+    assert_eq!(status.directives, vec!["aggkit_prover=debug".to_string()]);
+    assert_eq!(status.revert_in, Some(Duration::from_secs(60)));
+
+    // The configured filters are restored once the TTL expires.
+    tokio::time::sleep(Duration::from_secs(61)).await;
+    assert!(!log_filter.status().overridden);
+    tracing::debug!(target: "aggkit_prover", "hidden after the override");
+
+    // Flush the non-blocking writer.
+    drop(guard);
+
+    let logs = fs::read_to_string(&path).unwrap();
+    assert!(!logs.contains("hidden"), "{logs}");
+    assert!(logs.contains("shown during the override"), "{logs}");
+}
@@ -0,0 +1,112 @@
+use axum::{body::Bytes, extract::State, routing::post, Router};
+use opentelemetry_proto::tonic::{
+    collector::trace::v1::ExportTraceServiceRequest, common::v1::any_value::Value,
+};
+use prost::Message as _;
+use prover_logger::{
+    log::{Log, OtlpConfig},
+    propagation::{inject_into_headers, set_parent_from_headers},
+};
+use tokio::{net::TcpListener, sync::mpsc};
+
+const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+const PARENT_SPAN_ID: &str = "00f067aa0ba902b7";
+
+/// Starts an OTLP/HTTP collector forwarding the received exports.
+async fn collector() -> (String, mpsc::UnboundedReceiver<ExportTraceServiceRequest>) {
+    let (sender, receiver) = mpsc::unbounded_channel();
+
+    let app = Router::new()
+        .route(
+            "/v1/traces",
+            post(
+                |State(sender): State<mpsc::UnboundedSender<_>>, body: Bytes| async move {
+                    let request = ExportTraceServiceRequest::decode(body).unwrap();
+                    _ = sender.send(request);
+                },
+            ),
+        )
+        .with_state(sender);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}/v1/traces", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    (endpoint, receiver)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn spans_continue_the_caller_trace() {
+    let (endpoint, mut exports) = collector().await;
+
+    let guard = prover_logger::tracing(&Log {
+        otlp: Some(OtlpConfig {
+            endpoint: endpoint.parse().unwrap(),
+            service_name: "otlp-test".to_string(),
+            // Traces continued from a sampled caller are sampled regardless.
+            sampling_ratio: 0.0,
+        }),
+        ..Default::default()
+    });
+
+    // Incoming request from a sampled caller.
+    let mut incoming = http::HeaderMap::new();
+    incoming.insert(
+        "traceparent",
+        format!("00-{TRACE_ID}-{PARENT_SPAN_ID}-01")
+            .parse()
+            .unwrap(),
+    );
+
+    let span = tracing::info_span!(target: "aggkit_prover", "generate_aggchain_proof");
+    set_parent_from_headers(&span, &incoming);
+
+    let outgoing = span.in_scope(|| {
+        let mut outgoing = http::HeaderMap::new();
+        inject_into_headers(&mut outgoing);
+        outgoing
+    });
+    drop(span);
+
+    // The outgoing requests carry the caller trace.
+    let traceparent = outgoing["traceparent"].to_str().unwrap();
+    assert!(
+        traceparent.starts_with(&format!("00-{TRACE_ID}-")),
+        "{traceparent}"
+    );
+    assert!(traceparent.ends_with("-01"), "{traceparent}");
+
+    // Flush the pending spans.
+    tokio::task::spawn_blocking(move || drop(guard))
+        .await
+        .unwrap();
+
+    let export = exports.recv().await.expect("spans exported");
+    let resource_spans = &export.resource_spans[0];
+
+    let service_name = resource_spans
+        .resource
+        .as_ref()
+        .unwrap()
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == "service.name")
+        .and_then(|attribute| attribute.value.clone()?.value);
+    assert_eq!(
+        service_name,
+        Some(Value::StringValue("otlp-test".to_string()))
+    );
+
+    let span = resource_spans
+        .scope_spans
+        .iter()
+        .flat_map(|scope_spans| &scope_spans.spans)
+        .find(|span| span.name == "generate_aggchain_proof")
+        .expect("request span exported");
+    assert_eq!(hex(&span.trace_id), TRACE_ID);
+    assert_eq!(hex(&span.parent_span_id), PARENT_SPAN_ID);
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,94 @@
+use std::fs;
+
+use prover_logger::log::{Log, LogOutput, LogRotation};
+
+#[test]
+fn every_output_gets_its_own_format_and_level() {
+    // The `RUST_LOG` environment variable would take precedence over the levels
+    // of the outputs.
+    std::env::remove_var("RUST_LOG");
+
+    let directory =
+        std::env::temp_dir().join(format!("prover-logger-outputs-{}", std::process::id()));
+    _ = fs::remove_dir_all(&directory);
+
+    let config: Log = toml::from_str(&format!(
+        r#"
+        level = "info"
+        format = "pretty"
+
+        [[outputs]]
+        output = "{directory}/json/prover.log"
+        format = "json"
+        level = "debug"
+        rotation = "size"
+        max-file-size = 1048576
+        max-files = 3
+
+        [[outputs]]
+        output = "{directory}/pretty/prover.log"
+        level = "warn"
+        "#,
+        directory = directory.display(),
+    ))
+    .unwrap();
+
+    assert_eq!(
+        config.outputs[0].output,
+        LogOutput::File(directory.join("json/prover.log"))
+    );
+    assert_eq!(config.outputs[0].rotation, LogRotation::Size);
+    assert_eq!(config.outputs[1].rotation, LogRotation::Never);
+
+    let guard = prover_logger::tracing(&config);
+
+    tracing::debug!(target: "aggkit_prover", "debug message");
+    tracing::warn!(target: "aggkit_prover", "warn message");
+
+    // Flush the non-blocking writers.
+    drop(guard);
+
+    let json = fs::read_to_string(directory.join("json/prover.log")).unwrap();
+    let lines = json.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2, "{json}");
+    assert!(lines[0].starts_with('{') && lines[0].contains("debug message"));
+    assert!(lines[1].contains("warn message"));
+
+    let pretty = fs::read_to_string(directory.join("pretty/prover.log")).unwrap();
+    assert!(!pretty.contains("debug message"), "{pretty}");
+    assert!(pretty.contains("warn message"), "{pretty}");
+    assert!(!pretty.contains('\u{1b}'), "no colors in files: {pretty}");
+}
+
+#[test]
+fn plain_outputs_use_the_global_settings() {
+    let config: Log = toml::from_str(
+        r#"
+        outputs = ["stdout", "stderr", "/var/log/aggkit-prover.log"]
+        "#,
+    )
+    .unwrap();
+
+    let outputs = config
+        .outputs
+        .iter()
+        .map(|output| (&output.output, output.format, output.level))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        outputs,
+        vec![
+            (&LogOutput::Stdout, None, None),
+            (&LogOutput::Stderr, None, None),
+            (
+                &LogOutput::File("/var/log/aggkit-prover.log".into()),
+                None,
+                None
+            ),
+        ]
+    );
+
+    // Outputs are serialized back as tables.
+    let serialized = toml::to_string(&config).unwrap();
+    let deserialized: Log = toml::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, config);
+}
@@ -0,0 +1,178 @@
+//! Hermetic harness of the aggkit prover.
+//!
+//! [`TestKit::start`] boots the aggkit prover runtime in-process, with a mock
+//! prover executing the aggchain program and a [`MockProposer`] serving mock
+//! aggregation proofs, both reading the fake chains of a [`Scenario`]. The
+//! tests then drive it over gRPC.
+
+use std::{net::TcpListener, sync::Arc, time::Duration};
+
+use aggchain_proof_contracts::testutils::{FakeAggchainContractsClient, FakeMethod};
+use aggchain_proof_service::service::AggchainProofService;
+use aggchain_proof_types::{AggchainProofInputs, OptimisticAggchainProofInputs};
+use aggkit_prover::ProverRuntime;
+use aggkit_prover_config::ProverConfig;
+use aggkit_prover_types::v1::{
+    aggchain_proof_service_client::AggchainProofServiceClient, GenerateAggchainProofRequest,
+    GenerateAggchainProofResponse, GenerateOptimisticAggchainProofRequest,
+    GenerateOptimisticAggchainProofResponse,
+};
+use agglayer_evm_client::MockRpc;
+use eyre::{bail, Context as _};
+use mock_proposer::{ContractsOutputs, MockProposer};
+use proposer_client::GrpcUri;
+use prover_config::{MockProverConfig, ProverType};
+use tokio_util::sync::{CancellationToken, DropGuard};
+use tonic::{transport::Channel, Status};
+
+pub use crate::scenario::{Scenario, TRUSTED_SEQUENCER};
+
+mod scenario;
+
+#[cfg(test)]
+mod tests;
+
+/// Number of the L1 block the proposer anchors the aggregation proofs at.
+const L1_BLOCK_NUMBER: u64 = 100;
+
+/// Delay before the first retry of a failed aggregation proof request, short
+/// for the proposer faults to be retried quickly.
+const PROPOSER_INITIAL_BACKOFF: Duration = Duration::from_millis(10);
+
+/// How long the aggkit prover is given to initialize its aggchain proof
+/// service.
+const INITIALIZATION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Aggkit prover running in-process over the chains of a [`Scenario`], shut
+/// down when dropped.
+///
+/// The tests runtime must be multi-threaded, as it runs the clients of the
+/// aggchain proof service.
+pub struct TestKit {
+    client: AggchainProofServiceClient<Channel>,
+    contracts_client: FakeAggchainContractsClient,
+    proposer_contracts_client: FakeAggchainContractsClient,
+    _shutdown: DropGuard,
+}
+
+impl TestKit {
+    pub async fn start(scenario: &Scenario) -> eyre::Result<Self> {
+        let cancellation_token = CancellationToken::new();
+        let shutdown = cancellation_token.clone().drop_guard();
+
+        // The proposer reads its own copy of the chains, the faults injected in the
+        // scenario only hit the aggkit prover.
+        let contracts_client = scenario.contracts_client().clone();
+        let proposer_contracts_client = FakeAggchainContractsClient::new()
+            .set_failures(FakeMethod::L2OutputAtBlock, scenario.proposer_failures());
+        let chain = contracts_client.update(|chain| chain.clone());
+        proposer_contracts_client.update(|proposer_chain| *proposer_chain = chain);
+
+        let proposer = MockProposer::new(ContractsOutputs::new(Arc::new(
+            proposer_contracts_client.clone(),
+        )))
+        .await?;
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let proposer_endpoint: GrpcUri = format!("http://{}", listener.local_addr()?).parse()?;
+        tokio::spawn(proposer.serve(listener, cancellation_token.clone().cancelled_owned()));
+
+        // The gRPC listener is handed over to the prover, the metrics server binds
+        // a port of its own.
+        let grpc_listener = TcpListener::bind("127.0.0.1:0")?;
+        let mut config = ProverConfig {
+            grpc_endpoint: grpc_listener.local_addr()?,
+            primary_prover: ProverType::MockProver(MockProverConfig::default()),
+            ..Default::default()
+        };
+        config.telemetry.addr = "127.0.0.1:0".parse()?;
+        config.shutdown.drain_timeout = Duration::ZERO;
+
+        let service_config = &mut config.aggchain_proof_service;
+        service_config.proposer_service.mock = true;
+        service_config.proposer_service.client.proposer_endpoint = proposer_endpoint;
+        service_config.proposer_service.client.initial_backoff = PROPOSER_INITIAL_BACKOFF;
+        service_config.aggchain_proof_builder.primary_prover = config.primary_prover.clone();
+        service_config.aggchain_proof_builder.validate_bridge_events = true;
+
+        let mut l1_rpc = MockRpc::new();
+        l1_rpc
+            .expect_get_block_number()
+            .returning(|_| Ok(L1_BLOCK_NUMBER));
+
+        let service = AggchainProofService::new_with_clients(
+            service_config,
+            Arc::new(l1_rpc),
+            Arc::new(contracts_client.clone()),
+        )
+        .await
+        .context("Failed to create the aggchain proof service")?;
+
+        let grpc_endpoint = config.grpc_endpoint;
+        let runtime = ProverRuntime::new(Arc::new(config), "testkit")
+            .set_aggchain_proof_service(async move { Ok(service) })
+            .set_grpc_listener(grpc_listener)
+            .set_cancellation_token(cancellation_token);
+        let grpc_service = runtime.grpc_service();
+        let prover = std::thread::spawn(move || runtime.start());
+
+        let initialization = async {
+            while !grpc_service.is_initialized() {
+                if prover.is_finished() {
+                    bail!("The aggkit prover stopped while initializing");
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+
+            Ok(())
+        };
+        tokio::time::timeout(INITIALIZATION_TIMEOUT, initialization)
+            .await
+            .context("Timed out initializing the aggkit prover")??;
+
+        let channel = Channel::from_shared(format!("http://{grpc_endpoint}"))?.connect_lazy();
+
+        Ok(Self {
+            client: AggchainProofServiceClient::new(channel),
+            contracts_client,
+            proposer_contracts_client,
+            _shutdown: shutdown,
+        })
+    }
+
+    /// Client of the aggchain proof service of the aggkit prover.
+    pub fn client(&self) -> AggchainProofServiceClient<Channel> {
+        self.client.clone()
+    }
+
+    /// Client of the chains read by the aggkit prover.
+    pub fn contracts_client(&self) -> &FakeAggchainContractsClient {
+        &self.contracts_client
+    }
+
+    /// Client of the chains read by the mock proposer.
+    pub fn proposer_contracts_client(&self) -> &FakeAggchainContractsClient {
+        &self.proposer_contracts_client
+    }
+
+    pub async fn generate_aggchain_proof(
+        &self,
+        inputs: AggchainProofInputs,
+    ) -> Result<GenerateAggchainProofResponse, Status> {
+        self.client()
+            .generate_aggchain_proof(GenerateAggchainProofRequest::from(inputs))
+            .await
+            .map(tonic::Response::into_inner)
+    }
+
+    pub async fn generate_optimistic_aggchain_proof(
+        &self,
+        inputs: OptimisticAggchainProofInputs,
+    ) -> Result<GenerateOptimisticAggchainProofResponse, Status> {
+        self.client()
+            .generate_optimistic_aggchain_proof(GenerateOptimisticAggchainProofRequest::from(
+                inputs,
+            ))
+            .await
+            .map(tonic::Response::into_inner)
+    }
+}
@@ -0,0 +1,269 @@
+//! Chain data and requests the aggkit prover is driven with.
+
+use std::{collections::HashMap, fs::File, io::BufReader, path::Path};
+
+use aggchain_proof_contracts::{
+    bridge_events::InsertedGerEvent,
+    contracts::OpSuccinctConfig,
+    testutils::{FakeAggchainContractsClient, FakeL2Block, FakeMethod},
+};
+use aggchain_proof_core::bridge::BridgeConstraintsInput;
+use aggchain_proof_types::{
+    imported_bridge_exit::{BridgeExitHash, ImportedBridgeExitWithBlockNumber},
+    inserted_ger::{InsertedGer, InsertedGerWithBlockNumber},
+    removed_ger::RemovedGerWithBlockNumber,
+    unclaim::UnclaimWithBlockNumber,
+    AggchainProofInputs, OptimisticAggchainProofInputs,
+};
+use agglayer_primitives::{address, Address, Digest, Signature};
+use eyre::{eyre, Context as _};
+use sp1_sdk::HashableKey as _;
+
+/// Trusted sequencer of the scenarios, committed to by the mock aggregation
+/// proofs.
+pub const TRUSTED_SEQUENCER: Address = address!("0x39027D57969aD59161365e0bbd53D2F63eE5AAA6");
+
+/// L2 chain served to the aggkit prover and the aggchain proof request sent
+/// to it.
+///
+/// Every bridge event added through the scenario is both emitted on the L2
+/// and supplied in the request. The tests make them diverge by updating one
+/// side only, through [`Scenario::update_inputs`] or the contracts client.
+pub struct Scenario {
+    contracts_client: FakeAggchainContractsClient,
+    inputs: AggchainProofInputs,
+    proposer_failures: usize,
+}
+
+impl Scenario {
+    /// The recorded sepolia chain of the bridge constraints test input of
+    /// `aggchain-proof-core`, with its bridge events.
+    ///
+    /// The GER manager of the recorded sketches is moved to the address the
+    /// aggchain program reads, in state tries rebuilt from the accounts of the
+    /// sketches. Their anchor headers commit to the new state roots, so that
+    /// the aggchain proofs of this chain succeed.
+    pub fn recorded() -> eyre::Result<Self> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/test_input/bridge_constraints_input.json");
+        let file = File::open(&path).with_context(|| format!("Opening {}", path.display()))?;
+        let input: BridgeConstraintsInput = serde_json::from_reader(BufReader::new(file))
+            .context("Deserializing the bridge constraints input")?;
+        let witness = input.bridge_witness;
+
+        let last_proven_block = witness.prev_l2_block_sketch.anchor.header().number;
+        let end_block = witness.new_l2_block_sketch.anchor.header().number;
+
+        let contracts_client = FakeAggchainContractsClient::new()
+            .set_block(
+                last_proven_block,
+                FakeL2Block::new(last_proven_block).set_sketch(witness.prev_l2_block_sketch),
+            )
+            .set_block(
+                end_block,
+                FakeL2Block::new(end_block)
+                    .set_local_exit_root(input.new_local_exit_root)
+                    .set_sketch(witness.new_l2_block_sketch),
+            )
+            .set_op_succinct_config(OpSuccinctConfig {
+                aggregation_vkey_hash: Digest(proposer_elfs::aggregation::vkey().bytes32_raw()),
+                range_vkey_commitment: Digest(proposer_elfs::range::commitment()),
+                rollup_config_hash: Digest([0x11; 32]),
+            })
+            .set_trusted_sequencer(TRUSTED_SEQUENCER);
+
+        // The request is anchored at the latest L1 info tree leaf, whose inclusion
+        // proof is the one of its GER.
+        let anchor = witness
+            .inserted_gers
+            .last()
+            .ok_or_else(|| eyre!("No inserted GER in the bridge constraints input"))?;
+        let inputs = AggchainProofInputs {
+            last_proven_block,
+            requested_end_block: end_block,
+            l1_info_tree_root_hash: input.l1_info_root,
+            l1_info_tree_leaf: anchor.l1_info_tree_leaf,
+            l1_info_tree_merkle_proof: anchor.proof.clone(),
+            ger_leaves: HashMap::new(),
+            imported_bridge_exits: Vec::new(),
+            removed_gers: Vec::new(),
+            unclaims: Vec::new(),
+        };
+
+        let mut scenario = Self {
+            contracts_client,
+            inputs,
+            proposer_failures: 0,
+        };
+
+        // The recorded events are laid out one kind per block, in their recorded
+        // order, right after the last proven block.
+        let first_block = last_proven_block + 1;
+        for (log_index, global_exit_root) in (0..).zip(witness.raw_inserted_gers) {
+            // The GERs removed afterwards come without an inclusion proof, which is
+            // not checked for them.
+            let inserted_ger = match witness
+                .inserted_gers
+                .iter()
+                .find(|ger| ger.l1_info_tree_leaf.inner.global_exit_root == global_exit_root)
+            {
+                Some(ger) => InsertedGer {
+                    proof_ger_l1root: ger.proof.clone(),
+                    l1_leaf: ger.l1_info_tree_leaf,
+                },
+                None => {
+                    let mut l1_leaf = anchor.l1_info_tree_leaf;
+                    l1_leaf.inner.global_exit_root = global_exit_root;
+                    InsertedGer {
+                        proof_ger_l1root: anchor.proof.clone(),
+                        l1_leaf,
+                    }
+                }
+            };
+
+            scenario = scenario.insert_ger(InsertedGerWithBlockNumber {
+                block_number: first_block,
+                inserted_ger,
+                log_index,
+            });
+        }
+        for (log_index, global_exit_root) in (0..).zip(witness.removed_gers) {
+            scenario = scenario.remove_ger(RemovedGerWithBlockNumber {
+                global_exit_root,
+                block_number: first_block + 1,
+                log_index,
+            });
+        }
+        for (log_index, exit) in (0..).zip(witness.imported_bridge_exits) {
+            scenario = scenario.claim(ImportedBridgeExitWithBlockNumber {
+                block_number: first_block + 2,
+                bridge_exit_hash: BridgeExitHash(exit.bridge_exit_hash),
+                global_index: exit.global_index.into(),
+                log_index,
+            });
+        }
+        for (log_index, global_index) in (0..).zip(witness.unset_claims) {
+            scenario = scenario.unclaim(UnclaimWithBlockNumber {
+                global_index,
+                block_number: first_block + 3,
+                log_index,
+            });
+        }
+
+        Ok(scenario)
+    }
+
+    /// Client of the L1 and L2 chains served to the aggkit prover.
+    pub fn contracts_client(&self) -> &FakeAggchainContractsClient {
+        &self.contracts_client
+    }
+
+    /// Inputs of the aggchain proof request.
+    pub fn inputs(&self) -> &AggchainProofInputs {
+        &self.inputs
+    }
+
+    /// Inputs of the optimistic aggchain proof request, signed with
+    /// `signature`.
+    pub fn optimistic_inputs(&self, signature: Signature) -> OptimisticAggchainProofInputs {
+        OptimisticAggchainProofInputs {
+            aggchain_proof_inputs: self.inputs.clone(),
+            signature_optimistic_mode: signature,
+        }
+    }
+
+    /// Number of aggregation proof requests the proposer fails first.
+    pub fn proposer_failures(&self) -> usize {
+        self.proposer_failures
+    }
+
+    /// Inserts `inserted_ger` on the L2 and in the request.
+    pub fn insert_ger(mut self, inserted_ger: InsertedGerWithBlockNumber) -> Self {
+        let global_exit_root = inserted_ger.inserted_ger.l1_leaf.inner.global_exit_root;
+        self.contracts_client.update(|chain| {
+            chain.block_mut(inserted_ger.block_number);
+            let events = &mut chain.bridge_events.inserted_gers;
+            events.push(InsertedGerEvent {
+                global_exit_root,
+                block_number: inserted_ger.block_number,
+                log_index: inserted_ger.log_index,
+            });
+            events.sort_by_key(|event| (event.block_number, event.log_index));
+        });
+        self.inputs
+            .ger_leaves
+            .insert(global_exit_root.to_string(), inserted_ger);
+        self
+    }
+
+    /// Removes `removed_ger` on the L2 and in the request.
+    pub fn remove_ger(mut self, removed_ger: RemovedGerWithBlockNumber) -> Self {
+        self.contracts_client.update(|chain| {
+            chain.block_mut(removed_ger.block_number);
+            let events = &mut chain.bridge_events.removed_gers;
+            events.push(removed_ger.clone());
+            events.sort();
+        });
+        self.inputs.removed_gers.push(removed_ger);
+        self
+    }
+
+    /// Claims `imported_bridge_exit` on the L2 and in the request.
+    pub fn claim(mut self, imported_bridge_exit: ImportedBridgeExitWithBlockNumber) -> Self {
+        self.contracts_client.update(|chain| {
+            chain.block_mut(imported_bridge_exit.block_number);
+            let events = &mut chain.bridge_events.imported_bridge_exits;
+            events.push(imported_bridge_exit.clone());
+            events.sort();
+        });
+        self.inputs.imported_bridge_exits.push(imported_bridge_exit);
+        self
+    }
+
+    /// Unclaims `unclaim` on the L2 and in the request.
+    pub fn unclaim(mut self, unclaim: UnclaimWithBlockNumber) -> Self {
+        self.contracts_client.update(|chain| {
+            chain.block_mut(unclaim.block_number);
+            let events = &mut chain.bridge_events.unclaims;
+            events.push(unclaim.clone());
+            events.sort();
+        });
+        self.inputs.unclaims.push(unclaim);
+        self
+    }
+
+    /// Applies `update` to the request only.
+    pub fn update_inputs(mut self, update: impl FnOnce(&mut AggchainProofInputs)) -> Self {
+        update(&mut self.inputs);
+        self
+    }
+
+    /// Reorgs the end block out for good once the prover has pinned it, while
+    /// the L2 node keeps serving the sketch of the reorged out block.
+    pub fn reorg_end_block(mut self) -> Self {
+        let end_block = self.inputs.requested_end_block;
+        self.contracts_client =
+            self.contracts_client
+                .set_change_after(FakeMethod::L2LocalExitRoot, 2, move |chain| {
+                    chain.block_mut(end_block).hash = Digest([0xaa; 32])
+                });
+        self
+    }
+
+    /// Moves the aggregation vkey of the op-succinct configuration on the L1
+    /// to another program once the prover is initialized.
+    pub fn mismatch_aggregation_vkey(mut self) -> Self {
+        self.contracts_client =
+            self.contracts_client
+                .set_change_after(FakeMethod::OpSuccinctConfig, 1, |chain| {
+                    chain.op_succinct_config.aggregation_vkey_hash = Digest([0xbb; 32])
+                });
+        self
+    }
+
+    /// Fails the next `count` aggregation proof requests to the proposer.
+    pub fn fail_proposer(mut self, count: usize) -> Self {
+        self.proposer_failures = count;
+        self
+    }
+}
@@ -0,0 +1,366 @@
+use aggchain_proof_contracts::testutils::{FakeL2Block, FakeMethod};
+use aggchain_proof_core::bridge::BridgeConstraintsInput;
+use aggchain_proof_types::{
+    imported_bridge_exit::{BridgeExitHash, ImportedBridgeExitWithBlockNumber},
+    inserted_ger::{InsertedGer, InsertedGerWithBlockNumber},
+    removed_ger::RemovedGerWithBlockNumber,
+    unclaim::UnclaimWithBlockNumber,
+};
+use agglayer_primitives::{Digest, Signature, U256};
+use proposer_client::config::ProposerClientConfig;
+use sp1_sdk::HashableKey as _;
+use tonic::{Code, Status};
+use tonic_types::StatusExt as _;
+
+use crate::{Scenario, TestKit};
+
+/// Fields of the bad request violations of `status`.
+fn violated_fields(status: &Status) -> Vec<String> {
+    status
+        .get_error_details()
+        .bad_request()
+        .map(|bad_request| {
+            bad_request
+                .field_violations
+                .iter()
+                .map(|violation| violation.field.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Causes of the internal error of `status`.
+fn error_causes(status: &Status) -> Vec<String> {
+    status
+        .get_error_details()
+        .debug_info()
+        .map(|debug_info| debug_info.stack_entries.clone())
+        .unwrap_or_default()
+}
+
+/// End block of the request of `scenario`, as served to the aggkit prover.
+fn end_block(scenario: &Scenario) -> FakeL2Block {
+    let end_block = scenario.inputs().requested_end_block;
+    scenario
+        .contracts_client()
+        .update(|chain| chain.block_mut(end_block).clone())
+}
+
+#[test]
+fn recorded_input_satisfies_the_bridge_constraints() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/test_input/bridge_constraints_input.json");
+    let input: BridgeConstraintsInput =
+        serde_json::from_reader(std::io::BufReader::new(std::fs::File::open(path).unwrap()))
+            .unwrap();
+
+    input.verify().unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn recorded_request_reaches_the_prover() {
+    let scenario = Scenario::recorded().unwrap();
+    let end_block = end_block(&scenario);
+    let testkit = TestKit::start(&scenario).await.unwrap();
+
+    let inputs = scenario.inputs().clone();
+    let response = testkit
+        .generate_aggchain_proof(inputs.clone())
+        .await
+        .unwrap();
+
+    assert!(response.aggchain_proof.is_some());
+    assert_eq!(response.last_proven_block, inputs.last_proven_block);
+    assert_eq!(response.end_block, inputs.requested_end_block);
+    assert_eq!(
+        response.local_exit_root_hash,
+        Some(end_block.local_exit_root.into())
+    );
+
+    assert_eq!(
+        testkit
+            .proposer_contracts_client()
+            .calls(FakeMethod::L2OutputAtBlock),
+        2
+    );
+    assert!(
+        testkit
+            .contracts_client()
+            .calls(FakeMethod::TrustedSequencerAddress)
+            >= 1
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn optimistic_request_skips_the_proposer() {
+    let scenario = Scenario::recorded().unwrap();
+    let testkit = TestKit::start(&scenario).await.unwrap();
+
+    // The optimistic mode signature is only checked by the aggchain program.
+    let signature = Signature::try_from(&[1u8; 65][..]).unwrap();
+    let status = testkit
+        .generate_optimistic_aggchain_proof(scenario.optimistic_inputs(signature))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+    assert!(violated_fields(&status).is_empty());
+
+    assert_eq!(
+        testkit
+            .proposer_contracts_client()
+            .calls(FakeMethod::L2OutputAtBlock),
+        0
+    );
+    assert!(
+        testkit
+            .contracts_client()
+            .calls(FakeMethod::TrustedSequencerAddress)
+            >= 1
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bridge_events_added_on_both_sides_are_validated() {
+    let scenario = Scenario::recorded().unwrap();
+    let end_block = scenario.inputs().requested_end_block;
+
+    // A GER inserted and removed, and a bridge exit claimed and unclaimed, in the
+    // end block. The removed GER is not checked against the L1 info root.
+    let mut l1_leaf = scenario.inputs().l1_info_tree_leaf;
+    l1_leaf.inner.global_exit_root = Digest([0xcc; 32]);
+    let proof_ger_l1root = scenario.inputs().l1_info_tree_merkle_proof.clone();
+    let global_index = U256::from(0xdd);
+    let scenario = scenario
+        .insert_ger(InsertedGerWithBlockNumber {
+            block_number: end_block,
+            inserted_ger: InsertedGer {
+                proof_ger_l1root,
+                l1_leaf,
+            },
+            log_index: 0,
+        })
+        .remove_ger(RemovedGerWithBlockNumber {
+            global_exit_root: Digest([0xcc; 32]),
+            block_number: end_block,
+            log_index: 1,
+        })
+        .claim(ImportedBridgeExitWithBlockNumber {
+            block_number: end_block,
+            bridge_exit_hash: BridgeExitHash(Digest([0xee; 32])),
+            global_index: global_index.into(),
+            log_index: 2,
+        })
+        .unclaim(UnclaimWithBlockNumber {
+            global_index,
+            block_number: end_block,
+            log_index: 3,
+        });
+    let testkit = TestKit::start(&scenario).await.unwrap();
+
+    let status = testkit
+        .generate_aggchain_proof(scenario.inputs().clone())
+        .await
+        .unwrap_err();
+
+    // The request goes past the validation, up to the aggchain program.
+    assert_eq!(status.code(), Code::Internal);
+    assert!(violated_fields(&status).is_empty());
+    assert!(
+        testkit
+            .contracts_client()
+            .calls(FakeMethod::TrustedSequencerAddress)
+            >= 1
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn removed_ger_missing_from_the_request_is_reported() {
+    let scenario = Scenario::recorded().unwrap();
+    let end_block = scenario.inputs().requested_end_block;
+    let scenario = scenario
+        .remove_ger(RemovedGerWithBlockNumber {
+            global_exit_root: Digest([0xcc; 32]),
+            block_number: end_block,
+            log_index: 0,
+        })
+        .update_inputs(|inputs| {
+            inputs
+                .removed_gers
+                .retain(|removed_ger| removed_ger.block_number != end_block)
+        });
+    let testkit = TestKit::start(&scenario).await.unwrap();
+
+    let status = testkit
+        .generate_aggchain_proof(scenario.inputs().clone())
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+    assert_eq!(violated_fields(&status), ["removed_gers"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn missing_inserted_ger_is_reported() {
+    let scenario = Scenario::recorded()
+        .unwrap()
+        .update_inputs(|inputs| inputs.ger_leaves.clear());
+    let testkit = TestKit::start(&scenario).await.unwrap();
+
+    let status = testkit
+        .generate_aggchain_proof(scenario.inputs().clone())
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+    assert!(violated_fields(&status).contains(&"ger_leaves".to_string()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn missing_unclaim_is_reported() {
+    let scenario = Scenario::recorded()
+        .unwrap()
+        .update_inputs(|inputs| inputs.unclaims.clear());
+    let testkit = TestKit::start(&scenario).await.unwrap();
+
+    let status = testkit
+        .generate_aggchain_proof(scenario.inputs().clone())
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+    assert_eq!(violated_fields(&status), ["unclaims"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn claim_not_emitted_on_the_l2_is_reported() {
+    // Claims once more the first recorded bridge exit, in a later block.
+    let scenario = Scenario::recorded().unwrap().update_inputs(|inputs| {
+        let mut claim = inputs.imported_bridge_exits[0].clone();
+        claim.block_number = inputs.requested_end_block;
+        inputs.imported_bridge_exits.push(claim);
+    });
+    let testkit = TestKit::start(&scenario).await.unwrap();
+
+    let status = testkit
+        .generate_aggchain_proof(scenario.inputs().clone())
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+    assert_eq!(violated_fields(&status), ["imported_bridge_exits"]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reorged_end_block_fails_the_request() {
+    let scenario = Scenario::recorded().unwrap().reorg_end_block();
+    let pinned = end_block(&scenario).hash;
+    let testkit = TestKit::start(&scenario).await.unwrap();
+
+    let status = testkit
+        .generate_aggchain_proof(scenario.inputs().clone())
+        .await
+        .unwrap_err();
+
+    // The end block is pinned when validating the bridge events, and reorged
+    // out right after.
+    assert_eq!(status.code(), Code::Internal);
+    assert_eq!(
+        status.message(),
+        "Aggchain proof builder service request failed"
+    );
+    assert_eq!(
+        error_causes(&status),
+        [format!(
+            "L2 reorg detected at block {}: data read at block hash {}, pinned to {pinned}",
+            scenario.inputs().requested_end_block,
+            Digest([0xaa; 32]),
+        )]
+    );
+    assert_eq!(
+        testkit
+            .contracts_client()
+            .calls(FakeMethod::TrustedSequencerAddress),
+        0
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn mismatched_aggregation_vkey_fails_the_request() {
+    let scenario = Scenario::recorded().unwrap().mismatch_aggregation_vkey();
+    let testkit = TestKit::start(&scenario).await.unwrap();
+
+    let status = testkit
+        .generate_aggchain_proof(scenario.inputs().clone())
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+    assert_eq!(
+        status.message(),
+        "Aggchain proof builder service request failed"
+    );
+    assert_eq!(
+        error_causes(&status),
+        [format!(
+            "Mismatch on the aggregation vkey hash - got from op succinct contract config: {:?}, \
+             expected from the elf: {:?}",
+            Digest([0xbb; 32]),
+            Digest(proposer_elfs::aggregation::vkey().bytes32_raw()),
+        )]
+    );
+    assert_eq!(
+        testkit
+            .contracts_client()
+            .calls(FakeMethod::TrustedSequencerAddress),
+        0
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn proposer_failure_is_retried() {
+    let scenario = Scenario::recorded().unwrap().fail_proposer(1);
+    let testkit = TestKit::start(&scenario).await.unwrap();
+
+    testkit
+        .generate_aggchain_proof(scenario.inputs().clone())
+        .await
+        .unwrap();
+
+    // The failed attempt reads the first output root only.
+    assert_eq!(
+        testkit
+            .proposer_contracts_client()
+            .calls(FakeMethod::L2OutputAtBlock),
+        3
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn proposer_failure_fails_the_request() {
+    // The first attempt and the retries of the proposer client.
+    let attempts = ProposerClientConfig::default().max_retries + 1;
+    let scenario = Scenario::recorded().unwrap().fail_proposer(attempts);
+    let testkit = TestKit::start(&scenario).await.unwrap();
+
+    let status = testkit
+        .generate_aggchain_proof(scenario.inputs().clone())
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+    assert_eq!(
+        status.message(),
+        "Proposer service returned an error during operation"
+    );
+    let causes = error_causes(&status);
+    let injected_failure = format!("Injected failure of L2OutputAtBlock, call {attempts}");
+    assert!(
+        causes.iter().any(|cause| cause.contains(&injected_failure)),
+        "{causes:?}"
+    );
+    assert_eq!(
+        testkit.contracts_client().calls(FakeMethod::L2BridgeEvents),
+        0
+    );
+}